@@ -5,105 +5,187 @@ extern crate syn;
 
 use proc_macro::TokenStream;
 
-use proc_macro2::Literal;
-use rust_format::Formatter;
-use syn::parse::Parser;
+use proc_macro2::{Literal, Span};
 use syn::punctuated::Punctuated;
-use syn::{ImplItem, ImplItemFn, ItemImpl, Path, PathArguments, Type};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DeriveInput, Expr, ExprLit, Fields, FnArg, GenericArgument, Ident, ImplItem,
+    ImplItemFn, ItemImpl, Lit, LitInt, LitStr, Meta, Path, PathArguments, ReturnType, Type,
+    TypeParamBound,
+};
 
 #[proc_macro_attribute]
 pub fn extensions(args: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as ItemImpl);
+    let arguments = parse_macro_input!(args with Punctuated::<Type, Token![,]>::parse_terminated);
 
-    let tokens = args.clone();
-    let parser = Punctuated::<Path, Token![,]>::parse_separated_nonempty;
-    let mut parsed = parser
-        .parse(tokens)
-        .unwrap()
-        .into_iter()
-        .collect::<Vec<Path>>();
-    if parsed.len() != 2 {
-        panic!("Must contain two arguments: extensions package and a target type");
+    let reflection_impl =
+        parse_extensions_arguments(arguments).and_then(|(category, target_type)| {
+            generate_phlow_implementation_for_external_type(input, category, target_type)
+        });
+
+    TokenStream::from(reflection_impl.unwrap_or_else(|error| error.to_compile_error()))
+}
+
+/// Split the arguments into an extensions category and a target type
+fn parse_extensions_arguments(arguments: Punctuated<Type, Token![,]>) -> syn::Result<(Path, Type)> {
+    if arguments.len() != 2 {
+        let span = if arguments.is_empty() {
+            Span::call_site()
+        } else {
+            arguments.span()
+        };
+        return Err(syn::Error::new(
+            span,
+            "expected two arguments: an extensions category and a target type, \
+            e.g. `#[phlow::extensions(MyExtensions, MyType)]`",
+        ));
     }
 
-    let category = parsed.remove(0);
-    let target_type = parsed.remove(0);
+    let mut arguments = arguments.into_iter();
+    let category = match arguments.next().unwrap() {
+        Type::Path(category) if category.qself.is_none() => category.path,
+        category => {
+            return Err(syn::Error::new_spanned(
+                category,
+                "expected the path of an extensions category defined with `define_extensions!`",
+            ))
+        }
+    };
+    let target_type = arguments.next().unwrap();
+    validate_target_type(&target_type)?;
 
-    let reflection_impl =
-        generate_phlow_implementation_for_external_type(input, category, target_type);
+    Ok((category, target_type))
+}
 
-    TokenStream::from(reflection_impl)
+/// Phlow objects own their values, so the target must be a sized type
+fn validate_target_type(target_type: &Type) -> syn::Result<()> {
+    let is_unsized = match target_type {
+        Type::Slice(_) | Type::TraitObject(_) => true,
+        Type::Path(path) => path.qself.is_none() && path.path.is_ident("str"),
+        Type::Paren(paren) => return validate_target_type(&paren.elem),
+        Type::Group(group) => return validate_target_type(&group.elem),
+        _ => false,
+    };
+
+    if is_unsized {
+        return Err(syn::Error::new_spanned(
+            target_type,
+            "expected a sized target type, phlow objects own their values; \
+            extend a boxed or a `'static` reference type instead, e.g. `Box<[u8]>` or `&'static str`",
+        ));
+    }
+    Ok(())
 }
 
-fn extract_generics(t: &Type) -> Option<proc_macro2::TokenStream> {
-    let tokens = match t {
-        Type::Path(path) => {
+/// Categories given by a single name are looked up at the root of the crate,
+/// qualified categories may be defined in any module or in other crates
+fn qualify_category(category: &Path) -> proc_macro2::TokenStream {
+    if category.leading_colon.is_none() && category.segments.len() == 1 {
+        quote! { crate::#category }
+    } else {
+        quote! { #category }
+    }
+}
+
+/// Return the marker of the generic parameters of the extensions struct
+fn extract_generics(t: &Type) -> syn::Result<Option<proc_macro2::TokenStream>> {
+    match t {
+        Type::Path(path) if path.qself.is_none() => {
             let segments = &path.path.segments;
-            if segments.len() != 1 {
-                panic!(
-                    "Only supports single segments path, but was: {:?}",
-                    segments
-                );
+            if segments.len() != 1 || path.path.leading_colon.is_some() {
+                return Err(syn::Error::new_spanned(
+                    t,
+                    "expected the name of an extensions struct to define, not a path",
+                ));
             }
-            match &segments[0].arguments {
-                PathArguments::None => None,
-                PathArguments::AngleBracketed(angle) => Some(quote! { #angle }),
-                PathArguments::Parenthesized(_) => None,
+            let angle = match &segments[0].arguments {
+                PathArguments::AngleBracketed(angle) => angle,
+                PathArguments::None | PathArguments::Parenthesized(_) => return Ok(None),
+            };
+
+            // const parameters do not need to be used by the struct
+            let markers = angle
+                .args
+                .iter()
+                .filter_map(|argument| match argument {
+                    GenericArgument::Type(argument) => Some(quote! { #argument }),
+                    GenericArgument::Lifetime(lifetime) => Some(quote! { & #lifetime () }),
+                    _ => None,
+                })
+                .collect::<Vec<proc_macro2::TokenStream>>();
+
+            if markers.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(quote! { <(#(#markers,)*)> }))
             }
         }
-        _ => {
-            panic!("Unsupported type: {:?}", t)
-        }
-    };
-
-    tokens
+        _ => Err(syn::Error::new_spanned(
+            t,
+            "expected the name of an extensions struct to define",
+        )),
+    }
 }
 
 fn generate_phlow_implementation_for_external_type(
     implementation: ItemImpl,
     extension_category: Path,
-    extension_target_type: Path,
-) -> proc_macro2::TokenStream {
+    extension_target_type: Type,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if let Some((_, trait_path, _)) = &implementation.trait_ {
+        return Err(syn::Error::new_spanned(
+            trait_path,
+            "expected an inherent impl block of an extensions struct, not a trait implementation",
+        ));
+    }
+
     let self_type = &implementation.self_ty;
     let extension_struct_name = quote! { #self_type };
-    let extension_category_type_name = quote! { #extension_category };
+    let extension_category_type_name = qualify_category(&extension_category);
     let target_type_name = quote! { #extension_target_type };
 
-    let generics_with_bounds = &implementation.generics;
-    let generics = extract_generics(&implementation.self_ty);
+    let (impl_generics, _, where_clause) = implementation.generics.split_for_impl();
+    let generics = extract_generics(&implementation.self_ty)?;
 
-    let struct_impl = if generics.is_some() {
+    let struct_impl = if let Some(generics) = generics {
         quote! { pub struct #extension_struct_name(std::marker::PhantomData #generics ); }
     } else {
         quote! { pub struct #extension_struct_name; }
     };
 
+    let description = Literal::string(&extract_documentation(&implementation.attrs));
+
     let phlow_methods = generate_phlow_methods(
         extension_struct_name.clone(),
-        target_type_name.clone(),
+        &extension_target_type,
         &implementation,
-    );
+    )?;
 
-    quote! {
+    Ok(quote! {
         #struct_impl
         #implementation
 
-        impl #generics_with_bounds phlow::Phlow<crate::#extension_category_type_name> for #target_type_name {
+        impl #impl_generics phlow::Phlow<#extension_category_type_name> for #target_type_name #where_clause {
             #phlow_methods
 
             fn phlow_extension() -> Option<phlow::PhlowExtension> {
-                Some(phlow::PhlowExtension::new::<crate::#extension_category_type_name, Self>())
+                Some(
+                    phlow::PhlowExtension::new::<#extension_category_type_name, Self>()
+                        .defined_in(module_path!(), env!("CARGO_CRATE_NAME"))
+                        .described_as(#description)
+                )
             }
         }
-    }
+    })
 }
 
 fn generate_phlow_methods(
     extension_container_type: proc_macro2::TokenStream,
-    target_type: proc_macro2::TokenStream,
+    target_type: &Type,
     implementation: &ItemImpl,
-) -> proc_macro2::TokenStream {
-    let view_methods: Vec<&ImplItemFn> = implementation
+) -> syn::Result<proc_macro2::TokenStream> {
+    let methods: Vec<&ImplItemFn> = implementation
         .items
         .iter()
         .map(|each| match each {
@@ -112,9 +194,32 @@ fn generate_phlow_methods(
         })
         .filter(|each| each.is_some())
         .map(|each| each.unwrap())
-        .filter(is_view_method)
         .collect();
 
+    let (view_methods, helper_methods): (Vec<&ImplItemFn>, Vec<&ImplItemFn>) =
+        methods.into_iter().partition(is_view_method);
+
+    // report all invalid view methods at once
+    view_methods
+        .iter()
+        .map(|each_method| validate_view_method(each_method))
+        .chain(
+            view_methods
+                .iter()
+                .map(|each_method| parse_view_attributes(each_method).map(|_| ())),
+        )
+        .chain(
+            helper_methods
+                .iter()
+                .map(|each_method| validate_helper_method(each_method)),
+        )
+        .filter_map(Result::err)
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        })
+        .map_or(Ok(()), Err)?;
+
     let get_views = view_methods
         .into_iter()
         .map(|each_method| {
@@ -132,14 +237,79 @@ fn generate_phlow_methods(
             let formatted = get_source_code(each_method);
             let source_code = Literal::string(formatted.as_str());
 
-            quote! {
+            // the location macros expand to where the name of the method is
+            let source_location = quote_spanned! { name_ident.span() =>
+                phlow::PhlowSourceLocation {
+                    file: file!(),
+                    line: line!(),
+                    column: column!(),
+                }
+            };
+
+            let description = match extract_documentation(&each_method.attrs) {
+                documentation if documentation.is_empty() => quote! { None },
+                documentation => {
+                    let documentation = Literal::string(&documentation);
+                    quote! { Some(#documentation.to_string()) }
+                }
+            };
+
+            let attributes = parse_view_attributes(each_method)?;
+            let title = match attributes.title {
+                None => quote! { None },
+                Some(title) => quote! { Some(#title.to_string()) },
+            };
+            let priority = match attributes.priority {
+                None => quote! { None },
+                Some(priority) => quote! { Some(#priority) },
+            };
+            let matches_variant = attributes
+                .variant
+                .map(|variant| {
+                    let enum_path = enum_path_of(target_type, &variant)?;
+                    Ok::<_, syn::Error>(quote! { matches!(&*typed_reference, #enum_path::#variant { .. }) })
+                })
+                .transpose()?;
+            let matches_when = attributes
+                .when
+                .map(|when| quote! { (#when)(&typed_reference) });
+            let condition = match (matches_variant, matches_when) {
+                (None, None) => quote! { None },
+                (matches_variant, matches_when) => {
+                    let predicates = matches_variant.into_iter().chain(matches_when);
+                    quote! {
+                        Some(std::sync::Arc::new(| object: &phlow::PhlowObject | {
+                            object
+                                .value_ref::<#target_type>()
+                                .map_or(false, |typed_reference| #(#predicates)&&*)
+                        }))
+                    }
+                }
+            };
+
+            // errors of fallible view methods are shown in an error view instead
+            let box_view = if returns_result(each_method) {
+                quote! {
+                    match view {
+                        Ok(view) => Some(Box::new(view) as Box<dyn phlow::PhlowView>),
+                        Err(error) => {
+                            let view = phlow::PhlowProtoView::new(object.clone(), method.clone());
+                            Some(Box::new(phlow::PhlowView::error(&view, error)))
+                        }
+                    }
+                }
+            } else {
+                quote! { Some(Box::new(view)) }
+            };
+
+            Ok(quote! {
                 phlow::PhlowViewMethod {
                     method: std::sync::Arc::new(| object: &phlow::PhlowObject, method: &phlow::PhlowViewMethod | {
                         if let Some(typed_reference) = object.value_ref::<#target_type>() {
                             let view = <#extension_container_type> :: #method_name (
                                 &typed_reference,
                                 phlow::PhlowProtoView::new(object.clone(), method.clone()));
-                            Some(Box::new(view))
+                            #box_view
                         } else {
                             phlow::log::warn!("Failed to cast object of type {} to {} when building a view {}",
                                 object.value_type_name(),
@@ -151,25 +321,301 @@ fn generate_phlow_methods(
                     extension: extension.clone(),
                     full_method_name:  #full_method_name_string.to_string(),
                     method_name:  #method_name_string.to_string(),
-                    source_code: #source_code.to_string()
+                    source_code: #source_code.to_string(),
+                    source_location: #source_location,
+                    description: #description,
+                    title: #title,
+                    priority: #priority,
+                    condition: #condition,
                 }
-            }
+            })
         })
-        .collect::<Vec<proc_macro2::TokenStream>>();
+        .collect::<syn::Result<Vec<proc_macro2::TokenStream>>>()?;
 
-    quote! {
+    Ok(quote! {
         fn phlow_view_methods(extension: &phlow::PhlowExtension) -> Vec<phlow::PhlowViewMethod> {
             vec![#(#get_views),*]
         }
-    }
+    })
 }
 
+/// Join the lines of `///` doc comments, or `#[doc = "..."]` attributes
+fn extract_documentation(attributes: &[Attribute]) -> String {
+    let lines = attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("doc"))
+        .filter_map(|attribute| match &attribute.meta {
+            Meta::NameValue(name_value) => match &name_value.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(line),
+                    ..
+                }) => Some(line.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .flat_map(|line| {
+            line.split('\n')
+                .map(|line| {
+                    line.strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<String>>()
+        })
+        .collect::<Vec<String>>();
+
+    lines.join("\n").trim().to_string()
+}
+
+/// View methods are marked with `#[phlow::view]` or with an imported `#[view]`
 fn is_view_method(method: &&ImplItemFn) -> bool {
-    method.attrs.iter().any(|_each| true)
+    method.attrs.iter().any(is_view_attribute)
+}
+
+fn is_view_attribute(attribute: &Attribute) -> bool {
+    let segments = &attribute.path().segments;
+    match segments.len() {
+        1 => segments[0].ident == "view",
+        2 => segments[0].ident == "phlow" && segments[1].ident == "view",
+        _ => false,
+    }
+}
+
+/// Arguments of `#[phlow::view(title = "...", priority = ..., when = ..., variant = ...)]`
+#[derive(Default)]
+struct ViewAttributes {
+    title: Option<LitStr>,
+    priority: Option<LitInt>,
+    /// A predicate taking a reference to the target
+    when: Option<Expr>,
+    /// The variant of the target enum the view is limited to
+    variant: Option<Ident>,
+}
+
+fn parse_view_attributes(method: &ImplItemFn) -> syn::Result<ViewAttributes> {
+    let mut attributes = ViewAttributes::default();
+
+    for attribute in method.attrs.iter().filter(|each| is_view_attribute(each)) {
+        if !matches!(attribute.meta, Meta::List(_)) {
+            continue;
+        }
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("title") {
+                attributes.title = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("priority") {
+                let priority: LitInt = meta.value()?.parse()?;
+                priority.base10_parse::<usize>().map_err(|_| {
+                    syn::Error::new_spanned(&priority, "expected a non-negative integer priority")
+                })?;
+                attributes.priority = Some(priority);
+            } else if meta.path.is_ident("when") {
+                attributes.when = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("variant") {
+                let variant: Path = meta.value()?.parse()?;
+                let variant = variant.get_ident().cloned().ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &variant,
+                        "expected the name of a variant of the target enum, e.g. `variant = Foo`",
+                    )
+                })?;
+                attributes.variant = Some(variant);
+            } else {
+                return Err(meta.error("expected `title`, `priority`, `when` or `variant`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(attributes)
+}
+
+/// Turn the target type into a path that can prefix variants in patterns, e.g. `Message::<T>`
+fn enum_path_of(target_type: &Type, variant: &Ident) -> syn::Result<Path> {
+    match target_type {
+        Type::Path(path) if path.qself.is_none() => {
+            let mut enum_path = path.path.clone();
+            for segment in enum_path.segments.iter_mut() {
+                if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                    arguments.colon2_token = Some(Default::default());
+                }
+            }
+            Ok(enum_path)
+        }
+        Type::Paren(paren) => enum_path_of(&paren.elem, variant),
+        Type::Group(group) => enum_path_of(&group.elem, variant),
+        _ => Err(syn::Error::new_spanned(
+            variant,
+            "views of a variant require the target type to be an enum",
+        )),
+    }
+}
+
+/// Helper methods may be anything but a view method that is missing its attribute
+fn validate_helper_method(method: &ImplItemFn) -> syn::Result<()> {
+    let signature = &method.sig;
+    let takes_view = signature.inputs.iter().any(|argument| match argument {
+        FnArg::Typed(argument) => is_impl_phlow_view(&argument.ty),
+        FnArg::Receiver(_) => false,
+    });
+    let returns_view = match &signature.output {
+        ReturnType::Type(_, return_type) => {
+            is_impl_phlow_view(return_type) || is_result_of_impl_phlow_view(return_type)
+        }
+        ReturnType::Default => false,
+    };
+
+    if takes_view && returns_view {
+        return Err(syn::Error::new_spanned(
+            &signature.ident,
+            format!(
+                "`{}` looks like a view method but is not annotated, \
+                mark it with `#[phlow::view]` to turn it into a view",
+                signature.ident
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// View methods must look like `fn name(this: &Target, view: impl PhlowView) -> impl PhlowView`
+fn validate_view_method(method: &ImplItemFn) -> syn::Result<()> {
+    let signature = &method.sig;
+    let expected_signature = "expected `(this: &Target, view: impl PhlowView) -> impl PhlowView`";
+
+    if let Some(receiver) = signature.receiver() {
+        return Err(syn::Error::new_spanned(
+            receiver,
+            format!(
+                "view methods are associated functions and can not take `self`, {}",
+                expected_signature
+            ),
+        ));
+    }
+
+    if signature.inputs.len() != 2 {
+        let span = if signature.inputs.is_empty() {
+            signature.paren_token.span.join()
+        } else {
+            signature.inputs.span()
+        };
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "view methods take exactly two arguments, {}",
+                expected_signature
+            ),
+        ));
+    }
+
+    let argument_types = signature
+        .inputs
+        .iter()
+        .filter_map(|argument| match argument {
+            FnArg::Typed(argument) => Some(argument.ty.as_ref()),
+            FnArg::Receiver(_) => None,
+        })
+        .collect::<Vec<&Type>>();
+
+    if !matches!(argument_types[0], Type::Reference(reference) if reference.mutability.is_none()) {
+        return Err(syn::Error::new_spanned(
+            argument_types[0],
+            "the first argument of a view method must be a shared reference to the target, \
+            e.g. `this: &Target`",
+        ));
+    }
+
+    if !is_impl_phlow_view(argument_types[1]) {
+        return Err(syn::Error::new_spanned(
+            argument_types[1],
+            "the second argument of a view method must be `impl PhlowView`",
+        ));
+    }
+
+    match &signature.output {
+        ReturnType::Type(_, return_type)
+            if is_impl_phlow_view(return_type) || is_result_of_impl_phlow_view(return_type) =>
+        {
+            Ok(())
+        }
+        ReturnType::Type(_, return_type) => Err(syn::Error::new_spanned(
+            return_type,
+            "view methods must return `impl PhlowView` or `Result<impl PhlowView, E>`",
+        )),
+        ReturnType::Default => Err(syn::Error::new(
+            signature.paren_token.span.close(),
+            "view methods must return `impl PhlowView` or `Result<impl PhlowView, E>`",
+        )),
+    }
+}
+
+/// Fallible view methods return `Result<impl PhlowView, E>` where the error implements `Display`
+fn is_result_of_impl_phlow_view(t: &Type) -> bool {
+    match t {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .filter(|segment| segment.ident == "Result")
+            .and_then(|segment| match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) => arguments.args.first(),
+                _ => None,
+            })
+            .is_some_and(|argument| {
+                matches!(argument, GenericArgument::Type(view) if is_impl_phlow_view(view))
+            }),
+        Type::Paren(paren) => is_result_of_impl_phlow_view(&paren.elem),
+        _ => false,
+    }
+}
+
+fn returns_result(method: &ImplItemFn) -> bool {
+    match &method.sig.output {
+        ReturnType::Type(_, return_type) => is_result_of_impl_phlow_view(return_type),
+        ReturnType::Default => false,
+    }
 }
 
+fn is_impl_phlow_view(t: &Type) -> bool {
+    match t {
+        Type::ImplTrait(impl_trait) => impl_trait.bounds.iter().any(|bound| match bound {
+            TypeParamBound::Trait(bound) => bound
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "PhlowView"),
+            _ => false,
+        }),
+        Type::Paren(paren) => is_impl_phlow_view(&paren.elem),
+        _ => false,
+    }
+}
+
+/// Capture the source code of a view method as configured by the `source-*` features,
+/// only the signature is captured when source capture is off
 fn get_source_code(each_method: &ImplItemFn) -> String {
-    let token_stream = quote! { #each_method };
+    if cfg!(not(feature = "source-raw")) {
+        let signature = &each_method.sig;
+        return quote! { #signature }.to_string();
+    }
+
+    #[cfg(feature = "rustfmt")]
+    if let Some(formatted) = format_with_rustfmt(each_method) {
+        return formatted;
+    }
+
+    #[cfg(feature = "source-formatted")]
+    return pretty_print(each_method);
+
+    #[allow(unreachable_code)]
+    quote! { #each_method }.to_string()
+}
+
+/// Returns `None` if rustfmt is not installed or fails
+#[cfg(feature = "rustfmt")]
+fn format_with_rustfmt(each_method: &ImplItemFn) -> Option<String> {
+    use rust_format::Formatter;
 
     let config = rust_format::Config::new_str()
         .edition(rust_format::Edition::Rust2021)
@@ -177,10 +623,222 @@ fn get_source_code(each_method: &ImplItemFn) -> String {
         .option("reorder_modules", "false")
         .option("max_width", "85");
     let rust_fmt = rust_format::RustFmt::from_config(config);
-    rust_fmt.format_tokens(token_stream).unwrap()
+    rust_fmt.format_tokens(quote! { #each_method }).ok()
+}
+
+#[cfg(feature = "source-formatted")]
+fn pretty_print(each_method: &ImplItemFn) -> String {
+    let function = syn::ItemFn {
+        attrs: each_method.attrs.clone(),
+        vis: each_method.vis.clone(),
+        sig: each_method.sig.clone(),
+        block: Box::new(each_method.block.clone()),
+    };
+    let file = syn::File {
+        shebang: None,
+        attrs: vec![],
+        items: vec![syn::Item::Fn(function)],
+    };
+    prettyplease::unparse(&file).trim_end().to_string()
 }
 
 #[proc_macro_attribute]
 pub fn view(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
+
+/// Implement `phlow::PhlowVariant` for an enum. With `#[phlow(extensions = MyExtensions)]`
+/// the enum also gets a "Variant" view in that category, enums that define their own
+/// extensions in the category should add `view.variant::<MyEnum>()` to them instead.
+///
+/// Fields are wrapped with the `phlow_extensions_of_val` function generated by
+/// `import_extensions!` at the crate root, enums in crates that import their extensions
+/// elsewhere should point to it with `#[phlow(extensions_of_val = path::to::phlow_extensions_of_val)]`
+#[proc_macro_derive(PhlowVariant, attributes(phlow))]
+pub fn derive_phlow_variant(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    let implementation = generate_phlow_variant_implementation(input);
+    TokenStream::from(implementation.unwrap_or_else(|error| error.to_compile_error()))
+}
+
+fn generate_phlow_variant_implementation(
+    mut input: DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`PhlowVariant` can only be derived for enums",
+            ))
+        }
+    };
+
+    let attributes = parse_variant_attributes(&input.attrs)?;
+    let extensions_of_val = attributes
+        .extensions_of_val
+        .unwrap_or_else(|| parse_quote! { crate::phlow_extensions_of_val });
+
+    let enum_name = &input.ident;
+    let (name_arms, fields_arms): (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) =
+        data.variants
+            .iter()
+            .map(|variant| {
+                let variant_name = &variant.ident;
+                let variant_name_string = Literal::string(&variant_name.to_string());
+
+                // tuple fields are bound to `field_0`, `field_1`, ... and named by their index
+                let (bindings, names): (Vec<Ident>, Vec<String>) = match &variant.fields {
+                    Fields::Named(fields) => fields
+                        .named
+                        .iter()
+                        .map(|field| {
+                            let name = field.ident.clone().unwrap();
+                            let name_string = name.to_string();
+                            (name, name_string)
+                        })
+                        .unzip(),
+                    Fields::Unnamed(fields) => (0..fields.unnamed.len())
+                        .map(|index| (format_ident!("field_{}", index), index.to_string()))
+                        .unzip(),
+                    Fields::Unit => (vec![], vec![]),
+                };
+                let pattern = match &variant.fields {
+                    Fields::Named(_) => quote! { Self::#variant_name { #(#bindings),* } },
+                    Fields::Unnamed(_) => quote! { Self::#variant_name ( #(#bindings),* ) },
+                    Fields::Unit => quote! { Self::#variant_name },
+                };
+                let names = names.iter().map(|name| Literal::string(name));
+
+                (
+                    quote! { Self::#variant_name { .. } => #variant_name_string },
+                    quote! {
+                        #pattern => vec![#(
+                            (
+                                #names.to_string(),
+                                phlow::PhlowObject::reference(#bindings, object, #extensions_of_val)
+                            )
+                        ),*]
+                    },
+                )
+            })
+            .unzip();
+
+    // fields are referenced by phlow objects that require `'static` values
+    let type_parameters = input
+        .generics
+        .type_params()
+        .map(|parameter| parameter.ident.clone())
+        .collect::<Vec<Ident>>();
+    let where_clause = input.generics.make_where_clause();
+    for parameter in type_parameters {
+        where_clause
+            .predicates
+            .push(parse_quote! { #parameter: 'static });
+    }
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let variant_impl = quote! {
+        impl #impl_generics phlow::PhlowVariant for #enum_name #type_generics #where_clause {
+            fn phlow_variant_name(&self) -> &'static str {
+                match self {
+                    #(#name_arms,)*
+                }
+            }
+
+            fn phlow_variant_fields(&self, object: &phlow::PhlowObject) -> Vec<(String, phlow::PhlowObject)> {
+                match self {
+                    #(#fields_arms,)*
+                }
+            }
+        }
+    };
+
+    let category = match attributes.category {
+        None => return Ok(variant_impl),
+        Some(category) => qualify_category(&category),
+    };
+
+    let enum_name_string = enum_name.to_string();
+    let full_method_name = Literal::string(&format!("{}::variant_for", enum_name_string));
+    let source_code = Literal::string(&format!(
+        "fn variant_for(_this: &{0}, view: impl PhlowView) -> impl PhlowView {{\n    view.variant::<{0}>()\n}}",
+        enum_name_string
+    ));
+    let description = Literal::string(&format!(
+        "The variant of {} and its fields, derived with `PhlowVariant`",
+        enum_name_string
+    ));
+    let source_location = quote_spanned! { enum_name.span() =>
+        phlow::PhlowSourceLocation {
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        }
+    };
+
+    Ok(quote! {
+        #variant_impl
+
+        impl #impl_generics phlow::Phlow<#category> for #enum_name #type_generics #where_clause {
+            fn phlow_view_methods(extension: &phlow::PhlowExtension) -> Vec<phlow::PhlowViewMethod> {
+                vec![
+                    phlow::PhlowViewMethod {
+                        method: std::sync::Arc::new(| object: &phlow::PhlowObject, method: &phlow::PhlowViewMethod | {
+                            let view = phlow::PhlowProtoView::new(object.clone(), method.clone());
+                            Some(Box::new(phlow::PhlowView::variant::<Self>(&view)))
+                        }),
+                        extension: extension.clone(),
+                        full_method_name: #full_method_name.to_string(),
+                        method_name: "variant_for".to_string(),
+                        source_code: #source_code.to_string(),
+                        source_location: #source_location,
+                        description: None,
+                        title: Some("Variant".to_string()),
+                        priority: None,
+                        condition: None,
+                    }
+                ]
+            }
+
+            fn phlow_extension() -> Option<phlow::PhlowExtension> {
+                Some(
+                    phlow::PhlowExtension::new::<#category, Self>()
+                        .defined_in(module_path!(), env!("CARGO_CRATE_NAME"))
+                        .described_as(#description)
+                )
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct VariantAttributes {
+    category: Option<Path>,
+    extensions_of_val: Option<Path>,
+}
+
+/// Parse `#[phlow(extensions = MyExtensions, extensions_of_val = path::to::function)]`
+fn parse_variant_attributes(attributes: &[Attribute]) -> syn::Result<VariantAttributes> {
+    let mut variant_attributes = VariantAttributes::default();
+
+    for attribute in attributes
+        .iter()
+        .filter(|each| each.path().is_ident("phlow"))
+    {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("extensions") {
+                variant_attributes.category = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("extensions_of_val") {
+                variant_attributes.extensions_of_val = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `extensions` or `extensions_of_val`"))
+            }
+        })?;
+    }
+
+    Ok(variant_attributes)
+}
@@ -91,7 +91,7 @@ impl ColorExtensions {
                 .cycle()
                 .take((width * height * 4) as usize)
                 .collect();
-            PhlowBitmap::rgba8(pixels, width, height)
+            PhlowBitmap::rgba8(pixels, width, height).unwrap_or_default()
         })
     }
 }
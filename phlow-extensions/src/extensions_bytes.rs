@@ -0,0 +1,46 @@
+use phlow::PhlowView;
+
+/// Byte buffers are also vectors, so their views are registered in a category of their own
+/// to not conflict with [`crate::CoreExtensions`] defined for any `Vec<T>`
+#[phlow::extensions(BytesExtensions, Vec<u8>)]
+impl ByteVecExtensions {
+    #[phlow::view]
+    fn hex_dump_for(_this: &Vec<u8>, view: impl PhlowView) -> impl PhlowView {
+        view.hex_dump()
+            .title("Hex")
+            .priority(4)
+            .bytes_range::<Vec<u8>>(|bytes, start, count| bytes_range(&bytes, start, count))
+            .bytes_count::<Vec<u8>>(|bytes| bytes.len())
+    }
+}
+
+#[phlow::extensions(BytesExtensions, Box<[u8]>)]
+impl ByteSliceExtensions {
+    #[phlow::view]
+    #[allow(clippy::borrowed_box)]
+    fn hex_dump_for(_this: &Box<[u8]>, view: impl PhlowView) -> impl PhlowView {
+        view.hex_dump()
+            .title("Hex")
+            .priority(4)
+            .bytes_range::<Box<[u8]>>(|bytes, start, count| bytes_range(&bytes, start, count))
+            .bytes_count::<Box<[u8]>>(|bytes| bytes.len())
+    }
+}
+
+#[phlow::extensions(BytesExtensions, &'static [u8])]
+impl StaticByteSliceExtensions {
+    #[phlow::view]
+    fn hex_dump_for(_this: &&'static [u8], view: impl PhlowView) -> impl PhlowView {
+        view.hex_dump()
+            .title("Hex")
+            .priority(4)
+            .bytes_range::<&'static [u8]>(|bytes, start, count| bytes_range(&bytes, start, count))
+            .bytes_count::<&'static [u8]>(|bytes| bytes.len())
+    }
+}
+
+/// Copy at most `count` bytes starting from the `start` offset
+pub(crate) fn bytes_range(bytes: &[u8], start: usize, count: usize) -> Vec<u8> {
+    let bytes = &bytes[start.min(bytes.len())..];
+    bytes[..count.min(bytes.len())].to_vec()
+}
@@ -0,0 +1,28 @@
+use phlow::{PhlowDiff, PhlowView};
+
+#[phlow::extensions(CoreExtensions, PhlowDiff)]
+impl DiffExtensions {
+    #[phlow::view]
+    fn diff_for(_this: &PhlowDiff, view: impl PhlowView) -> impl PhlowView {
+        view.diff()
+            .title("Diff")
+            .priority(5)
+            .diff::<PhlowDiff>(|diff| diff.clone())
+    }
+
+    #[phlow::view]
+    fn objects_for(_this: &PhlowDiff, view: impl PhlowView) -> impl PhlowView {
+        view.columned_list()
+            .title("Objects")
+            .priority(6)
+            .items::<PhlowDiff>(|diff| {
+                phlow_all!(vec![
+                    ("Left", diff.get_left().clone()),
+                    ("Right", diff.get_right().clone()),
+                ])
+            })
+            .column_item::<(&str, phlow::PhlowObject)>("Side", |each| phlow!(each.0.to_string()))
+            .column_item::<(&str, phlow::PhlowObject)>("Object", |each| phlow!(each.1.clone()))
+            .send::<(&str, phlow::PhlowObject)>(|each| each.1.clone())
+    }
+}
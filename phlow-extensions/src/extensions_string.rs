@@ -1,5 +1,7 @@
 use phlow::PhlowView;
 
+use crate::extensions_bytes::bytes_range;
+
 #[phlow::extensions(CoreExtensions, String)]
 impl StringExtensions {
     #[phlow::view]
@@ -17,4 +19,15 @@ impl StringExtensions {
             .priority(6)
             .items::<String>(|string| phlow_all!(string.chars()))
     }
+
+    #[phlow::view]
+    fn bytes_for(_this: &String, view: impl PhlowView) -> impl PhlowView {
+        view.hex_dump()
+            .title("Bytes")
+            .priority(7)
+            .bytes_range::<String>(|string, start, count| {
+                bytes_range(string.as_bytes(), start, count)
+            })
+            .bytes_count::<String>(|string| string.len())
+    }
 }
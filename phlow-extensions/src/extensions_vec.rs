@@ -7,10 +7,13 @@ impl<T: 'static> VecExtensions<T> {
         view.list()
             .title("Items")
             .priority(5)
-            .items::<Vec<T>>(|vec| {
+            .items_range::<Vec<T>>(|vec, start, count| {
                 vec.iter()
+                    .skip(start)
+                    .take(count)
                     .map(|each| phlow_generic!(each, vec.phlow_object()))
                     .collect()
             })
+            .items_count::<Vec<T>>(|vec| vec.len())
     }
 }
@@ -4,6 +4,8 @@
 #[macro_use]
 extern crate phlow;
 
+mod extensions_bytes;
+mod extensions_diff;
 mod extensions_f32;
 mod extensions_integer;
 mod extensions_rc;
@@ -11,7 +13,8 @@ mod extensions_string;
 mod extensions_vec;
 
 define_extensions!(CoreExtensions);
-import_extensions!(CoreExtensions);
+define_extensions!(BytesExtensions);
+import_extensions!(CoreExtensions, BytesExtensions);
 
 #[macro_export]
 macro_rules! representations_view_for_integer {
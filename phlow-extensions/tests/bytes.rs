@@ -0,0 +1,40 @@
+#[macro_use]
+extern crate phlow;
+extern crate phlow_extensions;
+
+use phlow_extensions::{BytesExtensions, CoreExtensions};
+
+import_extensions!(CoreExtensions, BytesExtensions);
+
+fn view_names<T: 'static>(value: T) -> Vec<String> {
+    phlow!(value)
+        .phlow_view_methods()
+        .into_iter()
+        .map(|method| method.method_name)
+        .collect()
+}
+
+#[test]
+pub fn test_byte_vec() {
+    let names = view_names(vec![1u8, 2, 3]);
+    assert!(names.contains(&"hex_dump_for".to_string()));
+    assert!(names.contains(&"items_for".to_string()));
+}
+
+#[test]
+pub fn test_byte_slice() {
+    let names = view_names(vec![1u8, 2, 3].into_boxed_slice());
+    assert!(names.contains(&"hex_dump_for".to_string()));
+}
+
+#[test]
+pub fn test_static_byte_slice() {
+    let names = view_names::<&'static [u8]>(b"phlow");
+    assert!(names.contains(&"hex_dump_for".to_string()));
+}
+
+#[test]
+pub fn test_string() {
+    let names = view_names("phlow".to_string());
+    assert!(names.contains(&"bytes_for".to_string()));
+}
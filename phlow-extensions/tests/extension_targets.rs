@@ -0,0 +1,164 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+extern crate phlow_extensions;
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use phlow::PhlowView;
+
+define_extensions!(TestExtensions);
+import_extensions!(
+    TestExtensions,
+    categories::ModuleExtensions,
+    phlow_extensions::CoreExtensions
+);
+
+mod categories {
+    define_extensions!(ModuleExtensions);
+}
+
+pub mod model {
+    pub struct Node(pub &'static str);
+    pub struct Label(pub &'static str);
+
+    pub trait HasLabel {
+        type Label;
+    }
+
+    impl HasLabel for Node {
+        type Label = Label;
+    }
+}
+
+pub struct Wrapper<T>(T);
+
+#[phlow::extensions(TestExtensions, crate::model::Node)]
+impl NodeExtensions {
+    #[phlow::view]
+    fn name_for(_this: &model::Node, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .title("Name")
+            .text::<model::Node>(|node| node.0.to_string())
+    }
+}
+
+#[phlow::extensions(categories::ModuleExtensions, model::Node)]
+impl NodeModuleExtensions {
+    #[phlow::view]
+    fn module_for(_this: &model::Node, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Module")
+    }
+}
+
+#[phlow::extensions(phlow_extensions::CoreExtensions, model::Node)]
+impl NodeCoreExtensions {
+    #[phlow::view]
+    fn core_for(_this: &model::Node, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Core")
+    }
+}
+
+#[phlow::extensions(TestExtensions, <model::Node as model::HasLabel>::Label)]
+impl LabelExtensions {
+    #[phlow::view]
+    fn label_for(_this: &model::Label, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Label")
+    }
+}
+
+#[phlow::extensions(TestExtensions, std::collections::HashMap<K, V>)]
+impl<K, V> MapExtensions<K, V>
+where
+    K: Display + 'static,
+    V: Display + 'static,
+{
+    #[phlow::view]
+    fn entries_for(_this: &HashMap<K, V>, view: impl PhlowView) -> impl PhlowView {
+        view.list().title("Entries")
+    }
+}
+
+#[phlow::extensions(TestExtensions, Wrapper<T>)]
+impl<T> WrapperExtensions<T>
+where
+    T: Display + 'static,
+{
+    #[phlow::view]
+    fn wrapped_for(_this: &Wrapper<T>, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .title("Wrapped")
+            .text::<Wrapper<T>>(|wrapper| wrapper.0.to_string())
+    }
+}
+
+#[phlow::extensions(TestExtensions, (usize, &'static str))]
+impl PairExtensions {
+    #[phlow::view]
+    fn pair_for(_this: &(usize, &'static str), view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Pair")
+    }
+}
+
+#[phlow::extensions(TestExtensions, [u8; 4])]
+impl AddressExtensions {
+    #[phlow::view]
+    fn address_for(_this: &[u8; 4], view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Address")
+    }
+}
+
+#[phlow::extensions(TestExtensions, &'static str)]
+impl StrExtensions {
+    #[phlow::view]
+    fn str_for(_this: &&'static str, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Str")
+    }
+}
+
+#[phlow::extensions(TestExtensions, &'static [u8])]
+impl ByteSliceExtensions {
+    #[phlow::view]
+    fn bytes_for(_this: &&'static [u8], view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Bytes")
+    }
+}
+
+fn view_titles<T: 'static>(value: T) -> Vec<String> {
+    phlow!(value)
+        .phlow_views()
+        .iter()
+        .map(|view| view.get_title().to_string())
+        .collect()
+}
+
+#[test]
+pub fn qualified_target_and_categories() {
+    assert_eq!(
+        view_titles(model::Node("root")),
+        vec!["Core", "Module", "Name"]
+    );
+    assert_eq!(view_titles(model::Label("leaf")), vec!["Label"]);
+}
+
+#[test]
+pub fn generic_targets_with_where_clauses() {
+    let mut map = HashMap::new();
+    map.insert("answer".to_string(), 42);
+    assert_eq!(view_titles(map), vec!["Entries"]);
+
+    let wrapper = Wrapper(42);
+    let view = phlow!(wrapper).phlow_view_named("wrapped_for").unwrap();
+    assert_eq!(view.to_string().lines().nth(2), Some("42"));
+}
+
+#[test]
+pub fn tuple_array_and_reference_targets() {
+    assert_eq!(view_titles((1usize, "one")), vec!["Pair"]);
+    assert_eq!(view_titles([127u8, 0, 0, 1]), vec!["Address"]);
+    assert_eq!(view_titles("text"), vec!["Str"]);
+    assert_eq!(view_titles(b"bytes".as_slice()), vec!["Bytes"]);
+}
@@ -4,16 +4,26 @@
 
 extern crate phlow;
 
+pub use phlow_bitmap_view::*;
+pub use phlow_canvas_view::*;
 pub use phlow_columned_list_view::*;
+pub use phlow_composite_view::*;
 pub use phlow_list_view::*;
 pub use phlow_object::*;
+pub use phlow_progress::*;
+pub use phlow_style::*;
 pub use phlow_text_view::*;
 pub use phlow_view::*;
 pub use phlow_view_method::*;
 
+mod phlow_bitmap_view;
+mod phlow_canvas_view;
 mod phlow_columned_list_view;
+mod phlow_composite_view;
 mod phlow_list_view;
 mod phlow_object;
+mod phlow_progress;
+mod phlow_style;
 mod phlow_text_view;
 mod phlow_view;
 mod phlow_view_method;
@@ -0,0 +1,101 @@
+use std::ffi::c_void;
+
+use phlow::{PhlowBitmap, PhlowBitmapView, PhlowView, PixelFormat};
+use value_box::{BoxerError, ReturnBoxerResult, ValueBox, ValueBoxPointer};
+
+use crate::{deliver_async, with_view, AsyncDelivery, PhlowComputationCallback};
+
+#[no_mangle]
+pub extern "C" fn phlow_bitmap_view_compute_bitmap(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+) -> *mut ValueBox<PhlowBitmap> {
+    with_view(phlow_view, |phlow_view: &PhlowBitmapView| {
+        Ok(ValueBox::new(phlow_view.compute_bitmap()).into_raw())
+    })
+    .or_log(std::ptr::null_mut())
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_bitmap_view_compute_bitmap_async(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    callback: PhlowComputationCallback<PhlowBitmap>,
+    data: *const c_void,
+) {
+    let computation = with_view(phlow_view, |phlow_view: &PhlowBitmapView| {
+        let view = phlow_view.clone();
+        Ok(AsyncDelivery::of(&[phlow_view.object()], async move {
+            Some(view.async_compute_bitmap().await)
+        }))
+    });
+    deliver_async(computation, callback, data);
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_bitmap_get_width(bitmap: *mut ValueBox<PhlowBitmap>) -> i32 {
+    bitmap.with_ref_ok(|bitmap| bitmap.width()).or_log(0)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_bitmap_get_height(bitmap: *mut ValueBox<PhlowBitmap>) -> i32 {
+    bitmap.with_ref_ok(|bitmap| bitmap.height()).or_log(0)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_bitmap_get_stride(bitmap: *mut ValueBox<PhlowBitmap>) -> i32 {
+    bitmap.with_ref_ok(|bitmap| bitmap.stride()).or_log(0)
+}
+
+/// Return the pixel format as the discriminant of [`PixelFormat`]
+#[no_mangle]
+pub extern "C" fn phlow_bitmap_get_format(bitmap: *mut ValueBox<PhlowBitmap>) -> u8 {
+    bitmap
+        .with_ref_ok(|bitmap| bitmap.format() as u8)
+        .or_log(PixelFormat::default() as u8)
+}
+
+/// Convert the bitmap to a tightly packed one in the given [`PixelFormat`]
+#[no_mangle]
+pub extern "C" fn phlow_bitmap_convert(
+    bitmap: *mut ValueBox<PhlowBitmap>,
+    format: u8,
+) -> *mut ValueBox<PhlowBitmap> {
+    bitmap
+        .with_ref(|bitmap| {
+            PixelFormat::try_from(format)
+                .map(|format| ValueBox::new(bitmap.convert(format)).into_raw())
+                .map_err(|error| BoxerError::AnyError(error.into()))
+        })
+        .or_log(std::ptr::null_mut())
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_bitmap_get_pixels_length(bitmap: *mut ValueBox<PhlowBitmap>) -> usize {
+    bitmap.with_ref_ok(|bitmap| bitmap.pixels().len()).or_log(0)
+}
+
+/// Copy at most `length` bytes of pixel data into the buffer allocated by the host
+///
+/// # Safety
+/// The buffer must be valid for writes of `length` bytes
+#[no_mangle]
+pub unsafe extern "C" fn phlow_bitmap_copy_pixels(
+    bitmap: *mut ValueBox<PhlowBitmap>,
+    buffer: *mut u8,
+    length: usize,
+) {
+    if buffer.is_null() {
+        return;
+    }
+    bitmap
+        .with_ref_ok(|bitmap| {
+            let pixels = bitmap.pixels();
+            let length = pixels.len().min(length);
+            std::ptr::copy_nonoverlapping(pixels.as_ptr(), buffer, length);
+        })
+        .log();
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_bitmap_drop(bitmap: *mut ValueBox<PhlowBitmap>) {
+    bitmap.release();
+}
@@ -0,0 +1,50 @@
+use std::ffi::c_void;
+
+use phlow::{PhlowBitmap, PhlowCanvasView, PhlowView};
+use string_box::StringBox;
+use value_box::{BoxerError, ReturnBoxerResult, ValueBox, ValueBoxPointer};
+
+use crate::{deliver_async, with_view, AsyncDelivery, PhlowComputationCallback};
+
+#[no_mangle]
+pub extern "C" fn phlow_canvas_view_compute_svg(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    svg: *mut ValueBox<StringBox>,
+) {
+    with_view(phlow_view, |phlow_view: &PhlowCanvasView| {
+        svg.with_mut_ok(|svg| svg.set_string(phlow_view.compute_canvas().to_svg()))
+    })
+    .log();
+}
+
+/// Rasterize the canvas for hosts that can only display bitmaps,
+/// the extent of the canvas is multiplied by the scale
+#[no_mangle]
+pub extern "C" fn phlow_canvas_view_compute_bitmap(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    scale: f32,
+) -> *mut ValueBox<PhlowBitmap> {
+    with_view(phlow_view, |phlow_view: &PhlowCanvasView| {
+        phlow_view
+            .compute_bitmap(scale)
+            .map(|bitmap| ValueBox::new(bitmap).into_raw())
+            .map_err(|error| BoxerError::AnyError(error.into()))
+    })
+    .or_log(std::ptr::null_mut())
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_canvas_view_compute_bitmap_async(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    scale: f32,
+    callback: PhlowComputationCallback<PhlowBitmap>,
+    data: *const c_void,
+) {
+    let computation = with_view(phlow_view, |phlow_view: &PhlowCanvasView| {
+        let view = phlow_view.clone();
+        Ok(AsyncDelivery::of(&[phlow_view.object()], async move {
+            view.async_compute_canvas().await.rasterize(scale).ok()
+        }))
+    });
+    deliver_async(computation, callback, data);
+}
@@ -1,19 +1,93 @@
-use phlow::{PhlowColumnedListView, PhlowObject, PhlowView};
+use std::any::Any;
+use std::ffi::c_void;
+
+use phlow::{
+    PhlowColumn, PhlowColumnAlignment, PhlowColumnCellType, PhlowColumnWidth,
+    PhlowColumnedListView, PhlowObject, PhlowProgress, PhlowStyle, PhlowView,
+};
 use string_box::StringBox;
-use value_box::{BoxerError, ReturnBoxerResult, ValueBox, ValueBoxIntoRaw, ValueBoxPointer};
+use value_box::{
+    BoxerError, Result, ReturnBoxerResult, ValueBox, ValueBoxIntoRaw, ValueBoxPointer,
+};
+
+use crate::{computation_error, deliver_async, with_view, AsyncDelivery, PhlowComputationCallback};
 
-use crate::with_view;
+fn with_column<R: Any>(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    column_index: usize,
+    op: impl FnOnce(&PhlowColumn) -> Result<R>,
+) -> Result<R> {
+    with_view(phlow_view, |phlow_view: &PhlowColumnedListView| {
+        phlow_view
+            .get_columns()
+            .get(column_index)
+            .ok_or_else(|| {
+                BoxerError::AnyError(format!("Column at {} does not exist", column_index).into())
+            })
+            .and_then(op)
+    })
+}
 
 #[no_mangle]
 pub extern "C" fn phlow_columned_list_view_compute_items(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
 ) -> *mut ValueBox<Vec<PhlowObject>> {
     with_view(phlow_view, |phlow_view: &PhlowColumnedListView| {
-        Ok(ValueBox::new(phlow_view.compute_items()))
+        phlow_view
+            .try_compute_items()
+            .map(ValueBox::new)
+            .map_err(computation_error)
+    })
+    .into_raw()
+}
+
+/// Compute the items while reporting to the progress created by `phlow_view_new_items_progress`
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_view_compute_items_with_progress(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    progress: *mut ValueBox<PhlowProgress>,
+) -> *mut ValueBox<Vec<PhlowObject>> {
+    progress
+        .with_clone(|progress| {
+            with_view(phlow_view, |phlow_view: &PhlowColumnedListView| {
+                phlow_view
+                    .try_compute_items_with_progress(progress)
+                    .map(ValueBox::new)
+                    .map_err(computation_error)
+            })
+        })
+        .into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_view_compute_items_range(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    start: usize,
+    count: usize,
+) -> *mut ValueBox<Vec<PhlowObject>> {
+    with_view(phlow_view, |phlow_view: &PhlowColumnedListView| {
+        phlow_view
+            .try_compute_items_range(start, count)
+            .map(ValueBox::new)
+            .map_err(computation_error)
     })
     .into_raw()
 }
 
+/// Return the total amount of items or -1 if it is unknown without computing all items
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_view_compute_items_count(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+) -> isize {
+    with_view(phlow_view, |phlow_view: &PhlowColumnedListView| {
+        phlow_view
+            .try_compute_items_count()
+            .map(|count| count.map_or(-1, |count| count as isize))
+            .map_err(computation_error)
+    })
+    .or_log(-1)
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_columned_list_get_columns_len(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
@@ -40,6 +114,101 @@ pub extern "C" fn phlow_columned_list_get_column_title(
     .log();
 }
 
+/// Return the fixed width of the column or 0.0 if the width is not fixed
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_get_column_width(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    column_index: usize,
+) -> f32 {
+    with_column(phlow_view, column_index, |column| {
+        Ok(match column.get_width() {
+            Some(PhlowColumnWidth::Fixed(width)) => width,
+            _ => 0.0,
+        })
+    })
+    .or_log(0.0)
+}
+
+/// Return the relative width of the column or 0.0 if the width is not relative
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_get_column_weight(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    column_index: usize,
+) -> f32 {
+    with_column(phlow_view, column_index, |column| {
+        Ok(match column.get_width() {
+            Some(PhlowColumnWidth::Relative(weight)) => weight,
+            _ => 0.0,
+        })
+    })
+    .or_log(0.0)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_get_column_alignment(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    column_index: usize,
+) -> u8 {
+    with_column(phlow_view, column_index, |column| {
+        Ok(column.get_alignment() as u8)
+    })
+    .or_log(PhlowColumnAlignment::Left as u8)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_get_column_cell_type(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    column_index: usize,
+) -> u8 {
+    with_column(phlow_view, column_index, |column| {
+        Ok(column.get_cell_type() as u8)
+    })
+    .or_log(PhlowColumnCellType::Text as u8)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_get_column_spawns_objects(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    column_index: usize,
+) -> bool {
+    with_column(phlow_view, column_index, |column| {
+        Ok(column.get_spawns_objects())
+    })
+    .or_log(false)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_view_compute_cell_item_at(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    items: *mut ValueBox<Vec<PhlowObject>>,
+    row_index: usize,
+    column_index: usize,
+) -> *mut ValueBox<PhlowObject> {
+    with_column(phlow_view, column_index, |column| {
+        items.with_ref(|items| {
+            items
+                .get(row_index)
+                .ok_or_else(|| {
+                    BoxerError::AnyError(format!("Item at {} does not exist", row_index).into())
+                })
+                .and_then(|item| {
+                    column.compute_cell_item(item).ok_or_else(|| {
+                        BoxerError::AnyError(
+                            format!(
+                                "Could not compute cell item for row: {}, column: {}",
+                                row_index,
+                                column.get_title()
+                            )
+                            .into(),
+                        )
+                    })
+                })
+                .map(ValueBox::new)
+        })
+    })
+    .into_raw()
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_columned_list_view_compute_item_text_at(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
@@ -89,6 +258,36 @@ pub extern "C" fn phlow_columned_list_view_compute_item_text_at(
     .log();
 }
 
+/// Return the style of the cell or null if the cell is not styled
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_view_compute_cell_style_at(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    items: *mut ValueBox<Vec<PhlowObject>>,
+    row_index: usize,
+    column_index: usize,
+) -> *mut ValueBox<PhlowStyle> {
+    with_column(phlow_view, column_index, |column| {
+        items.with_ref(|items| {
+            items
+                .get(row_index)
+                .ok_or_else(|| {
+                    BoxerError::AnyError(format!("Item at {} does not exist", row_index).into())
+                })
+                .map(|item| {
+                    column
+                        .compute_cell_item(item)
+                        .and_then(|cell_item| column.compute_cell_style(&cell_item))
+                })
+        })
+    })
+    .map(|style| {
+        style.map_or(std::ptr::null_mut(), |style| {
+            ValueBox::new(style).into_raw()
+        })
+    })
+    .or_log(std::ptr::null_mut())
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_columned_list_view_compute_item_send_at(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
@@ -114,3 +313,59 @@ pub extern "C" fn phlow_columned_list_view_compute_item_send_at(
     })
     .into_raw()
 }
+
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_view_compute_item_text_at_async(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    items: *mut ValueBox<Vec<PhlowObject>>,
+    row_index: usize,
+    column_index: usize,
+    callback: PhlowComputationCallback<StringBox>,
+    data: *const c_void,
+) {
+    let computation = with_column(phlow_view, column_index, |column| {
+        let column = column.clone();
+        items
+            .with_ref(|items| {
+                items.get(row_index).cloned().ok_or_else(|| {
+                    BoxerError::AnyError(format!("Item at {} does not exist", row_index).into())
+                })
+            })
+            .map(|item| {
+                let computed_item = item.clone();
+                AsyncDelivery::of(&[&item], async move {
+                    let cell_item = column.async_compute_cell_item(&computed_item).await?;
+                    Some(StringBox::from_string(
+                        column.async_compute_cell_text(&cell_item).await,
+                    ))
+                })
+            })
+    });
+    deliver_async(computation, callback, data);
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_columned_list_view_compute_item_send_at_async(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    items: *mut ValueBox<Vec<PhlowObject>>,
+    index: usize,
+    callback: PhlowComputationCallback<PhlowObject>,
+    data: *const c_void,
+) {
+    let computation = with_view(phlow_view, |phlow_view: &PhlowColumnedListView| {
+        let view = phlow_view.clone();
+        items
+            .with_ref(|items| {
+                items.get(index).cloned().ok_or_else(|| {
+                    BoxerError::AnyError(format!("Item at {} does not exist", index).into())
+                })
+            })
+            .map(|item| {
+                let computed_item = item.clone();
+                AsyncDelivery::of(&[phlow_view.object(), &item], async move {
+                    view.async_compute_item_send(&computed_item).await
+                })
+            })
+    });
+    deliver_async(computation, callback, data);
+}
@@ -0,0 +1,39 @@
+use phlow::{PhlowCompositeView, PhlowView};
+use value_box::{ReturnBoxerResult, ValueBox};
+
+use crate::with_view;
+
+/// Return 0 for stacked and 1 for tabbed sub-views
+#[no_mangle]
+pub extern "C" fn phlow_composite_view_get_layout(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+) -> u8 {
+    with_view(phlow_view, |phlow_view: &PhlowCompositeView| {
+        Ok(phlow_view.get_layout() as u8)
+    })
+    .or_log(0)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_composite_view_get_views_count(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+) -> usize {
+    with_view(phlow_view, |phlow_view: &PhlowCompositeView| {
+        Ok(phlow_view.sub_views_count())
+    })
+    .or_log(0)
+}
+
+/// Build the sub-view at the index, returns null if it can not be built
+#[no_mangle]
+pub extern "C" fn phlow_composite_view_get_view_at(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    index: usize,
+) -> *mut ValueBox<Box<dyn PhlowView>> {
+    with_view(phlow_view, |phlow_view: &PhlowCompositeView| {
+        Ok(phlow_view
+            .compute_view_at(index)
+            .map_or(std::ptr::null_mut(), |view| ValueBox::new(view).into_raw()))
+    })
+    .or_log(std::ptr::null_mut())
+}
@@ -1,19 +1,82 @@
-use phlow::{PhlowListView, PhlowObject, PhlowView};
+use std::ffi::c_void;
+
+use phlow::{PhlowListView, PhlowObject, PhlowProgress, PhlowStyle, PhlowView};
 use string_box::StringBox;
-use value_box::{BoxerError, ReturnBoxerResult, ValueBox, ValueBoxIntoRaw, ValueBoxPointer};
+use value_box::{
+    BoxerError, Result, ReturnBoxerResult, ValueBox, ValueBoxIntoRaw, ValueBoxPointer,
+};
+
+use crate::{computation_error, deliver_async, with_view, AsyncDelivery, PhlowComputationCallback};
 
-use crate::with_view;
+fn item_at(items: *mut ValueBox<Vec<PhlowObject>>, index: usize) -> Result<PhlowObject> {
+    items.with_ref(|items| {
+        items
+            .get(index)
+            .cloned()
+            .ok_or_else(|| BoxerError::AnyError(format!("Item at {} does not exist", index).into()))
+    })
+}
 
 #[no_mangle]
 pub extern "C" fn phlow_list_view_compute_items(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
 ) -> *mut ValueBox<Vec<PhlowObject>> {
     with_view(phlow_view, |phlow_view: &PhlowListView| {
-        Ok(ValueBox::new(phlow_view.compute_items_sync()))
+        phlow_view
+            .try_compute_items_sync()
+            .map(ValueBox::new)
+            .map_err(computation_error)
+    })
+    .into_raw()
+}
+
+/// Compute the items while reporting to the progress created by `phlow_view_new_items_progress`
+#[no_mangle]
+pub extern "C" fn phlow_list_view_compute_items_with_progress(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    progress: *mut ValueBox<PhlowProgress>,
+) -> *mut ValueBox<Vec<PhlowObject>> {
+    progress
+        .with_clone(|progress| {
+            with_view(phlow_view, |phlow_view: &PhlowListView| {
+                phlow_view
+                    .try_compute_items_sync_with_progress(progress)
+                    .map(ValueBox::new)
+                    .map_err(computation_error)
+            })
+        })
+        .into_raw()
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_list_view_compute_items_range(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    start: usize,
+    count: usize,
+) -> *mut ValueBox<Vec<PhlowObject>> {
+    with_view(phlow_view, |phlow_view: &PhlowListView| {
+        phlow_view
+            .try_compute_items_range_sync(start, count)
+            .map(ValueBox::new)
+            .map_err(computation_error)
     })
     .into_raw()
 }
 
+/// Return the total amount of items or -1 if it is unknown without computing all items
+#[no_mangle]
+pub extern "C" fn phlow_list_view_compute_items_count(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+) -> isize {
+    with_view(phlow_view, |phlow_view: &PhlowListView| {
+        phlow_view
+            .try_compute_items_count_sync()
+            .map(|count| count.map_or(-1, |count| count as isize))
+            .map_err(computation_error)
+    })
+    .or_log(-1)
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_list_view_compute_item_text_at(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
@@ -28,16 +91,42 @@ pub extern "C" fn phlow_list_view_compute_item_text_at(
                 .ok_or_else(|| {
                     BoxerError::AnyError(format!("Item at {} does not exist", index).into())
                 })
-                .map(|item| {
-                    item_text.with_mut_ok(|item_text| {
-                        item_text.set_string(phlow_view.compute_item_text_sync(item))
-                    })
+                .and_then(|item| {
+                    phlow_view
+                        .try_compute_item_text_sync(item)
+                        .map_err(computation_error)
                 })
+                .and_then(|text| item_text.with_mut_ok(|item_text| item_text.set_string(text)))
         })
     })
     .log();
 }
 
+/// Return the style of the item at the index or null if the item is not styled
+#[no_mangle]
+pub extern "C" fn phlow_list_view_compute_item_style_at(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    items: *mut ValueBox<Vec<PhlowObject>>,
+    index: usize,
+) -> *mut ValueBox<PhlowStyle> {
+    with_view(phlow_view, |phlow_view: &PhlowListView| {
+        items.with_ref(|items| {
+            items
+                .get(index)
+                .ok_or_else(|| {
+                    BoxerError::AnyError(format!("Item at {} does not exist", index).into())
+                })
+                .map(|item| phlow_view.compute_item_style_sync(item))
+        })
+    })
+    .map(|style| {
+        style.map_or(std::ptr::null_mut(), |style| {
+            ValueBox::new(style).into_raw()
+        })
+    })
+    .or_log(std::ptr::null_mut())
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_list_view_compute_item_send_at(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
@@ -63,3 +152,45 @@ pub extern "C" fn phlow_list_view_compute_item_send_at(
     })
     .into_raw()
 }
+
+#[no_mangle]
+pub extern "C" fn phlow_list_view_compute_item_text_at_async(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    items: *mut ValueBox<Vec<PhlowObject>>,
+    index: usize,
+    callback: PhlowComputationCallback<StringBox>,
+    data: *const c_void,
+) {
+    let computation = with_view(phlow_view, |phlow_view: &PhlowListView| {
+        let view = phlow_view.clone();
+        item_at(items, index).map(|item| {
+            let computed_item = item.clone();
+            AsyncDelivery::of(&[phlow_view.object(), &item], async move {
+                Some(StringBox::from_string(
+                    view.compute_item_text(&computed_item).await,
+                ))
+            })
+        })
+    });
+    deliver_async(computation, callback, data);
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_list_view_compute_item_send_at_async(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    items: *mut ValueBox<Vec<PhlowObject>>,
+    index: usize,
+    callback: PhlowComputationCallback<PhlowObject>,
+    data: *const c_void,
+) {
+    let computation = with_view(phlow_view, |phlow_view: &PhlowListView| {
+        let view = phlow_view.clone();
+        item_at(items, index).map(|item| {
+            let computed_item = item.clone();
+            AsyncDelivery::of(&[phlow_view.object(), &item], async move {
+                view.compute_item_to_send(&computed_item).await
+            })
+        })
+    });
+    deliver_async(computation, callback, data);
+}
@@ -20,6 +20,21 @@ pub extern "C" fn phlow_object_get_views(
         .into_raw()
 }
 
+/// Return views of the given type, such as `list_view`, sorted by priority
+#[no_mangle]
+pub extern "C" fn phlow_object_get_views_of_type(
+    phlow_object: *mut ValueBox<PhlowObject>,
+    view_type: *mut ValueBox<StringBox>,
+) -> *mut ValueBox<Vec<Box<dyn PhlowView>>> {
+    phlow_object
+        .with_ref(|phlow_object| {
+            view_type.with_ref_ok(|view_type| {
+                ValueBox::new(phlow_object.phlow_views_of_type(view_type.as_str()))
+            })
+        })
+        .into_raw()
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_object_get_view_named(
     phlow_object: *mut ValueBox<PhlowObject>,
@@ -30,7 +45,7 @@ pub extern "C" fn phlow_object_get_view_named(
             view_name.with_ref(|view_name| {
                 phlow_object
                     .phlow_view_named(view_name.as_str())
-                    .map(|view| ValueBox::new(view))
+                    .map(ValueBox::new)
                     .ok_or_else(|| {
                         BoxerError::AnyError(
                             format!("View named {} does not exist", view_name.as_str()).into(),
@@ -41,6 +56,29 @@ pub extern "C" fn phlow_object_get_view_named(
         .into_raw()
 }
 
+/// Find a view by the full name of its defining method,
+/// sub-views of composite views are addressed as `name#index`
+#[no_mangle]
+pub extern "C" fn phlow_object_get_view_by_selector(
+    phlow_object: *mut ValueBox<PhlowObject>,
+    selector: *mut ValueBox<StringBox>,
+) -> *mut ValueBox<Box<dyn PhlowView>> {
+    phlow_object
+        .with_ref(|phlow_object| {
+            selector.with_ref(|selector| {
+                phlow_object
+                    .phlow_view_by_selector(selector.as_str())
+                    .map(ValueBox::new)
+                    .ok_or_else(|| {
+                        BoxerError::AnyError(
+                            format!("View {} does not exist", selector.as_str()).into(),
+                        )
+                    })
+            })
+        })
+        .into_raw()
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_object_to_string(
     phlow_object: *mut ValueBox<PhlowObject>,
@@ -67,6 +105,14 @@ pub extern "C" fn phlow_object_get_value_type(
         .log();
 }
 
+/// Force views to compute their cached values of the object again
+#[no_mangle]
+pub extern "C" fn phlow_object_mark_changed(phlow_object: *mut ValueBox<PhlowObject>) {
+    phlow_object
+        .with_ref_ok(|phlow_object| phlow_object.mark_changed())
+        .log();
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_object_drop(phlow_object: *mut ValueBox<PhlowObject>) {
     phlow_object.release();
@@ -0,0 +1,60 @@
+use phlow::{PhlowProgress, PhlowView};
+use string_box::StringBox;
+use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
+
+/// Return a new progress for one computation of the items of the view, or null if their
+/// computation does not report one. Pass it to the `compute_items_with_progress` function
+/// of the view and poll it while the items are being computed
+#[no_mangle]
+pub extern "C" fn phlow_view_new_items_progress(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+) -> *mut ValueBox<PhlowProgress> {
+    phlow_view
+        .with_ref_ok(|phlow_view| {
+            phlow_view
+                .new_items_progress()
+                .map_or(std::ptr::null_mut(), |progress| {
+                    ValueBox::new(progress).into_raw()
+                })
+        })
+        .or_log(std::ptr::null_mut())
+}
+
+/// Return the completed fraction between 0 and 1, or a negative number if it is unknown
+#[no_mangle]
+pub extern "C" fn phlow_progress_get_fraction(progress: *mut ValueBox<PhlowProgress>) -> f32 {
+    progress
+        .with_ref_ok(|progress| progress.get_fraction().unwrap_or(-1.0))
+        .or_log(-1.0)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_progress_get_message(
+    progress: *mut ValueBox<PhlowProgress>,
+    message: *mut ValueBox<StringBox>,
+) {
+    progress
+        .with_ref(|progress| {
+            message.with_mut_ok(|message| message.set_string(progress.get_message()))
+        })
+        .log();
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_progress_get_items_count(progress: *mut ValueBox<PhlowProgress>) -> usize {
+    progress
+        .with_ref_ok(|progress| progress.get_report().items_count)
+        .or_log(0)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_progress_is_finished(progress: *mut ValueBox<PhlowProgress>) -> bool {
+    progress
+        .with_ref_ok(|progress| progress.is_finished())
+        .or_log(false)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_progress_drop(progress: *mut ValueBox<PhlowProgress>) {
+    progress.release();
+}
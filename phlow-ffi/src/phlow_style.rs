@@ -0,0 +1,81 @@
+use phlow::PhlowStyle;
+use string_box::StringBox;
+use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
+
+#[no_mangle]
+pub extern "C" fn phlow_style_has_foreground(style: *mut ValueBox<PhlowStyle>) -> bool {
+    style
+        .with_ref_ok(|style| style.get_foreground().is_some())
+        .or_log(false)
+}
+
+/// Return the foreground color packed as `0xRRGGBBAA`
+#[no_mangle]
+pub extern "C" fn phlow_style_get_foreground(style: *mut ValueBox<PhlowStyle>) -> u32 {
+    style
+        .with_ref_ok(|style| style.get_foreground().map_or(0, |color| color.as_u32()))
+        .or_log(0)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_style_has_background(style: *mut ValueBox<PhlowStyle>) -> bool {
+    style
+        .with_ref_ok(|style| style.get_background().is_some())
+        .or_log(false)
+}
+
+/// Return the background color packed as `0xRRGGBBAA`
+#[no_mangle]
+pub extern "C" fn phlow_style_get_background(style: *mut ValueBox<PhlowStyle>) -> u32 {
+    style
+        .with_ref_ok(|style| style.get_background().map_or(0, |color| color.as_u32()))
+        .or_log(0)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_style_is_bold(style: *mut ValueBox<PhlowStyle>) -> bool {
+    style.with_ref_ok(|style| style.is_bold()).or_log(false)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_style_is_italic(style: *mut ValueBox<PhlowStyle>) -> bool {
+    style.with_ref_ok(|style| style.is_italic()).or_log(false)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_style_is_underline(style: *mut ValueBox<PhlowStyle>) -> bool {
+    style
+        .with_ref_ok(|style| style.is_underline())
+        .or_log(false)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_style_is_dimmed(style: *mut ValueBox<PhlowStyle>) -> bool {
+    style.with_ref_ok(|style| style.is_dimmed()).or_log(false)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_style_has_icon(style: *mut ValueBox<PhlowStyle>) -> bool {
+    style
+        .with_ref_ok(|style| style.get_icon().is_some())
+        .or_log(false)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_style_get_icon(
+    style: *mut ValueBox<PhlowStyle>,
+    icon: *mut ValueBox<StringBox>,
+) {
+    style
+        .with_ref(|style| {
+            icon.with_mut_ok(|icon| {
+                icon.set_string(style.get_icon().unwrap_or_default().to_string())
+            })
+        })
+        .log();
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_style_drop(style: *mut ValueBox<PhlowStyle>) {
+    style.release();
+}
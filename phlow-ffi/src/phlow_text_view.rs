@@ -1,8 +1,10 @@
+use std::ffi::c_void;
+
 use phlow::{PhlowTextView, PhlowView};
 use string_box::StringBox;
 use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
 
-use crate::with_view;
+use crate::{computation_error, deliver_async, with_view, AsyncDelivery, PhlowComputationCallback};
 
 #[no_mangle]
 pub extern "C" fn phlow_text_view_compute_text(
@@ -10,7 +12,25 @@ pub extern "C" fn phlow_text_view_compute_text(
     text: *mut ValueBox<StringBox>,
 ) {
     with_view(phlow_view, |phlow_view: &PhlowTextView| {
-        text.with_mut_ok(|text| text.set_string(phlow_view.compute_text()))
+        phlow_view
+            .try_compute_text()
+            .map_err(computation_error)
+            .and_then(|computed_text| text.with_mut_ok(|text| text.set_string(computed_text)))
     })
     .log();
 }
+
+#[no_mangle]
+pub extern "C" fn phlow_text_view_compute_text_async(
+    phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
+    callback: PhlowComputationCallback<StringBox>,
+    data: *const c_void,
+) {
+    let computation = with_view(phlow_view, |phlow_view: &PhlowTextView| {
+        let view = phlow_view.clone();
+        Ok(AsyncDelivery::of(&[phlow_view.object()], async move {
+            Some(StringBox::from_string(view.async_compute_text().await))
+        }))
+    });
+    deliver_async(computation, callback, data);
+}
@@ -1,8 +1,111 @@
 use std::any::Any;
+use std::ffi::c_void;
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use futures_executor::ThreadPool;
+
 use string_box::StringBox;
-use value_box::{Result, ReturnBoxerResult, ValueBox, ValueBoxPointer};
+use value_box::{BoxerError, Result, ReturnBoxerResult, ValueBox, ValueBoxPointer};
+
+use phlow::{
+    downcast_view_ref, set_global_time_budget, PhlowComputationError, PhlowObject, PhlowView,
+};
+
+/// Called with the data pointer given by the host and the boxed result of an async computation.
+/// The host takes ownership of the result, which is null if the computation failed
+pub type PhlowComputationCallback<T> = extern "C" fn(*const c_void, *mut ValueBox<T>);
+
+struct PhlowCallbackData(*const c_void);
+
+unsafe impl Send for PhlowCallbackData {}
+
+impl PhlowCallbackData {
+    fn as_ptr(&self) -> *const c_void {
+        self.0
+    }
+}
+
+/// Async computations requested by the host share a pool of worker threads
+fn executor() -> std::result::Result<&'static ThreadPool, BoxerError> {
+    static EXECUTOR: OnceLock<std::result::Result<ThreadPool, String>> = OnceLock::new();
+    EXECUTOR
+        .get_or_init(|| {
+            ThreadPool::builder()
+                .name_prefix("phlow-ffi-")
+                .create()
+                .map_err(|error| error.to_string())
+        })
+        .as_ref()
+        .map_err(|error| BoxerError::AnyError(error.clone().into()))
+}
+
+/// An async computation of a view along with whether it may be evaluated on another thread
+pub struct AsyncDelivery<F> {
+    computation: F,
+    is_thread_safe: bool,
+}
+
+impl<F> AsyncDelivery<F> {
+    /// The computation uses the given objects, it is evaluated on the shared executor
+    /// only if all of them are thread safe, see [`PhlowObject::is_thread_safe`]
+    pub fn of(objects: &[&PhlowObject], computation: F) -> Self {
+        Self {
+            computation,
+            is_thread_safe: objects.iter().all(|object| object.is_thread_safe()),
+        }
+    }
+}
+
+/// Evaluate the computation on the shared executor and deliver its result via the callback,
+/// so that the host is never blocked waiting for an async computation.
+/// Computations over objects that are not thread safe are evaluated on the calling thread
+/// and deliver their result before returning
+pub fn deliver_async<T: Any, F: Future<Output = Option<T>> + Send + 'static>(
+    delivery: Result<AsyncDelivery<F>>,
+    callback: PhlowComputationCallback<T>,
+    data: *const c_void,
+) {
+    let delivery = match delivery {
+        Ok(delivery) => delivery,
+        Err(error) => {
+            Err::<(), _>(error).log();
+            callback(data, std::ptr::null_mut());
+            return;
+        }
+    };
+
+    let deliver = move |data: *const c_void, result: Option<T>| {
+        callback(
+            data,
+            result.map_or(std::ptr::null_mut(), |result| {
+                ValueBox::new(result).into_raw()
+            }),
+        )
+    };
+
+    if !delivery.is_thread_safe {
+        deliver(data, futures_executor::block_on(delivery.computation));
+        return;
+    }
+
+    let executor = match executor() {
+        Ok(executor) => executor,
+        Err(error) => {
+            Err::<(), _>(error).log();
+            callback(data, std::ptr::null_mut());
+            return;
+        }
+    };
 
-use phlow::{downcast_view_ref, PhlowView};
+    let data = PhlowCallbackData(data);
+    let computation = delivery.computation;
+    executor.spawn_ok(async move {
+        let result = computation.await;
+        deliver(data.as_ptr(), result);
+    });
+}
 
 pub fn with_view<T: PhlowView, R: Any>(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
@@ -15,6 +118,21 @@ pub fn with_view<T: PhlowView, R: Any>(
     })
 }
 
+/// Report a computation that exceeded its time budget as an error of the entry point
+pub fn computation_error(error: PhlowComputationError) -> BoxerError {
+    BoxerError::AnyError(Box::new(error))
+}
+
+/// Limit computations of views that do not define their own time budget,
+/// zero milliseconds removes the limit
+#[no_mangle]
+pub extern "C" fn phlow_set_global_time_budget(milliseconds: u64) {
+    set_global_time_budget(match milliseconds {
+        0 => None,
+        milliseconds => Some(Duration::from_millis(milliseconds)),
+    });
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_view_get_type(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
@@ -49,6 +167,18 @@ pub extern "C" fn phlow_view_get_priority(phlow_view: *mut ValueBox<Box<dyn Phlo
         .or_log(0)
 }
 
+/// Return the time budget of the view in milliseconds or zero if it is unlimited
+#[no_mangle]
+pub extern "C" fn phlow_view_get_time_budget(phlow_view: *mut ValueBox<Box<dyn PhlowView>>) -> u64 {
+    phlow_view
+        .with_ref_ok(|phlow_view| {
+            phlow_view
+                .get_time_budget()
+                .map_or(0, |budget| budget.as_millis() as u64)
+        })
+        .or_log(0)
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_view_get_source_code(
     phlow_view: *mut ValueBox<Box<dyn PhlowView>>,
@@ -1,4 +1,4 @@
-use phlow::PhlowViewMethod;
+use phlow::{PhlowObject, PhlowViewMethod};
 use string_box::StringBox;
 use value_box::{ReturnBoxerResult, ValueBox, ValueBoxPointer};
 
@@ -28,6 +28,126 @@ pub extern "C" fn phlow_view_method_get_name(
         .log();
 }
 
+/// Get the path of the file that defines the view method, as given by `file!()`
+#[no_mangle]
+pub extern "C" fn phlow_view_method_get_source_file(
+    phlow_method: *mut ValueBox<PhlowViewMethod>,
+    file: *mut ValueBox<StringBox>,
+) {
+    phlow_method
+        .with_ref(|phlow_method| {
+            file.with_mut_ok(|file| {
+                file.set_string(phlow_method.source_location().file.to_string())
+            })
+        })
+        .log();
+}
+
+/// Return the line of the view method name starting at 1, or 0 if unknown
+#[no_mangle]
+pub extern "C" fn phlow_view_method_get_source_line(
+    phlow_method: *mut ValueBox<PhlowViewMethod>,
+) -> u32 {
+    phlow_method
+        .with_ref_ok(|phlow_method| phlow_method.source_location().line)
+        .or_log(0)
+}
+
+/// Return the column of the view method name starting at 1, or 0 if unknown
+#[no_mangle]
+pub extern "C" fn phlow_view_method_get_source_column(
+    phlow_method: *mut ValueBox<PhlowViewMethod>,
+) -> u32 {
+    phlow_method
+        .with_ref_ok(|phlow_method| phlow_method.source_location().column)
+        .or_log(0)
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_view_method_get_module_path(
+    phlow_method: *mut ValueBox<PhlowViewMethod>,
+    module_path: *mut ValueBox<StringBox>,
+) {
+    phlow_method
+        .with_ref(|phlow_method| {
+            module_path.with_mut_ok(|module_path| {
+                module_path.set_string(phlow_method.extension.module_path().to_string())
+            })
+        })
+        .log();
+}
+
+#[no_mangle]
+pub extern "C" fn phlow_view_method_get_crate_name(
+    phlow_method: *mut ValueBox<PhlowViewMethod>,
+    crate_name: *mut ValueBox<StringBox>,
+) {
+    phlow_method
+        .with_ref(|phlow_method| {
+            crate_name.with_mut_ok(|crate_name| {
+                crate_name.set_string(phlow_method.extension.crate_name().to_string())
+            })
+        })
+        .log();
+}
+
+/// Get the doc comment of the view method, or an empty string if there is none
+#[no_mangle]
+pub extern "C" fn phlow_view_method_get_description(
+    phlow_method: *mut ValueBox<PhlowViewMethod>,
+    description: *mut ValueBox<StringBox>,
+) {
+    phlow_method
+        .with_ref(|phlow_method| {
+            description.with_mut_ok(|description| {
+                description.set_string(
+                    phlow_method
+                        .get_description()
+                        .unwrap_or_default()
+                        .to_string(),
+                )
+            })
+        })
+        .log();
+}
+
+/// Get the title declared by the view attribute, or an empty string if there is none
+#[no_mangle]
+pub extern "C" fn phlow_view_method_get_title(
+    phlow_method: *mut ValueBox<PhlowViewMethod>,
+    title: *mut ValueBox<StringBox>,
+) {
+    phlow_method
+        .with_ref(|phlow_method| {
+            title
+                .with_mut_ok(|title| title.set_string(phlow_method.get_default_title().to_string()))
+        })
+        .log();
+}
+
+/// Return the priority declared by the view attribute or the default priority
+#[no_mangle]
+pub extern "C" fn phlow_view_method_get_priority(
+    phlow_method: *mut ValueBox<PhlowViewMethod>,
+) -> usize {
+    phlow_method
+        .with_ref_ok(|phlow_method| phlow_method.get_default_priority())
+        .or_log(10)
+}
+
+/// Return true if the view of the method applies to the object
+#[no_mangle]
+pub extern "C" fn phlow_view_method_is_applicable_to(
+    phlow_method: *mut ValueBox<PhlowViewMethod>,
+    phlow_object: *mut ValueBox<PhlowObject>,
+) -> bool {
+    phlow_method
+        .with_ref(|phlow_method| {
+            phlow_object.with_ref_ok(|phlow_object| phlow_method.is_applicable_to(phlow_object))
+        })
+        .or_log(false)
+}
+
 #[no_mangle]
 pub extern "C" fn phlow_view_method_drop(phlow_method: *mut ValueBox<PhlowViewMethod>) {
     phlow_method.release();
@@ -3,9 +3,9 @@
 #[macro_use]
 extern crate phlow;
 
-use phlow_extensions::CoreExtensions;
+use phlow_extensions::{BytesExtensions, CoreExtensions};
 
-import_extensions!(CoreExtensions);
+import_extensions!(CoreExtensions, BytesExtensions);
 
 fn main() {
     phlow_server::serve(phlow!("Hello".to_string()))
@@ -3,10 +3,10 @@
 #[macro_use]
 extern crate phlow;
 
-use phlow_extensions::CoreExtensions;
+use phlow_extensions::{BytesExtensions, CoreExtensions};
 use phlow_server::PhlowServer;
 
-import_extensions!(CoreExtensions);
+import_extensions!(CoreExtensions, BytesExtensions);
 
 fn main() {
     let server = PhlowServer::new(phlow!("Hello".to_string()));
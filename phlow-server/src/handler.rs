@@ -1,11 +1,24 @@
+use std::future::Future;
 use std::str::FromStr;
+use std::time::Duration;
 
-use phlow::{PhlowObject, PhlowObjectId, PhlowViewSpecification};
+use phlow::{
+    phlow, within_time_budget, PhlowComputationError, PhlowDiff, PhlowDiffMode, PhlowObject,
+    PhlowObjectId, PhlowProgress, PhlowProtoView, PhlowView, PhlowViewMethod,
+    PhlowViewSpecification,
+};
+use tokio::runtime::Handle;
 use urlencoding::decode;
 use uuid::Uuid;
-use warp::{Rejection, reply, Reply};
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{reply, Rejection, Reply};
 
-use crate::{PhlowObjectDescription, PhlowServer, PhlowViewSpecificationDataNode};
+use crate::{
+    PhlowDiffQuery, PhlowObjectDescription, PhlowProgressRegistration, PhlowServer,
+    PhlowViewSourceDescription, PhlowViewSpecificationDataNode, PhlowViewSpecificationItemsQuery,
+    PhlowViewsQuery,
+};
 
 pub async fn session(server: PhlowServer) -> Result<impl Reply, Rejection> {
     Ok(reply::json(&server.session().to_string()))
@@ -23,6 +36,18 @@ pub async fn object(id: PhlowObjectId, server: PhlowServer) -> Result<impl Reply
     Ok(reply::json(&server.retrieve_object(id)))
 }
 
+/// Invalidate cached computations of the object, return false if there is no such object
+pub async fn refresh_object(
+    id: PhlowObjectId,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let object = server.find_object(id);
+    if let Some(object) = object.as_ref() {
+        object.mark_changed();
+    }
+    Ok(reply::json(&object.is_some()))
+}
+
 pub async fn release_object(
     session: String,
     id: PhlowObjectId,
@@ -33,16 +58,32 @@ pub async fn release_object(
     Ok(reply::json(&probably_deleted_object.is_some()))
 }
 
-pub async fn object_views(id: PhlowObjectId, server: PhlowServer) -> Result<impl Reply, Rejection> {
-    let views = server
-        .registered_object_description_by_id_views(id)
-        .unwrap_or_else(|| vec![]);
-    let specs = views
+pub async fn object_views(
+    id: PhlowObjectId,
+    query: PhlowViewsQuery,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let views = match query.view_type {
+        None => server.registered_object_description_by_id_views(id),
+        Some(view_type) => server
+            .find_object(id)
+            .map(|object| object.phlow_views_of_type(view_type)),
+    }
+    .unwrap_or_else(|| vec![]);
+    let budgeted_specs = views
         .into_iter()
-        .map(|view| view.as_view_specification())
-        .filter(|view| view.is_some())
-        .map(|view| view.unwrap())
-        .collect::<Vec<Box<dyn PhlowViewSpecification>>>();
+        .filter_map(|view| BudgetedViewSpecification::for_view(view.as_ref()))
+        .collect::<Vec<BudgetedViewSpecification>>();
+
+    // views exceeding their time budget are replaced by text views describing the error
+    let mut specs = vec![];
+    for spec in budgeted_specs {
+        let failed_view = spec.failed_view.clone();
+        match spec.retrieve(retrieve_included_data).await {
+            Ok(spec) => specs.push(spec),
+            Err(error) => specs.extend(failed_view.specification(error).await),
+        }
+    }
 
     Ok(reply::json(&specs))
 }
@@ -52,35 +93,205 @@ pub async fn object_view(
     view_selector: String,
     server: PhlowServer,
 ) -> Result<impl Reply, Rejection> {
-    let spec = find_view_specification_for_object_id(id, view_selector.as_str(), &server);
+    let spec = match find_view_specification_for_object_id(id, view_selector.as_str(), &server) {
+        None => None,
+        Some(spec) => match spec.retrieve(retrieve_included_data).await {
+            Ok(spec) => Some(spec),
+            Err(error) => return Ok(computation_error_reply(error)),
+        },
+    };
 
-    Ok(reply::json(&spec))
+    Ok(reply::json(&spec).into_response())
 }
 
 pub async fn object_view_items(
     id: PhlowObjectId,
     view_selector: String,
+    query: PhlowViewSpecificationItemsQuery,
     server: PhlowServer,
 ) -> Result<impl Reply, Rejection> {
     let spec = find_view_specification_for_object_id(id, view_selector.as_str(), &server);
     if let Some(spec) = spec {
-        let items: Vec<PhlowViewSpecificationDataNode> = spec
-            .retrieve_items()
-            .await
+        let registration = spec.register_progress(id, view_selector.as_str(), &server);
+        let items = spec
+            .retrieve(move |spec| async move {
+                match (query.offset, query.limit) {
+                    (None, None) => spec.retrieve_items().await,
+                    // without a limit all items from the offset on are retrieved,
+                    // range computations still receive them page by page
+                    (offset, limit) => {
+                        spec.retrieve_items_range(offset.unwrap_or(0), limit.unwrap_or(usize::MAX))
+                            .await
+                    }
+                }
+            })
+            .await;
+        drop(registration);
+        let items = match items {
+            Ok(items) => items,
+            Err(error) => return Ok(computation_error_reply(error)),
+        };
+
+        let items: Vec<PhlowViewSpecificationDataNode> = items
             .into_iter()
             .map(|item| {
                 let object = item.phlow_object().clone();
+                let spawned_objects = item
+                    .spawned_objects()
+                    .into_iter()
+                    .map(|cell_object| {
+                        cell_object.map(|cell_object| server.register_object(cell_object))
+                    })
+                    .collect();
                 PhlowViewSpecificationDataNode {
                     phlow_object: server.register_object(object.clone()),
                     node_id: object.object_id(),
                     node_value: item,
+                    spawned_objects,
                 }
             })
             .collect();
 
-        return Ok(reply::json(&items));
+        return Ok(reply::json(&items).into_response());
     }
-    return Ok(reply::json(&None::<Vec<PhlowViewSpecificationDataNode>>));
+    return Ok(reply::json(&None::<Vec<PhlowViewSpecificationDataNode>>).into_response());
+}
+
+pub async fn object_view_items_count(
+    id: PhlowObjectId,
+    view_selector: String,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let spec = find_view_specification_for_object_id(id, view_selector.as_str(), &server);
+    let count = match spec {
+        None => None,
+        Some(spec) => {
+            let registration = spec.register_progress(id, view_selector.as_str(), &server);
+            let count = spec
+                .retrieve(|spec| async move { spec.retrieve_items_count().await })
+                .await;
+            drop(registration);
+            match count {
+                Ok(count) => count,
+                Err(error) => return Ok(computation_error_reply(error)),
+            }
+        }
+    };
+    Ok(reply::json(&count).into_response())
+}
+
+/// Progress of the items computation of a view that is being retrieved,
+/// or null if there is no such computation in flight
+pub async fn object_view_progress(
+    id: PhlowObjectId,
+    view_selector: String,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let report = decode(view_selector.as_str())
+        .ok()
+        .and_then(|view_selector| server.find_progress(id, view_selector))
+        .map(|progress| progress.get_report());
+    Ok(reply::json(&report))
+}
+
+/// Location and source code of the method that defines a view
+pub async fn object_view_source(
+    id: PhlowObjectId,
+    view_selector: String,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let source = server.find_object(id).and_then(|object| {
+        let view_selector = decode(view_selector.as_str()).ok()?;
+        object
+            .phlow_view_by_selector(view_selector)
+            .map(|view| PhlowViewSourceDescription::new(view.get_defining_method()))
+    });
+    Ok(reply::json(&source))
+}
+
+pub async fn object_view_bitmap(
+    id: PhlowObjectId,
+    view_selector: String,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let spec = find_view_specification_for_object_id(id, view_selector.as_str(), &server);
+    let bitmap = match spec {
+        None => None,
+        Some(spec) => {
+            let bitmap = spec
+                .retrieve(|spec| async move { spec.retrieve_bitmap_specification().await })
+                .await;
+            match bitmap {
+                Ok(bitmap) => bitmap,
+                Err(error) => return Ok(computation_error_reply(error)),
+            }
+        }
+    };
+    Ok(reply::json(&bitmap).into_response())
+}
+
+pub async fn object_view_png(
+    id: PhlowObjectId,
+    view_selector: String,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let spec = find_view_specification_for_object_id(id, view_selector.as_str(), &server)
+        .ok_or_else(warp::reject::not_found)?;
+    let bitmap = match spec
+        .retrieve(|spec| async move { spec.retrieve_bitmap().await })
+        .await
+    {
+        Ok(bitmap) => bitmap.ok_or_else(warp::reject::not_found)?,
+        Err(error) => return Ok(computation_error_reply(error)),
+    };
+    let png = match bitmap.to_png() {
+        Ok(png) => png,
+        Err(error) => {
+            return Ok(reply::with_status(
+                reply::json(&error.to_string()),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            )
+            .into_response())
+        }
+    };
+
+    Ok(reply::with_header(png, "content-type", "image/png").into_response())
+}
+
+pub async fn object_view_svg(
+    id: PhlowObjectId,
+    view_selector: String,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let spec = find_view_specification_for_object_id(id, view_selector.as_str(), &server)
+        .ok_or_else(warp::reject::not_found)?;
+    let svg = match spec
+        .retrieve(|spec| async move { spec.retrieve_svg().await })
+        .await
+    {
+        Ok(svg) => svg.ok_or_else(warp::reject::not_found)?,
+        Err(error) => return Ok(computation_error_reply(error)),
+    };
+
+    Ok(reply::with_header(svg, "content-type", "image/svg+xml").into_response())
+}
+
+pub async fn object_view_dot(
+    id: PhlowObjectId,
+    view_selector: String,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let spec = find_view_specification_for_object_id(id, view_selector.as_str(), &server)
+        .ok_or_else(warp::reject::not_found)?;
+    let dot = match spec
+        .retrieve(|spec| async move { spec.retrieve_dot().await })
+        .await
+    {
+        Ok(dot) => dot.ok_or_else(warp::reject::not_found)?,
+        Err(error) => return Ok(computation_error_reply(error)),
+    };
+
+    Ok(reply::with_header(dot, "content-type", "text/vnd.graphviz").into_response())
 }
 
 pub async fn object_view_sent_item(
@@ -89,7 +300,7 @@ pub async fn object_view_sent_item(
     selected_object_id: PhlowObjectId,
     server: PhlowServer,
 ) -> Result<impl Reply, Rejection> {
-    let none_reply = Ok(reply::json(&None::<PhlowObjectDescription>));
+    let none_reply = Ok(reply::json(&None::<PhlowObjectDescription>).into_response());
 
     let inspected_object = match server.find_object(inspected_object_id) {
         None => {
@@ -111,20 +322,152 @@ pub async fn object_view_sent_item(
         Some(object) => object,
     };
 
-    let object_to_send = match view_spec.retrieve_sent_item(&selected_object).await {
-        None => return none_reply,
-        Some(object) => object,
+    let object_to_send = view_spec
+        .retrieve(move |spec| async move { spec.retrieve_sent_item(&selected_object).await })
+        .await;
+    let object_to_send = match object_to_send {
+        Ok(None) => return none_reply,
+        Ok(Some(object)) => object,
+        Err(error) => return Ok(computation_error_reply(error)),
     };
 
     let object_description = server.register_object(object_to_send);
-    Ok(reply::json(&object_description))
+    Ok(reply::json(&object_description).into_response())
+}
+
+/// Register a diff of two registered objects and return its description
+pub async fn objects_diff(
+    left_id: PhlowObjectId,
+    right_id: PhlowObjectId,
+    query: PhlowDiffQuery,
+    server: PhlowServer,
+) -> Result<impl Reply, Rejection> {
+    let (left, right) = match (server.find_object(left_id), server.find_object(right_id)) {
+        (Some(left), Some(right)) => (left, right),
+        _ => return Ok(reply::json(&None::<PhlowObjectDescription>)),
+    };
+
+    let mode = match (query.view, query.debug) {
+        (Some(view), _) => PhlowDiffMode::Items(view),
+        (None, true) => PhlowDiffMode::Debug,
+        (None, false) => PhlowDiffMode::Display,
+    };
+    let diff = PhlowDiff::new(left, right).mode(mode);
+
+    Ok(reply::json(&Some(server.register_object(phlow!(diff)))))
+}
+
+/// A view specification together with the time budget of its view and its items progress
+struct BudgetedViewSpecification {
+    specification: Box<dyn PhlowViewSpecification>,
+    time_budget: Option<Duration>,
+    progress: Option<PhlowProgress>,
+    // the retrieval may run on a worker thread only if the inspected object is thread safe
+    is_thread_safe: bool,
+    failed_view: FailedView,
+}
+
+impl BudgetedViewSpecification {
+    fn for_view(view: &dyn PhlowView) -> Option<Self> {
+        view.as_view_specification_builder()
+            .create_view_specification()
+            .map(|specification| Self {
+                progress: specification.get_items_progress(),
+                specification,
+                time_budget: view.get_time_budget(),
+                is_thread_safe: view.object().is_thread_safe(),
+                failed_view: FailedView {
+                    object: view.object().clone(),
+                    defining_method: view.get_defining_method().clone(),
+                    title: view.get_title().to_string(),
+                },
+            })
+    }
+
+    /// Make the progress of the items computation available for polling
+    /// until the registration is dropped
+    fn register_progress(
+        &self,
+        id: PhlowObjectId,
+        view_selector: &str,
+        server: &PhlowServer,
+    ) -> Option<PhlowProgressRegistration> {
+        let progress = self.progress.clone()?;
+        let view_selector = decode(view_selector).ok()?.into_owned();
+        Some(server.register_progress(id, view_selector, progress))
+    }
+
+    /// Retrieve data of the specification on a computation worker when the view has a time
+    /// budget, so that an endless or deadlocked computation does not hang the request.
+    /// Views of objects that are not thread safe are retrieved in place and can only be
+    /// given up while the retrieval awaits
+    async fn retrieve<R: Send + 'static, F: Future<Output = R> + Send + 'static>(
+        self,
+        retrieval: impl FnOnce(Box<dyn PhlowViewSpecification>) -> F + Send + 'static,
+    ) -> Result<R, PhlowComputationError> {
+        let specification = self.specification;
+        match self.time_budget {
+            None => Ok(retrieval(specification).await),
+            Some(budget) if !self.is_thread_safe => {
+                tokio::time::timeout(budget, retrieval(specification))
+                    .await
+                    .map_err(|_| PhlowComputationError::TimedOut(budget))
+            }
+            Some(_) => {
+                let runtime = Handle::current();
+                within_time_budget(self.time_budget, move || {
+                    runtime.block_on(retrieval(specification))
+                })
+                .await
+            }
+        }
+    }
+}
+
+/// Describes a view to replace it by a text view with an error once it fails
+#[derive(Clone)]
+struct FailedView {
+    object: PhlowObject,
+    defining_method: PhlowViewMethod,
+    title: String,
+}
+
+impl FailedView {
+    async fn specification(
+        self,
+        error: PhlowComputationError,
+    ) -> Option<Box<dyn PhlowViewSpecification>> {
+        let specification = PhlowProtoView::new(self.object, self.defining_method)
+            .error(error)
+            .title(self.title)
+            .as_view_specification_builder()
+            .create_view_specification()?;
+        Some(retrieve_included_data(specification).await)
+    }
+}
+
+async fn retrieve_included_data(
+    mut specification: Box<dyn PhlowViewSpecification>,
+) -> Box<dyn PhlowViewSpecification> {
+    specification.retrieve_included_data().await;
+    specification
+}
+
+fn computation_error_reply(error: PhlowComputationError) -> Response {
+    let status = match error {
+        PhlowComputationError::TimedOut(_) | PhlowComputationError::Cancelled => {
+            StatusCode::GATEWAY_TIMEOUT
+        }
+        PhlowComputationError::Failed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    reply::with_status(reply::json(&error.to_string()), status).into_response()
 }
 
 fn find_view_specification_for_object_id(
     id: PhlowObjectId,
     view_selector: &str,
     server: &PhlowServer,
-) -> Option<Box<dyn PhlowViewSpecification>> {
+) -> Option<BudgetedViewSpecification> {
     server
         .find_object(id)
         .and_then(|object| find_view_specification_for_object(&object, view_selector))
@@ -133,11 +476,9 @@ fn find_view_specification_for_object_id(
 fn find_view_specification_for_object(
     object: &PhlowObject,
     view_selector: &str,
-) -> Option<Box<dyn PhlowViewSpecification>> {
+) -> Option<BudgetedViewSpecification> {
     let view_selector = decode(view_selector).ok()?;
-    let views = object.phlow_views();
-    let view = views
-        .into_iter()
-        .find(|each| each.get_defining_method().full_method_name.as_str() == view_selector);
-    view.and_then(|view| view.as_view_specification())
+    object
+        .phlow_view_by_selector(view_selector)
+        .and_then(|view| BudgetedViewSpecification::for_view(view.as_ref()))
 }
@@ -13,16 +13,16 @@ use uuid::Uuid;
 use warp::Filter;
 
 use phlow::{
-    define_extensions, import_extensions, phlow, PhlowObject, PhlowObjectId, PhlowView,
-    PhlowViewSpecificationListingItem,
+    define_extensions, import_extensions, phlow, PhlowObject, PhlowObjectId, PhlowProgress,
+    PhlowView, PhlowViewMethod, PhlowViewSpecificationListingItem,
 };
-use phlow_extensions::CoreExtensions;
+use phlow_extensions::{BytesExtensions, CoreExtensions};
 
 mod extensions;
 mod handler;
 
 define_extensions!(PhlowServerExtensions);
-import_extensions!(CoreExtensions, PhlowServerExtensions);
+import_extensions!(CoreExtensions, BytesExtensions, PhlowServerExtensions);
 
 #[derive(Clone, Debug)]
 pub struct PhlowServer(Arc<RwLock<PhlowServerData>>);
@@ -34,6 +34,30 @@ struct PhlowServerData {
     session: Uuid,
     routes: Vec<(String, String)>,
     server_object_id: PhlowObjectId,
+    /// Progress of items computations in flight, by object id and view selector.
+    /// Concurrent requests of the same view register their progress side by side
+    progresses: HashMap<(PhlowObjectId, String), Vec<(u64, PhlowProgress)>>,
+    next_progress_registration: u64,
+}
+
+/// Keeps the progress of an items computation available for polling until it is dropped
+#[must_use = "the progress is unregistered once the registration is dropped"]
+pub struct PhlowProgressRegistration {
+    server: PhlowServer,
+    key: (PhlowObjectId, String),
+    registration: u64,
+}
+
+impl Drop for PhlowProgressRegistration {
+    fn drop(&mut self) {
+        let mut data = self.server.0.write();
+        if let Some(progresses) = data.progresses.get_mut(&self.key) {
+            progresses.retain(|(registration, _)| *registration != self.registration);
+            if progresses.is_empty() {
+                data.progresses.remove(&self.key);
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +75,62 @@ pub struct PhlowViewSpecificationDataNode {
     pub phlow_object: PhlowObjectDescription,
     pub node_id: PhlowObjectId,
     pub node_value: Box<dyn PhlowViewSpecificationListingItem>,
+    /// Registered cell objects of columns that spawn objects
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub spawned_objects: Vec<Option<PhlowObjectDescription>>,
+}
+
+/// Where the defining method of a view is, so that clients can jump to it
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhlowViewSourceDescription {
+    method_selector: String,
+    method_name: String,
+    file: String,
+    line: u32,
+    column: u32,
+    module_path: String,
+    crate_name: String,
+    source_code: String,
+}
+
+impl PhlowViewSourceDescription {
+    pub fn new(method: &PhlowViewMethod) -> Self {
+        let location = method.source_location();
+        Self {
+            method_selector: method.full_method_name.clone(),
+            method_name: method.method_name.clone(),
+            file: location.file.to_string(),
+            line: location.line,
+            column: location.column,
+            module_path: method.extension.module_path().to_string(),
+            crate_name: method.extension.crate_name().to_string(),
+            source_code: method.source_code().to_string(),
+        }
+    }
+}
+
+/// Optional paging of the items of a view: `?offset=&limit=`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PhlowViewSpecificationItemsQuery {
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+/// Optional filtering of views by their type: `?type=list_view`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PhlowViewsQuery {
+    #[serde(rename = "type")]
+    pub view_type: Option<String>,
+}
+
+/// How objects are compared: `?view=` diffs the items of the view with that method name,
+/// `?debug=true` diffs the `Debug` output, otherwise the `Display` output is compared
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PhlowDiffQuery {
+    pub view: Option<String>,
+    #[serde(default)]
+    pub debug: bool,
 }
 
 impl PhlowObjectDescription {
@@ -83,6 +163,8 @@ impl PhlowServer {
             session: Uuid::new_v4(),
             routes: vec![],
             server_object_id: 0,
+            progresses: Default::default(),
+            next_progress_registration: 0,
         })));
 
         let server_phlow_object = phlow!(server.clone());
@@ -184,6 +266,47 @@ impl PhlowServer {
         description
     }
 
+    /// Let clients poll the progress of an items computation while it is running,
+    /// until the returned registration is dropped
+    pub fn register_progress(
+        &self,
+        id: PhlowObjectId,
+        view_selector: impl Into<String>,
+        progress: PhlowProgress,
+    ) -> PhlowProgressRegistration {
+        let key = (id, view_selector.into());
+        let mut data = self.0.write();
+        let registration = data.next_progress_registration;
+        data.next_progress_registration += 1;
+        data.progresses
+            .entry(key.clone())
+            .or_default()
+            .push((registration, progress));
+
+        PhlowProgressRegistration {
+            server: self.clone(),
+            key,
+            registration,
+        }
+    }
+
+    /// Return the progress of the most recently started computation of the view that is still
+    /// running, or of the most recent one if all of them finished
+    pub fn find_progress(
+        &self,
+        id: PhlowObjectId,
+        view_selector: impl Into<String>,
+    ) -> Option<PhlowProgress> {
+        let data = self.0.read();
+        let progresses = data.progresses.get(&(id, view_selector.into()))?;
+        progresses
+            .iter()
+            .rev()
+            .find(|(_, progress)| !progress.is_finished())
+            .or_else(|| progresses.last())
+            .map(|(_, progress)| progress.clone())
+    }
+
     pub fn registered_object_description_by_id_views(
         &self,
         id: PhlowObjectId,
@@ -205,6 +328,13 @@ macro_rules! get_path {
     });
 }
 
+macro_rules! post_path {
+    ($server:ident, $($pieces:tt)*) => ({
+        $server.add_route("POST", stringify!($($pieces)*));
+        warp::path!($($pieces)*).and(warp::post())
+    });
+}
+
 macro_rules! delete_path {
     ($server:ident, $($pieces:tt)*) => ({
         $server.add_route("DELETE", stringify!($($pieces)*));
@@ -224,6 +354,22 @@ pub fn serve(object: PhlowObject) -> thread::JoinHandle<()> {
 }
 
 pub fn spawn(server: PhlowServer, port: u16) -> thread::JoinHandle<()> {
+    let routes = routes(&server);
+
+    thread::spawn(move || {
+        let rt = Runtime::new().unwrap();
+        rt.block_on(async move {
+            warp::serve(routes).run(([127, 0, 0, 1], port)).await;
+            ()
+        });
+        ()
+    })
+}
+
+/// All endpoints of the server, registering their paths with the server
+pub fn routes(
+    server: &PhlowServer,
+) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
     let session = get_path!(server, "session")
         .and(with_phlow_server(server.clone()))
         .and_then(handler::session);
@@ -242,6 +388,7 @@ pub fn spawn(server: PhlowServer, port: u16) -> thread::JoinHandle<()> {
         .and_then(handler::object);
 
     let object_views = get_path!(server, "objects" / PhlowObjectId / "views")
+        .and(warp::query::<PhlowViewsQuery>())
         .and(with_phlow_server(server.clone()))
         .and_then(handler::object_views);
 
@@ -253,9 +400,59 @@ pub fn spawn(server: PhlowServer, port: u16) -> thread::JoinHandle<()> {
         server,
         "objects" / PhlowObjectId / "views" / String / "items"
     )
+    .and(warp::query::<PhlowViewSpecificationItemsQuery>())
     .and(with_phlow_server(server.clone()))
     .and_then(handler::object_view_items);
 
+    let object_view_items_count = get_path!(
+        server,
+        "objects" / PhlowObjectId / "views" / String / "items" / "count"
+    )
+    .and(with_phlow_server(server.clone()))
+    .and_then(handler::object_view_items_count);
+
+    let object_view_progress = get_path!(
+        server,
+        "objects" / PhlowObjectId / "views" / String / "progress"
+    )
+    .and(with_phlow_server(server.clone()))
+    .and_then(handler::object_view_progress);
+
+    let object_view_source = get_path!(
+        server,
+        "objects" / PhlowObjectId / "views" / String / "source"
+    )
+    .and(with_phlow_server(server.clone()))
+    .and_then(handler::object_view_source);
+
+    let object_view_bitmap = get_path!(
+        server,
+        "objects" / PhlowObjectId / "views" / String / "bitmap"
+    )
+    .and(with_phlow_server(server.clone()))
+    .and_then(handler::object_view_bitmap);
+
+    let object_view_png = get_path!(
+        server,
+        "objects" / PhlowObjectId / "views" / String / "bitmap.png"
+    )
+    .and(with_phlow_server(server.clone()))
+    .and_then(handler::object_view_png);
+
+    let object_view_svg = get_path!(
+        server,
+        "objects" / PhlowObjectId / "views" / String / "canvas.svg"
+    )
+    .and(with_phlow_server(server.clone()))
+    .and_then(handler::object_view_svg);
+
+    let object_view_dot = get_path!(
+        server,
+        "objects" / PhlowObjectId / "views" / String / "graph.dot"
+    )
+    .and(with_phlow_server(server.clone()))
+    .and_then(handler::object_view_dot);
+
     let object_view_sent_item = get_path!(
         server,
         "objects" / PhlowObjectId / "views" / String / "send" / PhlowObjectId
@@ -263,28 +460,37 @@ pub fn spawn(server: PhlowServer, port: u16) -> thread::JoinHandle<()> {
     .and(with_phlow_server(server.clone()))
     .and_then(handler::object_view_sent_item);
 
+    let objects_diff = get_path!(server, "objects" / PhlowObjectId / "diff" / PhlowObjectId)
+        .and(warp::query::<PhlowDiffQuery>())
+        .and(with_phlow_server(server.clone()))
+        .and_then(handler::objects_diff);
+
+    let refresh_object = post_path!(server, "objects" / PhlowObjectId / "refresh")
+        .and(with_phlow_server(server.clone()))
+        .and_then(handler::refresh_object);
+
     let release_object = delete_path!(server, "session" / String / "objects" / PhlowObjectId)
         .and(with_phlow_server(server.clone()))
         .and_then(handler::release_object);
 
-    let routes = session
+    session
         .or(server_id)
         .or(objects)
         .or(object)
+        .or(refresh_object)
         .or(release_object)
         .or(object_views)
         .or(object_view)
         .or(object_view_items)
-        .or(object_view_sent_item);
-
-    thread::spawn(move || {
-        let rt = Runtime::new().unwrap();
-        rt.block_on(async move {
-            warp::serve(routes).run(([127, 0, 0, 1], port)).await;
-            ()
-        });
-        ()
-    })
+        .or(object_view_items_count)
+        .or(object_view_progress)
+        .or(object_view_source)
+        .or(object_view_bitmap)
+        .or(object_view_png)
+        .or(object_view_svg)
+        .or(object_view_dot)
+        .or(object_view_sent_item)
+        .or(objects_diff)
 }
 
 fn get_available_port() -> Option<u16> {
@@ -0,0 +1,76 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+use phlow::{PhlowBitmap, PhlowView};
+use phlow_server::{routes, PhlowServer};
+use serde_json::Value;
+
+define_extensions!(TestExtensions);
+import_extensions!(TestExtensions);
+
+pub struct Pixel(u8);
+
+#[phlow::extensions(TestExtensions, Pixel)]
+impl PixelExtensions {
+    #[phlow::view]
+    fn preview_for(_this: &Pixel, view: impl PhlowView) -> impl PhlowView {
+        view.bitmap()
+            .title("Preview")
+            .async_bitmap::<Pixel>(|pixel| {
+                let value = pixel.0;
+                Box::pin(async move { PhlowBitmap::rgba8(vec![value; 8], 2, 1).unwrap() })
+            })
+    }
+}
+
+const SELECTOR: &str = "PixelExtensions%3A%3Apreview_for";
+
+async fn get_json(server: &PhlowServer, path: String) -> Value {
+    let response = warp::test::request()
+        .method("GET")
+        .path(&path)
+        .reply(&routes(server))
+        .await;
+    assert_eq!(response.status(), 200, "GET {}", path);
+    serde_json::from_slice(response.body()).unwrap()
+}
+
+#[tokio::test]
+pub async fn bitmap_is_not_included_in_the_view() {
+    let pixel = phlow!(Pixel(42));
+    let server = PhlowServer::new(pixel.clone());
+
+    let view = get_json(
+        &server,
+        format!("/objects/{}/views/{}", pixel.object_id(), SELECTOR),
+    )
+    .await;
+    assert_eq!(view["title"], "Preview");
+    assert_eq!(view["dataTransport"], 2);
+    assert!(view.get("bitmap").is_none());
+}
+
+#[tokio::test]
+pub async fn bitmap_endpoint() {
+    let pixel = phlow!(Pixel(42));
+    let server = PhlowServer::new(pixel.clone());
+
+    let bitmap = get_json(
+        &server,
+        format!("/objects/{}/views/{}/bitmap", pixel.object_id(), SELECTOR),
+    )
+    .await;
+    assert_eq!(bitmap["width"], 2);
+    assert_eq!(bitmap["height"], 1);
+    assert_eq!(bitmap["pixels"], "KioqKioqKio=");
+
+    let unknown_view = get_json(
+        &server,
+        format!("/objects/{}/views/unknown/bitmap", pixel.object_id()),
+    )
+    .await;
+    assert!(unknown_view.is_null());
+}
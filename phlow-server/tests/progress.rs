@@ -0,0 +1,38 @@
+#[macro_use]
+extern crate phlow;
+
+use phlow::PhlowProgress;
+use phlow_server::PhlowServer;
+
+define_extensions!(TestExtensions);
+import_extensions!(TestExtensions);
+
+pub struct Numbers;
+
+fn progress(message: &str) -> PhlowProgress {
+    let progress = PhlowProgress::new();
+    progress.message(message);
+    progress
+}
+
+#[test]
+pub fn concurrent_progress_registrations() {
+    let numbers = phlow!(Numbers);
+    let id = numbers.object_id();
+    let server = PhlowServer::new(numbers);
+    let message = |server: &PhlowServer| {
+        server
+            .find_progress(id, "items_for")
+            .map(|progress| progress.get_message())
+    };
+
+    let items = server.register_progress(id, "items_for", progress("items"));
+    let count = server.register_progress(id, "items_for", progress("count"));
+    assert_eq!(message(&server), Some("count".to_string()));
+
+    drop(count);
+    assert_eq!(message(&server), Some("items".to_string()));
+
+    drop(items);
+    assert_eq!(message(&server), None);
+}
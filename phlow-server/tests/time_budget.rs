@@ -0,0 +1,71 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+use std::thread;
+use std::time::Duration;
+
+use phlow::{PhlowCancellationToken, PhlowView};
+use phlow_server::{routes, PhlowServer};
+use serde_json::Value;
+
+define_extensions!(TestExtensions);
+import_extensions!(TestExtensions);
+
+pub struct Numbers(usize);
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl NumbersExtensions {
+    #[phlow::view]
+    fn endless_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .title("Endless")
+            .time_budget(Duration::from_millis(50))
+            .text::<Numbers>(|_numbers| {
+                let token = PhlowCancellationToken::current();
+                while !token.is_cancelled() {
+                    thread::sleep(Duration::from_millis(1));
+                }
+                "cancelled".to_string()
+            })
+    }
+
+    #[phlow::view]
+    fn count_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .title("Count")
+            .time_budget(Duration::from_secs(10))
+            .text::<Numbers>(|numbers| numbers.0.to_string())
+    }
+}
+
+#[tokio::test]
+pub async fn exceeded_view_is_an_error_view() {
+    let numbers = phlow!(Numbers(3));
+    let server = PhlowServer::new(numbers.clone());
+
+    let response = warp::test::request()
+        .method("GET")
+        .path(&format!("/objects/{}/views", numbers.object_id()))
+        .reply(&routes(&server))
+        .await;
+    assert_eq!(response.status(), 200);
+
+    let views: Value = serde_json::from_slice(response.body()).unwrap();
+    let views = views.as_array().unwrap();
+    assert_eq!(views.len(), 2);
+    let view = |title: &str| {
+        views
+            .iter()
+            .find(|view| view["title"] == title)
+            .unwrap()
+            .clone()
+    };
+    assert_eq!(view("Count")["string"], "3");
+    assert_eq!(
+        view("Endless")["string"],
+        "Error: Computation did not finish within 50 ms"
+    );
+}
@@ -1,10 +1,11 @@
 // printing.rs requires #specialization to detect if type implements Debug or Display
-// to provide some printing capabilities to all types.
+// to provide some printing capabilities to all types. Extensions, phlow objects and
+// view specifications rely on it regardless of the enabled features.
 #![allow(incomplete_features)]
-#![cfg_attr(feature = "printing", feature(specialization))]
+#![feature(specialization)]
 
 #[cfg(feature = "phlow-derive")]
-pub use phlow_derive::{extensions, view};
+pub use phlow_derive::{extensions, view, PhlowVariant};
 
 pub extern crate log;
 
@@ -12,12 +13,14 @@ pub use crate::meta::*;
 pub use crate::object::*;
 pub use crate::printing::*;
 pub use crate::reflection::*;
+pub use crate::variant::*;
 pub use crate::views::*;
 
 mod meta;
 mod object;
 mod printing;
 mod reflection;
+mod variant;
 mod views;
 
 pub trait Phlow<Category> {
@@ -1,6 +1,6 @@
 use std::any;
 use std::any::type_name;
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 use std::sync::Arc;
 
 use crate::{
@@ -14,6 +14,9 @@ pub struct PhlowExtension {
     view_methods_fn: Arc<dyn Fn(&PhlowExtension) -> Vec<PhlowViewMethod> + Send + Sync + 'static>,
     category: &'static str,
     target: &'static str,
+    module_path: &'static str,
+    crate_name: &'static str,
+    description: &'static str,
 }
 
 impl PhlowExtension {
@@ -22,9 +25,25 @@ impl PhlowExtension {
             view_methods_fn: Arc::new(|extension| T::phlow_view_methods(extension)),
             category: any::type_name::<Category>(),
             target: any::type_name::<T>(),
+            module_path: "",
+            crate_name: "",
+            description: "",
         }
     }
 
+    /// Describe the extension, e.g. with the doc comment of its impl block
+    pub fn described_as(mut self, description: &'static str) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// Remember where the extension is defined, given by `module_path!()` and the crate name
+    pub fn defined_in(mut self, module_path: &'static str, crate_name: &'static str) -> Self {
+        self.module_path = module_path;
+        self.crate_name = crate_name;
+        self
+    }
+
     pub fn category_name(&self) -> &str {
         self.category
     }
@@ -33,6 +52,20 @@ impl PhlowExtension {
         self.target
     }
 
+    /// The module path of the extension, empty if unknown
+    pub fn module_path(&self) -> &str {
+        self.module_path
+    }
+
+    /// The name of the crate that defines the extension, empty if unknown
+    pub fn crate_name(&self) -> &str {
+        self.crate_name
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        Some(self.description).filter(|description| !description.is_empty())
+    }
+
     pub fn view_methods(&self) -> Vec<PhlowViewMethod> {
         (self.view_methods_fn)(self)
     }
@@ -43,10 +76,29 @@ impl Debug for PhlowExtension {
         f.debug_struct(any::type_name::<Self>())
             .field("category", &self.category)
             .field("target", &self.target)
+            .field("module_path", &self.module_path)
+            .field("crate_name", &self.crate_name)
             .finish()
     }
 }
 
+/// Where a view method is defined, lines and columns start at 1
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+pub struct PhlowSourceLocation {
+    pub file: &'static str,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl Display for PhlowSourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+pub type PhlowViewCondition = Arc<dyn Fn(&PhlowObject) -> bool + Send + Sync>;
+
 #[derive(Clone)]
 #[repr(C)]
 pub struct PhlowViewMethod {
@@ -56,16 +108,62 @@ pub struct PhlowViewMethod {
     pub method_name: String,
     pub full_method_name: String,
     pub source_code: String,
+    /// Location of the name of the view method
+    pub source_location: PhlowSourceLocation,
+    /// The doc comment of the view method
+    pub description: Option<String>,
+    /// Default title of the view given by `#[phlow::view(title = "...")]`
+    pub title: Option<String>,
+    /// Default priority of the view given by `#[phlow::view(priority = ...)]`
+    pub priority: Option<usize>,
+    /// Decides whether the view applies to an object, given by `#[phlow::view(when = ...)]`
+    pub condition: Option<PhlowViewCondition>,
 }
 
 impl PhlowViewMethod {
+    /// Build the view for the object unless the view does not apply to it
     pub fn as_view(&self, object: &PhlowObject) -> Option<Box<dyn PhlowView>> {
+        if !self.is_applicable_to(object) {
+            return None;
+        }
         (self.method)(object, self)
     }
 
     pub fn source_code(&self) -> &str {
         self.source_code.as_str()
     }
+
+    pub fn source_location(&self) -> &PhlowSourceLocation {
+        &self.source_location
+    }
+
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn get_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn get_priority(&self) -> Option<usize> {
+        self.priority
+    }
+
+    /// The title views start with before their building code sets one
+    pub fn get_default_title(&self) -> &str {
+        self.get_title().unwrap_or_default()
+    }
+
+    /// The priority views start with before their building code sets one
+    pub fn get_default_priority(&self) -> usize {
+        self.priority.unwrap_or(10)
+    }
+
+    pub fn is_applicable_to(&self, object: &PhlowObject) -> bool {
+        self.condition
+            .as_ref()
+            .is_none_or(|condition| condition(object))
+    }
 }
 
 impl Debug for PhlowViewMethod {
@@ -101,6 +199,13 @@ impl PrintExtensions {
             .map(|func| format!("{:?}", &Fmt(|f| func(value, f))))
     }
 
+    /// Multi-line `{:#?}` representation
+    pub fn debug_pretty_string(&self, value: &AnyValue) -> Option<String> {
+        self.debug_fmt_fn
+            .as_ref()
+            .map(|func| format!("{:#?}", Fmt(|f| func(value, f))))
+    }
+
     pub fn display_string(&self, value: &AnyValue) -> Option<String> {
         self.display_fmt_fn
             .as_ref()
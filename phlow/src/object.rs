@@ -2,12 +2,15 @@ use std::any::{type_name, Any, TypeId};
 use std::ffi::c_void;
 use std::fmt::{Binary, Debug, Formatter, Octal, UpperHex};
 use std::ops::{Deref, DerefMut};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
 
 use parking_lot::lock_api::{MappedRwLockReadGuard, MappedRwLockWriteGuard, RwLockReadGuard};
 use parking_lot::{RawRwLock, RwLock, RwLockWriteGuard};
 
-use crate::{AnyValue, PhlowExtension, PhlowView, PhlowViewMethod, PrintExtensions};
+use crate::{
+    AnyValue, PhlowCompositeView, PhlowExtension, PhlowView, PhlowViewMethod, PrintExtensions,
+};
 
 pub type PhlowObjectId = i64;
 
@@ -21,10 +24,48 @@ struct PhlowObjectData {
     // meta description of the type with the necessary vtables
     phlow_type: PhlowType,
     generic_types: Vec<PhlowType>,
+    // incremented whenever the value is replaced or mutated to invalidate cached computations
+    version: AtomicU64,
+    // the amount of objects that keep this one alive as their parent
+    children: AtomicUsize,
     #[cfg(feature = "object-id")]
     id: PhlowObjectId,
 }
 
+impl Drop for PhlowObjectData {
+    fn drop(&mut self) {
+        if let Some(parent) = self.parent.as_ref() {
+            parent.0.children.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+}
+
+/// Identifies a phlow object without keeping its value alive
+#[derive(Clone)]
+pub struct PhlowObjectIdentity(Weak<PhlowObjectData>);
+
+impl PhlowObjectIdentity {
+    pub fn is_identity_of(&self, object: &PhlowObject) -> bool {
+        std::ptr::eq(self.0.as_ptr(), Arc::as_ptr(&object.0))
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.0.strong_count() > 0
+    }
+
+    /// Return true if the object is kept alive by anything but the objects that borrow from it.
+    /// Items computed from an object refer to it as their parent, an object that is only kept
+    /// alive by such items is no longer used
+    pub fn is_used(&self) -> bool {
+        self.0
+            .upgrade()
+            // the upgraded reference itself is not a use
+            .is_some_and(|data| {
+                Arc::strong_count(&data) - 1 > data.children.load(Ordering::Acquire)
+            })
+    }
+}
+
 impl PhlowObject {
     pub fn object<T: Any>(
         object: T,
@@ -71,11 +112,16 @@ impl PhlowObject {
         generic_types: Vec<PhlowType>,
         parent: Option<PhlowObject>,
     ) -> Self {
+        if let Some(parent) = parent.as_ref() {
+            parent.0.children.fetch_add(1, Ordering::AcqRel);
+        }
         Self(Arc::new(PhlowObjectData {
             parent,
             value: RwLock::new(value),
             phlow_type,
             generic_types,
+            version: AtomicU64::new(0),
+            children: AtomicUsize::new(0),
             #[cfg(feature = "object-id")]
             id: unique_id::Generator::<i64>::next_id(
                 &unique_id::sequence::SequenceGenerator::default(),
@@ -99,11 +145,48 @@ impl PhlowObject {
         self.with_value(|value| self.0.phlow_type.value_to_string(value))
     }
 
+    /// Return the `Display` representation if the value implements it
+    pub fn to_display_string(&self) -> Option<String> {
+        self.with_value(|value| self.0.phlow_type.value_to_display_string(value))
+    }
+
+    /// Return the pretty-printed `Debug` representation if the value implements it
+    pub fn to_debug_string(&self) -> Option<String> {
+        self.with_value(|value| self.0.phlow_type.value_to_debug_string(value))
+    }
+
     #[cfg(feature = "object-id")]
     pub fn object_id(&self) -> PhlowObjectId {
         self.0.id
     }
 
+    pub fn identity(&self) -> PhlowObjectIdentity {
+        PhlowObjectIdentity(Arc::downgrade(&self.0))
+    }
+
+    /// Return the amount of times the value was replaced or mutated
+    pub fn version(&self) -> u64 {
+        self.0.version.load(Ordering::Acquire)
+    }
+
+    /// Return true if the value and the values it borrows from may be used from other threads.
+    /// Phlow objects can always be moved between threads, but computations over objects
+    /// that are not thread safe must stay on the thread that uses them
+    pub fn is_thread_safe(&self) -> bool {
+        self.0.phlow_type.is_thread_safe()
+            && self
+                .0
+                .parent
+                .as_ref()
+                .is_none_or(|parent| parent.is_thread_safe())
+    }
+
+    /// Invalidate cached computations of this object, for example after changing
+    /// its value through interior mutability
+    pub fn mark_changed(&self) {
+        self.0.version.fetch_add(1, Ordering::AcqRel);
+    }
+
     /// Return true if phlow object contains a value - object or reference.
     /// Note, that even though has_value() may return true, it does not mean
     /// that the value can actually be taken, because it does not check
@@ -117,6 +200,7 @@ impl PhlowObject {
     pub fn take_value<T: Any>(&self) -> Option<T> {
         let mut writer = self.0.value.write();
         let previous = std::mem::replace(&mut *writer, AnyValue::None);
+        self.mark_changed();
         previous.take_value()
     }
 
@@ -124,6 +208,7 @@ impl PhlowObject {
     pub fn replace_value<T: Any>(&self, object: T) -> Option<T> {
         let mut writer = self.0.value.write();
         let previous = std::mem::replace(&mut *writer, AnyValue::object(object));
+        self.mark_changed();
         previous.take_value()
     }
 
@@ -140,10 +225,12 @@ impl PhlowObject {
         self.0.value.read()
     }
 
+    /// Mutably borrow the value, cached computations of this object are invalidated
     pub fn value_mut<T: Any>(&self) -> Option<MappedRwLockWriteGuard<'_, RawRwLock, T>> {
-        RwLockWriteGuard::try_map(self.0.value.write(), |value| value.as_mut_safe())
-            .map(|reference| Some(reference))
-            .unwrap_or(None)
+        let reference =
+            RwLockWriteGuard::try_map(self.0.value.write(), |value| value.as_mut_safe()).ok()?;
+        self.mark_changed();
+        Some(reference)
     }
 
     pub fn value_ref<T: Any>(&self) -> Option<MappedRwLockReadGuard<'_, RawRwLock, T>> {
@@ -183,12 +270,41 @@ impl PhlowObject {
             .and_then(|each_method| each_method.as_view(&self))
     }
 
+    /// Find a view by the full name of its defining method.
+    /// Sub-views of composite views are addressed by appending their indices, e.g. `name#0`
+    pub fn phlow_view_by_selector(&self, selector: impl AsRef<str>) -> Option<Box<dyn PhlowView>> {
+        PhlowCompositeView::find_view(self.phlow_views(), selector.as_ref())
+    }
+
+    /// Return views sorted by priority, then by title and then by the full method name
     pub fn phlow_views(&self) -> Vec<Box<dyn PhlowView>> {
-        self.phlow_view_methods()
+        let mut views: Vec<Box<dyn PhlowView>> = self
+            .phlow_view_methods()
             .into_iter()
             .map(|each_method| each_method.as_view(&self))
             .filter(|each_view| each_view.is_some())
             .map(|each_view| each_view.unwrap())
+            .collect();
+
+        views.sort_by(|a, b| {
+            a.get_priority()
+                .cmp(&b.get_priority())
+                .then_with(|| a.get_title().cmp(b.get_title()))
+                .then_with(|| {
+                    a.get_defining_method()
+                        .full_method_name
+                        .cmp(&b.get_defining_method().full_method_name)
+                })
+        });
+        views
+    }
+
+    /// Return sorted views of the given type, such as `"list_view"`
+    pub fn phlow_views_of_type(&self, view_type: impl AsRef<str>) -> Vec<Box<dyn PhlowView>> {
+        let view_type = view_type.as_ref();
+        self.phlow_views()
+            .into_iter()
+            .filter(|each_view| each_view.get_view_type() == view_type)
             .collect()
     }
 }
@@ -366,6 +482,23 @@ impl<'value, T: Binary + 'static> Binary for TypedPhlowObjectMut<'value, T> {
     }
 }
 
+fn is_thread_safe<T>() -> bool {
+    trait Detect {
+        fn is_thread_safe() -> bool;
+    }
+    impl<T> Detect for T {
+        default fn is_thread_safe() -> bool {
+            false
+        }
+    }
+    impl<T: Send + Sync> Detect for T {
+        fn is_thread_safe() -> bool {
+            true
+        }
+    }
+    <T as Detect>::is_thread_safe()
+}
+
 #[derive(Debug, Clone)]
 #[repr(C)]
 pub struct PhlowType {
@@ -376,6 +509,8 @@ pub struct PhlowType {
     phlow_extensions: Vec<PhlowExtension>,
     // detects available printable options such as Display, Debug etc..
     print_extensions: PrintExtensions,
+    // values of the type may be used from other threads, see `PhlowObject::is_thread_safe`
+    is_thread_safe: bool,
 }
 
 impl PhlowType {
@@ -394,9 +529,15 @@ impl PhlowType {
             type_id: TypeId::of::<T>(),
             phlow_extensions,
             print_extensions,
+            is_thread_safe: is_thread_safe::<T>(),
         }
     }
 
+    /// Return true if the type is `Send` and `Sync`
+    pub fn is_thread_safe(&self) -> bool {
+        self.is_thread_safe
+    }
+
     pub fn type_name(&self) -> &str {
         self.type_name
     }
@@ -404,4 +545,12 @@ impl PhlowType {
     pub fn value_to_string(&self, value: &AnyValue) -> String {
         self.print_extensions.to_string(value)
     }
+
+    pub fn value_to_display_string(&self, value: &AnyValue) -> Option<String> {
+        self.print_extensions.display_string(value)
+    }
+
+    pub fn value_to_debug_string(&self, value: &AnyValue) -> Option<String> {
+        self.print_extensions.debug_pretty_string(value)
+    }
 }
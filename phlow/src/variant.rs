@@ -0,0 +1,10 @@
+use crate::PhlowObject;
+
+/// Describes the variant of an enum value, usually implemented with `#[derive(PhlowVariant)]`
+pub trait PhlowVariant {
+    fn phlow_variant_name(&self) -> &'static str;
+
+    /// Names and values of the fields of the variant, tuple fields are named by their index.
+    /// The values reference the fields of the value of the object
+    fn phlow_variant_fields(&self, object: &PhlowObject) -> Vec<(String, PhlowObject)>;
+}
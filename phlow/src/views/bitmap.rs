@@ -0,0 +1,317 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::MAX_RASTER_EXTENT;
+
+/// Layout of a single pixel. Multi-byte channels are stored in little-endian order
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+#[repr(u8)]
+pub enum PixelFormat {
+    #[default]
+    RGBA8888,
+    BGRA8888,
+    Gray8,
+    GrayAlpha88,
+    RGB888,
+    RGBA16,
+    RGBAF32,
+}
+
+impl PixelFormat {
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            Self::RGBA8888 | Self::BGRA8888 => 4,
+            Self::Gray8 => 1,
+            Self::GrayAlpha88 => 2,
+            Self::RGB888 => 3,
+            Self::RGBA16 => 8,
+            Self::RGBAF32 => 16,
+        }
+    }
+
+    /// Decode a pixel into normalized `[red, green, blue, alpha]` channels
+    fn read(&self, bytes: &[u8]) -> [f32; 4] {
+        let u8_channel = |index: usize| bytes[index] as f32 / u8::MAX as f32;
+        let u16_channel = |index: usize| {
+            u16::from_le_bytes([bytes[index * 2], bytes[index * 2 + 1]]) as f32 / u16::MAX as f32
+        };
+        let f32_channel = |index: usize| {
+            let mut channel = [0u8; 4];
+            channel.copy_from_slice(&bytes[index * 4..index * 4 + 4]);
+            f32::from_le_bytes(channel)
+        };
+
+        match self {
+            Self::RGBA8888 => [u8_channel(0), u8_channel(1), u8_channel(2), u8_channel(3)],
+            Self::BGRA8888 => [u8_channel(2), u8_channel(1), u8_channel(0), u8_channel(3)],
+            Self::Gray8 => [u8_channel(0), u8_channel(0), u8_channel(0), 1.0],
+            Self::GrayAlpha88 => [u8_channel(0), u8_channel(0), u8_channel(0), u8_channel(1)],
+            Self::RGB888 => [u8_channel(0), u8_channel(1), u8_channel(2), 1.0],
+            Self::RGBA16 => [
+                u16_channel(0),
+                u16_channel(1),
+                u16_channel(2),
+                u16_channel(3),
+            ],
+            Self::RGBAF32 => [
+                f32_channel(0),
+                f32_channel(1),
+                f32_channel(2),
+                f32_channel(3),
+            ],
+        }
+    }
+
+    /// Encode normalized `[red, green, blue, alpha]` channels and append them to the pixels
+    fn write(&self, [red, green, blue, alpha]: [f32; 4], pixels: &mut Vec<u8>) {
+        let to_u8 = |channel: f32| (channel.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8;
+        let to_u16 = |channel: f32| (channel.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16;
+        let gray = 0.299 * red + 0.587 * green + 0.114 * blue;
+
+        match self {
+            Self::RGBA8888 => pixels.extend([to_u8(red), to_u8(green), to_u8(blue), to_u8(alpha)]),
+            Self::BGRA8888 => pixels.extend([to_u8(blue), to_u8(green), to_u8(red), to_u8(alpha)]),
+            Self::Gray8 => pixels.push(to_u8(gray)),
+            Self::GrayAlpha88 => pixels.extend([to_u8(gray), to_u8(alpha)]),
+            Self::RGB888 => pixels.extend([to_u8(red), to_u8(green), to_u8(blue)]),
+            Self::RGBA16 => [red, green, blue, alpha]
+                .into_iter()
+                .for_each(|channel| pixels.extend(to_u16(channel).to_le_bytes())),
+            Self::RGBAF32 => [red, green, blue, alpha]
+                .into_iter()
+                .for_each(|channel| pixels.extend(channel.to_le_bytes())),
+        }
+    }
+}
+
+impl TryFrom<u8> for PixelFormat {
+    type Error = PhlowBitmapError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        [
+            Self::RGBA8888,
+            Self::BGRA8888,
+            Self::Gray8,
+            Self::GrayAlpha88,
+            Self::RGB888,
+            Self::RGBA16,
+            Self::RGBAF32,
+        ]
+        .into_iter()
+        .find(|format| *format as u8 == value)
+        .ok_or(PhlowBitmapError::UnknownFormat(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhlowBitmapError {
+    NegativeExtent { width: i32, height: i32 },
+    StrideTooSmall { stride: i32, row_length: usize },
+    WrongPixelsLength { expected: usize, actual: usize },
+    UnknownFormat(u8),
+    Encoding(String),
+    NonFiniteScale,
+    ExtentTooLarge { width: usize, height: usize },
+}
+
+impl Display for PhlowBitmapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NegativeExtent { width, height } => {
+                write!(f, "Bitmap extent {}x{} must not be negative", width, height)
+            }
+            Self::StrideTooSmall { stride, row_length } => write!(
+                f,
+                "Stride of {} bytes is smaller than a row of {} bytes",
+                stride, row_length
+            ),
+            Self::WrongPixelsLength { expected, actual } => write!(
+                f,
+                "Expected {} bytes of pixels (stride * height), but got {}",
+                expected, actual
+            ),
+            Self::UnknownFormat(format) => write!(f, "Unknown pixel format {}", format),
+            Self::Encoding(error) => write!(f, "Failed to encode or decode bitmap: {}", error),
+            Self::NonFiniteScale => write!(f, "Rasterization scale must be finite"),
+            Self::ExtentTooLarge { width, height } => write!(
+                f,
+                "Bitmap extent {}x{} exceeds the largest extent of {} pixels per side",
+                width, height, MAX_RASTER_EXTENT
+            ),
+        }
+    }
+}
+
+impl Error for PhlowBitmapError {}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+pub struct PhlowBitmap {
+    pixels: Vec<u8>,
+    width: i32,
+    height: i32,
+    /// Amount of bytes per row, including padding
+    stride: i32,
+    format: PixelFormat,
+}
+
+impl PhlowBitmap {
+    /// Create a bitmap checking that the stride fits a row of pixels
+    /// and that there are exactly `stride * height` bytes of pixels
+    pub fn new(
+        pixels: impl ToOwned<Owned = Vec<u8>>,
+        width: i32,
+        height: i32,
+        stride: i32,
+        format: PixelFormat,
+    ) -> Result<Self, PhlowBitmapError> {
+        if width < 0 || height < 0 {
+            return Err(PhlowBitmapError::NegativeExtent { width, height });
+        }
+
+        let row_length = width as usize * format.bytes_per_pixel();
+        if stride < 0 || (stride as usize) < row_length {
+            return Err(PhlowBitmapError::StrideTooSmall { stride, row_length });
+        }
+
+        let pixels = pixels.to_owned();
+        let expected = stride as usize * height as usize;
+        if pixels.len() != expected {
+            return Err(PhlowBitmapError::WrongPixelsLength {
+                expected,
+                actual: pixels.len(),
+            });
+        }
+
+        Ok(Self {
+            pixels,
+            width,
+            height,
+            stride,
+            format,
+        })
+    }
+
+    /// Create a bitmap with rows tightly packed one after another
+    pub fn packed(
+        pixels: impl ToOwned<Owned = Vec<u8>>,
+        width: i32,
+        height: i32,
+        format: PixelFormat,
+    ) -> Result<Self, PhlowBitmapError> {
+        let stride = width.saturating_mul(format.bytes_per_pixel() as i32);
+        Self::new(pixels, width, height, stride, format)
+    }
+
+    pub fn rgba8(
+        pixels: impl ToOwned<Owned = Vec<u8>>,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, PhlowBitmapError> {
+        Self::packed(pixels, width, height, PixelFormat::RGBA8888)
+    }
+
+    pub fn bgra8(
+        pixels: impl ToOwned<Owned = Vec<u8>>,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, PhlowBitmapError> {
+        Self::packed(pixels, width, height, PixelFormat::BGRA8888)
+    }
+
+    pub fn gray8(
+        pixels: impl ToOwned<Owned = Vec<u8>>,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, PhlowBitmapError> {
+        Self::packed(pixels, width, height, PixelFormat::Gray8)
+    }
+
+    pub fn gray_alpha8(
+        pixels: impl ToOwned<Owned = Vec<u8>>,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, PhlowBitmapError> {
+        Self::packed(pixels, width, height, PixelFormat::GrayAlpha88)
+    }
+
+    pub fn rgb8(
+        pixels: impl ToOwned<Owned = Vec<u8>>,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, PhlowBitmapError> {
+        Self::packed(pixels, width, height, PixelFormat::RGB888)
+    }
+
+    pub fn rgba16(channels: &[u16], width: i32, height: i32) -> Result<Self, PhlowBitmapError> {
+        let pixels = channels
+            .iter()
+            .flat_map(|channel| channel.to_le_bytes())
+            .collect::<Vec<u8>>();
+        Self::packed(pixels, width, height, PixelFormat::RGBA16)
+    }
+
+    pub fn rgba_f32(channels: &[f32], width: i32, height: i32) -> Result<Self, PhlowBitmapError> {
+        let pixels = channels
+            .iter()
+            .flat_map(|channel| channel.to_le_bytes())
+            .collect::<Vec<u8>>();
+        Self::packed(pixels, width, height, PixelFormat::RGBAF32)
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        self.pixels.as_slice()
+    }
+
+    pub fn stride(&self) -> i32 {
+        self.stride
+    }
+
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// Convert to a tightly packed bitmap of the given format.
+    /// Color channels are combined into luminance with the Rec. 601 luma weights
+    /// (0.299 red, 0.587 green, 0.114 blue) when converting to gray formats
+    pub fn convert(&self, format: PixelFormat) -> Self {
+        if format == self.format && self.stride as usize == self.row_length() {
+            return self.clone();
+        }
+
+        let bytes_per_pixel = self.format.bytes_per_pixel();
+        let mut pixels = Vec::with_capacity(
+            self.width as usize * self.height as usize * format.bytes_per_pixel(),
+        );
+        for row in self.pixels.chunks(self.stride.max(1) as usize) {
+            for pixel in row[..self.row_length()].chunks_exact(bytes_per_pixel) {
+                format.write(self.format.read(pixel), &mut pixels);
+            }
+        }
+
+        Self {
+            pixels,
+            width: self.width,
+            height: self.height,
+            stride: self.width * format.bytes_per_pixel() as i32,
+            format,
+        }
+    }
+
+    pub fn to_rgba8(&self) -> Self {
+        self.convert(PixelFormat::RGBA8888)
+    }
+
+    fn row_length(&self) -> usize {
+        self.width as usize * self.format.bytes_per_pixel()
+    }
+}
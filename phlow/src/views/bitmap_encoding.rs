@@ -0,0 +1,145 @@
+#[cfg(any(feature = "png", feature = "qoi"))]
+use crate::PixelFormat;
+use crate::{PhlowBitmap, PhlowBitmapError};
+
+/// How pixels of a bitmap are encoded for transport
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+#[cfg_attr(feature = "view-specification", serde(rename_all = "lowercase"))]
+pub enum PhlowBitmapEncoding {
+    /// Pixels as they are in memory, described by the stride and the format
+    #[default]
+    Raw,
+    #[cfg(feature = "png")]
+    Png,
+    #[cfg(feature = "qoi")]
+    Qoi,
+}
+
+impl PhlowBitmap {
+    pub fn encode(&self, encoding: PhlowBitmapEncoding) -> Result<Vec<u8>, PhlowBitmapError> {
+        match encoding {
+            PhlowBitmapEncoding::Raw => Ok(self.pixels().to_vec()),
+            #[cfg(feature = "png")]
+            PhlowBitmapEncoding::Png => self.to_png(),
+            #[cfg(feature = "qoi")]
+            PhlowBitmapEncoding::Qoi => self.to_qoi(),
+        }
+    }
+}
+
+#[cfg(feature = "png")]
+impl PhlowBitmap {
+    /// Encode as PNG keeping the color type and the bit depth of the format where possible
+    pub fn to_png(&self) -> Result<Vec<u8>, PhlowBitmapError> {
+        let (bitmap, color_type, bit_depth) = match self.format() {
+            PixelFormat::Gray8 => (self.convert(self.format()), png::ColorType::Grayscale, 8),
+            PixelFormat::GrayAlpha88 => (
+                self.convert(self.format()),
+                png::ColorType::GrayscaleAlpha,
+                8,
+            ),
+            PixelFormat::RGB888 => (self.convert(self.format()), png::ColorType::Rgb, 8),
+            PixelFormat::RGBA8888 | PixelFormat::BGRA8888 => {
+                (self.to_rgba8(), png::ColorType::Rgba, 8)
+            }
+            PixelFormat::RGBA16 | PixelFormat::RGBAF32 => {
+                (self.convert(PixelFormat::RGBA16), png::ColorType::Rgba, 16)
+            }
+        };
+
+        let mut pixels = bitmap.pixels().to_vec();
+        let bit_depth = if bit_depth == 16 {
+            // PNG stores 16 bit channels in big-endian order
+            pixels
+                .chunks_exact_mut(2)
+                .for_each(|channel| channel.swap(0, 1));
+            png::BitDepth::Sixteen
+        } else {
+            png::BitDepth::Eight
+        };
+
+        let mut bytes = vec![];
+        let mut encoder = png::Encoder::new(&mut bytes, self.width() as u32, self.height() as u32);
+        encoder.set_color(color_type);
+        encoder.set_depth(bit_depth);
+        encoder
+            .write_header()
+            .and_then(|mut writer| {
+                writer.write_image_data(pixels.as_slice())?;
+                writer.finish()
+            })
+            .map_err(|error| PhlowBitmapError::Encoding(error.to_string()))?;
+        Ok(bytes)
+    }
+
+    /// Decode a PNG image. Palette and low bit depth images are expanded to 8 bits per channel,
+    /// 16 bit images are decoded as [`PixelFormat::RGBA16`]
+    pub fn from_png(bytes: &[u8]) -> Result<Self, PhlowBitmapError> {
+        let to_error = |error: png::DecodingError| PhlowBitmapError::Encoding(error.to_string());
+
+        let mut decoder = png::Decoder::new(bytes);
+        decoder.set_transformations(png::Transformations::EXPAND);
+        let mut reader = decoder.read_info().map_err(to_error)?;
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).map_err(to_error)?;
+        pixels.truncate(info.buffer_size());
+
+        let width = info.width as i32;
+        let height = info.height as i32;
+        let channels = info.color_type.samples();
+
+        if info.bit_depth == png::BitDepth::Sixteen {
+            let channels = pixels
+                .chunks_exact(channels * 2)
+                .flat_map(|pixel| {
+                    let channel =
+                        |index: usize| u16::from_be_bytes([pixel[index * 2], pixel[index * 2 + 1]]);
+                    match channels {
+                        1 => [channel(0), channel(0), channel(0), u16::MAX],
+                        2 => [channel(0), channel(0), channel(0), channel(1)],
+                        3 => [channel(0), channel(1), channel(2), u16::MAX],
+                        _ => [channel(0), channel(1), channel(2), channel(3)],
+                    }
+                })
+                .collect::<Vec<u16>>();
+            return Self::rgba16(channels.as_slice(), width, height);
+        }
+
+        let format = match info.color_type {
+            png::ColorType::Grayscale => PixelFormat::Gray8,
+            png::ColorType::GrayscaleAlpha => PixelFormat::GrayAlpha88,
+            png::ColorType::Rgb => PixelFormat::RGB888,
+            png::ColorType::Rgba => PixelFormat::RGBA8888,
+            png::ColorType::Indexed => {
+                return Err(PhlowBitmapError::Encoding(
+                    "Indexed colors were not expanded".to_string(),
+                ))
+            }
+        };
+        Self::new(pixels, width, height, info.line_size as i32, format)
+    }
+}
+
+#[cfg(feature = "qoi")]
+impl PhlowBitmap {
+    /// Encode as QOI, which only supports 8 bit RGB and RGBA images
+    pub fn to_qoi(&self) -> Result<Vec<u8>, PhlowBitmapError> {
+        let bitmap = match self.format() {
+            PixelFormat::Gray8 | PixelFormat::RGB888 => self.convert(PixelFormat::RGB888),
+            _ => self.to_rgba8(),
+        };
+        qoi::encode_to_vec(bitmap.pixels(), self.width() as u32, self.height() as u32)
+            .map_err(|error| PhlowBitmapError::Encoding(error.to_string()))
+    }
+
+    pub fn from_qoi(bytes: &[u8]) -> Result<Self, PhlowBitmapError> {
+        let (header, pixels) = qoi::decode_to_vec(bytes)
+            .map_err(|error| PhlowBitmapError::Encoding(error.to_string()))?;
+        let format = match header.channels {
+            qoi::Channels::Rgb => PixelFormat::RGB888,
+            qoi::Channels::Rgba => PixelFormat::RGBA8888,
+        };
+        Self::packed(pixels, header.width as i32, header.height as i32, format)
+    }
+}
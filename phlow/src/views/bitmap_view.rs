@@ -1,25 +1,33 @@
 use std::any::Any;
 use std::fmt::{Debug, Display, Formatter};
 
-use crate::{PhlowObject, PhlowView, PhlowViewMethod, TypedPhlowObject, TypedPhlowObjectMut};
+use crate::{
+    AsyncComputation, BitmapComputation, PhlowBitmap, PhlowBitmapEncoding, PhlowObject, PhlowView,
+    PhlowViewMethod, SyncComputation, SyncMutComputation,
+};
 
 #[allow(unused)]
+#[derive(Clone)]
 pub struct PhlowBitmapView {
     object: PhlowObject,
     defining_method: PhlowViewMethod,
     title: String,
     priority: usize,
-    bitmap_computation: Box<dyn Fn(&PhlowObject) -> PhlowBitmap>,
+    bitmap_computation: BitmapComputation,
+    encoding: PhlowBitmapEncoding,
 }
 
 impl PhlowBitmapView {
     pub fn new(object: PhlowObject, defining_method: PhlowViewMethod) -> Self {
         Self {
+            title: defining_method.get_default_title().to_string(),
+            priority: defining_method.get_default_priority(),
             object,
             defining_method,
-            title: "".to_string(),
-            priority: 10,
-            bitmap_computation: Box::new(|_object| PhlowBitmap::default()),
+            bitmap_computation: BitmapComputation::Sync(std::sync::Arc::new(|_object| {
+                Some(PhlowBitmap::default())
+            })),
+            encoding: Default::default(),
         }
     }
 
@@ -33,91 +41,50 @@ impl PhlowBitmapView {
         self
     }
 
+    /// Choose how the bitmap is encoded when sent to a remote client
+    pub fn encoding(mut self, encoding: PhlowBitmapEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn get_encoding(&self) -> PhlowBitmapEncoding {
+        self.encoding
+    }
+
     pub fn bitmap<T: 'static>(
         mut self,
-        bitmap_block: impl Fn(TypedPhlowObject<T>) -> PhlowBitmap + 'static,
+        bitmap_block: impl SyncComputation<T, PhlowBitmap>,
     ) -> Self {
-        self.bitmap_computation = Box::new(move |each_object| match each_object.value_ref::<T>() {
-            Some(each_reference) => {
-                bitmap_block(TypedPhlowObject::new(each_object, &each_reference))
-            }
-            None => PhlowBitmap::default(),
-        });
+        self.bitmap_computation = BitmapComputation::new_sync(bitmap_block);
         self
     }
 
     pub fn bitmap_mut<T: 'static>(
         mut self,
-        bitmap_block: impl Fn(TypedPhlowObjectMut<T>) -> PhlowBitmap + 'static,
+        bitmap_block: impl SyncMutComputation<T, PhlowBitmap>,
     ) -> Self {
-        self.bitmap_computation = Box::new(move |each_object| match each_object.value_mut::<T>() {
-            Some(mut each_reference) => {
-                bitmap_block(TypedPhlowObjectMut::new(each_object, &mut each_reference))
-            }
-            None => PhlowBitmap::default(),
-        });
+        self.bitmap_computation = BitmapComputation::new_sync_mut(bitmap_block);
         self
     }
 
-    pub fn compute_bitmap(&self) -> PhlowBitmap {
-        (self.bitmap_computation)(&self.object)
-    }
-}
-
-#[derive(Debug, Default, Clone)]
-#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
-pub struct PhlowBitmap {
-    pixels: Vec<u8>,
-    width: i32,
-    height: i32,
-    stride: i32,
-    format: PixelFormat,
-}
-
-impl PhlowBitmap {
-    pub fn new(
-        pixels: impl ToOwned<Owned = Vec<u8>>,
-        width: i32,
-        height: i32,
-        stride: i32,
-        format: PixelFormat,
+    pub fn async_bitmap<T: 'static>(
+        mut self,
+        bitmap_block: impl AsyncComputation<T, PhlowBitmap>,
     ) -> Self {
-        Self {
-            pixels: pixels.to_owned(),
-            width,
-            height,
-            stride,
-            format,
-        }
-    }
-
-    pub fn rgba8(pixels: impl ToOwned<Owned = Vec<u8>>, width: i32, height: i32) -> Self {
-        Self::new(pixels, width, height, width, PixelFormat::RGBA8888)
-    }
-
-    pub fn bgra8(pixels: impl ToOwned<Owned = Vec<u8>>, width: i32, height: i32) -> Self {
-        Self::new(pixels, width, height, width, PixelFormat::BGRA8888)
-    }
-
-    pub fn pixels(&self) -> &[u8] {
-        self.pixels.as_slice()
+        self.bitmap_computation = BitmapComputation::new_async(bitmap_block);
+        self
     }
 
-    pub fn stride(&self) -> i32 {
-        self.stride
+    pub fn compute_bitmap(&self) -> PhlowBitmap {
+        self.bitmap_computation
+            .value_block_on(&self.object)
+            .unwrap_or_default()
     }
-}
 
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
-pub enum PixelFormat {
-    RGBA8888,
-    BGRA8888,
-}
-
-impl Default for PixelFormat {
-    fn default() -> Self {
-        Self::RGBA8888
+    pub async fn async_compute_bitmap(&self) -> PhlowBitmap {
+        self.bitmap_computation
+            .value_or_else(&self.object, PhlowBitmap::default)
+            .await
     }
 }
 
@@ -130,13 +97,18 @@ impl Debug for PhlowBitmapView {
 impl Display for PhlowBitmapView {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{}", self.title.as_str())?;
+        if let Some(description) = self.defining_method.get_description() {
+            writeln!(f, "{}", description)?;
+        }
         writeln!(f, "{}", "---------------------",)?;
 
         let bitmap = self.compute_bitmap();
         writeln!(
             f,
             "Bitmap: {}x{}; format: {:?}",
-            bitmap.width, bitmap.height, bitmap.format
+            bitmap.width(),
+            bitmap.height(),
+            bitmap.format()
         )?;
 
         Ok(())
@@ -185,13 +157,26 @@ impl PhlowView for PhlowBitmapView {
     }
 }
 
+#[allow(dead_code)]
+const _: () = {
+    fn assert_send<T: Send>() {}
+
+    // RFC 2056
+    fn assert_all() {
+        assert_send::<PhlowBitmapView>();
+    }
+};
+
+#[cfg(feature = "view-specification")]
+pub use specification::PhlowBitmapSpecification;
+
 #[cfg(feature = "view-specification")]
 mod specification {
     use serde::Serialize;
 
     use crate::{
         AsPhlowViewSpecification, PhlowViewSpecification, PhlowViewSpecificationDataTransport,
-        PhlowViewSpecificationListingItem,
+        PhlowViewSpecificationListingItem, PixelFormat,
     };
 
     use base64::{
@@ -210,16 +195,38 @@ mod specification {
         height: i32,
         stride: i32,
         format: PixelFormat,
+        encoding: PhlowBitmapEncoding,
     }
 
     impl PhlowBitmapSpecification {
         pub fn new(bitmap: PhlowBitmap) -> Self {
             Self {
                 pixels: (general_purpose::STANDARD.encode(bitmap.pixels())),
-                width: bitmap.width,
-                height: bitmap.height,
-                stride: bitmap.stride,
-                format: Default::default(),
+                width: bitmap.width(),
+                height: bitmap.height(),
+                stride: bitmap.stride(),
+                format: bitmap.format(),
+                encoding: PhlowBitmapEncoding::Raw,
+            }
+        }
+
+        /// Encode the pixels falling back to raw pixels if the bitmap can not be encoded
+        pub fn encoded(bitmap: PhlowBitmap, encoding: PhlowBitmapEncoding) -> Self {
+            let bytes = match bitmap.encode(encoding) {
+                Ok(bytes) => bytes,
+                Err(error) => {
+                    log::warn!("{}", error);
+                    return Self::new(bitmap);
+                }
+            };
+
+            Self {
+                pixels: general_purpose::STANDARD.encode(bytes),
+                width: bitmap.width(),
+                height: bitmap.height(),
+                stride: bitmap.stride(),
+                format: bitmap.format(),
+                encoding,
             }
         }
     }
@@ -230,8 +237,12 @@ mod specification {
         title: String,
         priority: usize,
         data_transport: PhlowViewSpecificationDataTransport,
-        bitmap: PhlowBitmapSpecification,
+        encoding: PhlowBitmapEncoding,
         method_selector: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip)]
+        phlow_view: PhlowBitmapView,
     }
 
     #[typetag::serialize(name = "GtPhlowBitmapViewSpecification")]
@@ -244,6 +255,17 @@ mod specification {
         async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject> {
             Some(item.clone())
         }
+
+        async fn retrieve_bitmap(&self) -> Option<PhlowBitmap> {
+            Some(self.phlow_view.async_compute_bitmap().await)
+        }
+
+        async fn retrieve_bitmap_specification(&self) -> Option<PhlowBitmapSpecification> {
+            Some(PhlowBitmapSpecification::encoded(
+                self.phlow_view.async_compute_bitmap().await,
+                self.encoding,
+            ))
+        }
     }
 
     impl AsPhlowViewSpecification for PhlowBitmapView {
@@ -251,9 +273,11 @@ mod specification {
             Some(Box::new(PhlowBitmapViewSpecification {
                 title: self.get_title().to_string(),
                 priority: self.get_priority(),
-                data_transport: PhlowViewSpecificationDataTransport::Included,
-                bitmap: PhlowBitmapSpecification::new(self.compute_bitmap()),
+                data_transport: PhlowViewSpecificationDataTransport::Lazy,
+                encoding: self.get_encoding(),
                 method_selector: self.get_defining_method().full_method_name.clone(),
+                description: self.get_defining_method().description.clone(),
+                phlow_view: self.clone(),
             }))
         }
     }
@@ -0,0 +1,487 @@
+use std::fmt::Write;
+
+use crate::PhlowColor;
+
+/// Affine transformation in the form of an SVG matrix:
+/// `x' = a * x + c * y + e` and `y' = b * x + d * y + f`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhlowTransform {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub e: f32,
+    pub f: f32,
+}
+
+impl PhlowTransform {
+    pub const IDENTITY: Self = Self {
+        a: 1.0,
+        b: 0.0,
+        c: 0.0,
+        d: 1.0,
+        e: 0.0,
+        f: 0.0,
+    };
+
+    pub fn translation(x: f32, y: f32) -> Self {
+        Self {
+            e: x,
+            f: y,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn scaling(x: f32, y: f32) -> Self {
+        Self {
+            a: x,
+            d: y,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Rotation clockwise in screen coordinates
+    pub fn rotation(radians: f32) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self {
+            a: cos,
+            b: sin,
+            c: -sin,
+            d: cos,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// A transformation that applies `other` first and then `self`
+    pub fn multiply(&self, other: &Self) -> Self {
+        Self {
+            a: self.a * other.a + self.c * other.b,
+            b: self.b * other.a + self.d * other.b,
+            c: self.a * other.c + self.c * other.d,
+            d: self.b * other.c + self.d * other.d,
+            e: self.a * other.e + self.c * other.f + self.e,
+            f: self.b * other.e + self.d * other.f + self.f,
+        }
+    }
+
+    pub fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+}
+
+impl Default for PhlowTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhlowPathSegment {
+    MoveTo(f32, f32),
+    LineTo(f32, f32),
+    /// Control point followed by the end point
+    QuadTo(f32, f32, f32, f32),
+    /// Two control points followed by the end point
+    CubicTo(f32, f32, f32, f32, f32, f32),
+    Close,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PhlowPath {
+    segments: Vec<PhlowPathSegment>,
+}
+
+impl PhlowPath {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn move_to(mut self, x: f32, y: f32) -> Self {
+        self.segments.push(PhlowPathSegment::MoveTo(x, y));
+        self
+    }
+
+    pub fn line_to(mut self, x: f32, y: f32) -> Self {
+        self.segments.push(PhlowPathSegment::LineTo(x, y));
+        self
+    }
+
+    pub fn quad_to(mut self, control_x: f32, control_y: f32, x: f32, y: f32) -> Self {
+        self.segments
+            .push(PhlowPathSegment::QuadTo(control_x, control_y, x, y));
+        self
+    }
+
+    pub fn cubic_to(
+        mut self,
+        first_x: f32,
+        first_y: f32,
+        second_x: f32,
+        second_y: f32,
+        x: f32,
+        y: f32,
+    ) -> Self {
+        self.segments.push(PhlowPathSegment::CubicTo(
+            first_x, first_y, second_x, second_y, x, y,
+        ));
+        self
+    }
+
+    pub fn close(mut self) -> Self {
+        self.segments.push(PhlowPathSegment::Close);
+        self
+    }
+
+    pub fn get_segments(&self) -> &[PhlowPathSegment] {
+        self.segments.as_slice()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PhlowShape {
+    Line {
+        from: (f32, f32),
+        to: (f32, f32),
+    },
+    Rectangle {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    },
+    Circle {
+        x: f32,
+        y: f32,
+        radius: f32,
+    },
+    Path(PhlowPath),
+    /// Text anchored at its baseline, painted with the fill color
+    Text {
+        x: f32,
+        y: f32,
+        text: String,
+        size: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhlowPaint {
+    fill: Option<PhlowColor>,
+    stroke: Option<PhlowColor>,
+    stroke_width: f32,
+}
+
+impl PhlowPaint {
+    pub fn get_fill(&self) -> Option<PhlowColor> {
+        self.fill
+    }
+
+    pub fn get_stroke(&self) -> Option<PhlowColor> {
+        self.stroke
+    }
+
+    pub fn get_stroke_width(&self) -> f32 {
+        self.stroke_width
+    }
+}
+
+impl Default for PhlowPaint {
+    fn default() -> Self {
+        Self {
+            fill: None,
+            stroke: Some(PhlowColor::BLACK),
+            stroke_width: 1.0,
+        }
+    }
+}
+
+/// Commands are replayed in order, `Save` and `Restore` push and pop the transformation and the paint
+#[derive(Debug, Clone, PartialEq)]
+pub enum PhlowCanvasCommand {
+    Save,
+    Restore,
+    Transform(PhlowTransform),
+    Fill(Option<PhlowColor>),
+    Stroke(Option<PhlowColor>, f32),
+    Draw(PhlowShape),
+}
+
+/// A list of drawing commands on a canvas of the given extent
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PhlowCanvas {
+    width: f32,
+    height: f32,
+    background: Option<PhlowColor>,
+    commands: Vec<PhlowCanvasCommand>,
+}
+
+impl PhlowCanvas {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            background: None,
+            commands: vec![],
+        }
+    }
+
+    pub fn background(mut self, color: PhlowColor) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub fn command(mut self, command: PhlowCanvasCommand) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    pub fn save(self) -> Self {
+        self.command(PhlowCanvasCommand::Save)
+    }
+
+    pub fn restore(self) -> Self {
+        self.command(PhlowCanvasCommand::Restore)
+    }
+
+    pub fn transform(self, transform: PhlowTransform) -> Self {
+        self.command(PhlowCanvasCommand::Transform(transform))
+    }
+
+    pub fn translate(self, x: f32, y: f32) -> Self {
+        self.transform(PhlowTransform::translation(x, y))
+    }
+
+    pub fn scale(self, x: f32, y: f32) -> Self {
+        self.transform(PhlowTransform::scaling(x, y))
+    }
+
+    pub fn rotate(self, radians: f32) -> Self {
+        self.transform(PhlowTransform::rotation(radians))
+    }
+
+    pub fn fill(self, color: PhlowColor) -> Self {
+        self.command(PhlowCanvasCommand::Fill(Some(color)))
+    }
+
+    pub fn no_fill(self) -> Self {
+        self.command(PhlowCanvasCommand::Fill(None))
+    }
+
+    pub fn stroke(self, color: PhlowColor, width: f32) -> Self {
+        self.command(PhlowCanvasCommand::Stroke(Some(color), width))
+    }
+
+    pub fn no_stroke(self) -> Self {
+        self.command(PhlowCanvasCommand::Stroke(None, 0.0))
+    }
+
+    pub fn line(self, from_x: f32, from_y: f32, to_x: f32, to_y: f32) -> Self {
+        self.command(PhlowCanvasCommand::Draw(PhlowShape::Line {
+            from: (from_x, from_y),
+            to: (to_x, to_y),
+        }))
+    }
+
+    pub fn rectangle(self, x: f32, y: f32, width: f32, height: f32) -> Self {
+        self.command(PhlowCanvasCommand::Draw(PhlowShape::Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }))
+    }
+
+    pub fn circle(self, x: f32, y: f32, radius: f32) -> Self {
+        self.command(PhlowCanvasCommand::Draw(PhlowShape::Circle {
+            x,
+            y,
+            radius,
+        }))
+    }
+
+    pub fn path(self, path: PhlowPath) -> Self {
+        self.command(PhlowCanvasCommand::Draw(PhlowShape::Path(path)))
+    }
+
+    pub fn text(self, x: f32, y: f32, text: impl Into<String>, size: f32) -> Self {
+        self.command(PhlowCanvasCommand::Draw(PhlowShape::Text {
+            x,
+            y,
+            text: text.into(),
+            size,
+        }))
+    }
+
+    pub fn get_width(&self) -> f32 {
+        self.width
+    }
+
+    pub fn get_height(&self) -> f32 {
+        self.height
+    }
+
+    pub fn get_background(&self) -> Option<PhlowColor> {
+        self.background
+    }
+
+    pub fn get_commands(&self) -> &[PhlowCanvasCommand] {
+        self.commands.as_slice()
+    }
+
+    /// Replay the commands calling the block for every shape
+    /// with the paint and the transformation in effect at that moment
+    pub fn for_each_shape(&self, mut block: impl FnMut(&PhlowShape, &PhlowPaint, &PhlowTransform)) {
+        let mut transform = PhlowTransform::IDENTITY;
+        let mut paint = PhlowPaint::default();
+        let mut saved = vec![];
+
+        for command in &self.commands {
+            match command {
+                PhlowCanvasCommand::Save => saved.push((transform, paint)),
+                PhlowCanvasCommand::Restore => {
+                    if let Some((saved_transform, saved_paint)) = saved.pop() {
+                        transform = saved_transform;
+                        paint = saved_paint;
+                    }
+                }
+                PhlowCanvasCommand::Transform(each) => transform = transform.multiply(each),
+                PhlowCanvasCommand::Fill(color) => paint.fill = *color,
+                PhlowCanvasCommand::Stroke(color, width) => {
+                    paint.stroke = *color;
+                    paint.stroke_width = *width;
+                }
+                PhlowCanvasCommand::Draw(shape) => block(shape, &paint, &transform),
+            }
+        }
+    }
+
+    pub fn to_svg(&self) -> String {
+        let mut svg = String::new();
+        write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+            w = self.width,
+            h = self.height
+        )
+        .unwrap();
+
+        if let Some(background) = self.background {
+            write!(
+                svg,
+                r#"<rect width="100%" height="100%"{}/>"#,
+                svg_color("fill", Some(background))
+            )
+            .unwrap();
+        }
+
+        self.for_each_shape(|shape, paint, transform| {
+            let mut attributes = match shape {
+                PhlowShape::Text { .. } => svg_color("fill", paint.fill),
+                _ => format!(
+                    "{}{}",
+                    svg_color("fill", paint.fill),
+                    svg_color("stroke", paint.stroke)
+                ),
+            };
+            if paint.stroke.is_some() && !matches!(shape, PhlowShape::Text { .. }) {
+                write!(attributes, r#" stroke-width="{}""#, paint.stroke_width).unwrap();
+            }
+            if !transform.is_identity() {
+                let PhlowTransform { a, b, c, d, e, f } = transform;
+                write!(
+                    attributes,
+                    r#" transform="matrix({} {} {} {} {} {})""#,
+                    a, b, c, d, e, f
+                )
+                .unwrap();
+            }
+
+            match shape {
+                PhlowShape::Line { from, to } => write!(
+                    svg,
+                    r#"<line x1="{}" y1="{}" x2="{}" y2="{}"{}/>"#,
+                    from.0, from.1, to.0, to.1, attributes
+                ),
+                PhlowShape::Rectangle {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => write!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}"{}/>"#,
+                    x, y, width, height, attributes
+                ),
+                PhlowShape::Circle { x, y, radius } => write!(
+                    svg,
+                    r#"<circle cx="{}" cy="{}" r="{}"{}/>"#,
+                    x, y, radius, attributes
+                ),
+                PhlowShape::Path(path) => {
+                    write!(svg, r#"<path d="{}"{}/>"#, svg_path_data(path), attributes)
+                }
+                PhlowShape::Text { x, y, text, size } => write!(
+                    svg,
+                    r#"<text x="{}" y="{}" font-size="{}"{}>{}</text>"#,
+                    x,
+                    y,
+                    size,
+                    attributes,
+                    escape_xml(text)
+                ),
+            }
+            .unwrap();
+        });
+
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+fn svg_color(attribute: &str, color: Option<PhlowColor>) -> String {
+    match color {
+        None => format!(r#" {}="none""#, attribute),
+        Some(color) if color.alpha == u8::MAX => format!(
+            r##" {}="#{:02X}{:02X}{:02X}""##,
+            attribute, color.red, color.green, color.blue
+        ),
+        Some(color) => format!(
+            r##" {a}="#{:02X}{:02X}{:02X}" {a}-opacity="{}""##,
+            color.red,
+            color.green,
+            color.blue,
+            color.alpha as f32 / u8::MAX as f32,
+            a = attribute
+        ),
+    }
+}
+
+fn svg_path_data(path: &PhlowPath) -> String {
+    path.get_segments()
+        .iter()
+        .map(|segment| match segment {
+            PhlowPathSegment::MoveTo(x, y) => format!("M{} {}", x, y),
+            PhlowPathSegment::LineTo(x, y) => format!("L{} {}", x, y),
+            PhlowPathSegment::QuadTo(cx, cy, x, y) => format!("Q{} {} {} {}", cx, cy, x, y),
+            PhlowPathSegment::CubicTo(c1x, c1y, c2x, c2y, x, y) => {
+                format!("C{} {} {} {} {} {}", c1x, c1y, c2x, c2y, x, y)
+            }
+            PhlowPathSegment::Close => "Z".to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
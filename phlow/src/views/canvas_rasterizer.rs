@@ -0,0 +1,393 @@
+use std::f32::consts::TAU;
+
+use crate::{
+    PhlowBitmap, PhlowBitmapError, PhlowCanvas, PhlowColor, PhlowPath, PhlowPathSegment,
+    PhlowShape, PhlowTransform, PixelFormat,
+};
+
+/// The largest width or height in pixels of a rasterized canvas
+pub const MAX_RASTER_EXTENT: usize = 16_384;
+
+/// Amount of scanlines sampled per row of pixels
+const SUBSCANLINES: usize = 4;
+/// Amount of line segments a curve is flattened into
+const CURVE_STEPS: usize = 16;
+
+type Polygon = Vec<(f32, f32)>;
+
+/// A flattened part of a shape, open polylines are not stroked back to their start
+struct Outline {
+    points: Polygon,
+    closed: bool,
+}
+
+impl PhlowCanvas {
+    /// Render the canvas into an RGBA bitmap, multiplying its extent by the scale.
+    /// Text requires fonts and is therefore not rasterized. Fails without allocating
+    /// if the scale is not finite or the bitmap would exceed [`MAX_RASTER_EXTENT`]
+    pub fn rasterize(&self, scale: f32) -> Result<PhlowBitmap, PhlowBitmapError> {
+        if !scale.is_finite() {
+            return Err(PhlowBitmapError::NonFiniteScale);
+        }
+        let width = (self.get_width() * scale).ceil().max(0.0);
+        let height = (self.get_height() * scale).ceil().max(0.0);
+        if width > MAX_RASTER_EXTENT as f32 || height > MAX_RASTER_EXTENT as f32 {
+            // `max` replaces NaN with zero and the casts saturate infinite extents
+            return Err(PhlowBitmapError::ExtentTooLarge {
+                width: width as usize,
+                height: height as usize,
+            });
+        }
+        let (width, height) = (width as usize, height as usize);
+        let mut raster = Raster::new(width, height);
+
+        if let Some(background) = self.get_background() {
+            let whole = vec![vec![
+                (0.0, 0.0),
+                (width as f32, 0.0),
+                (width as f32, height as f32),
+                (0.0, height as f32),
+            ]];
+            raster.fill(&whole, background);
+        }
+
+        let scaling = PhlowTransform::scaling(scale, scale);
+        self.for_each_shape(|shape, paint, transform| {
+            let transform = scaling.multiply(transform);
+            let outlines = shape_outlines(shape);
+
+            if let (Some(fill), false) =
+                (paint.get_fill(), matches!(shape, PhlowShape::Line { .. }))
+            {
+                let polygons = outlines
+                    .iter()
+                    .map(|outline| outline.points.clone())
+                    .collect::<Vec<Polygon>>();
+                raster.fill(&transform_polygons(&polygons, &transform), fill);
+            }
+            if let Some(stroke) = paint.get_stroke() {
+                let strokes = stroke_polygons(&outlines, paint.get_stroke_width() / 2.0);
+                raster.fill(&transform_polygons(&strokes, &transform), stroke);
+            }
+        });
+
+        Ok(raster.into_bitmap())
+    }
+}
+
+/// Premultiplied RGBA pixels
+struct Raster {
+    width: usize,
+    height: usize,
+    pixels: Vec<[f32; 4]>,
+}
+
+impl Raster {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![[0.0; 4]; width * height],
+        }
+    }
+
+    /// Fill the polygons with the non-zero winding rule
+    fn fill(&mut self, polygons: &[Polygon], color: PhlowColor) {
+        let edges = polygons
+            .iter()
+            .flat_map(|polygon| {
+                polygon
+                    .iter()
+                    .zip(polygon.iter().cycle().skip(1))
+                    .map(|(from, to)| (*from, *to))
+            })
+            .filter(|(from, to)| from.1 != to.1)
+            .collect::<Vec<_>>();
+        if edges.is_empty() || self.width == 0 {
+            return;
+        }
+
+        let alpha = color.alpha as f32 / u8::MAX as f32;
+        let source = [
+            color.red as f32 / u8::MAX as f32 * alpha,
+            color.green as f32 / u8::MAX as f32 * alpha,
+            color.blue as f32 / u8::MAX as f32 * alpha,
+            alpha,
+        ];
+
+        let mut coverage = vec![0.0f32; self.width];
+        let mut crossings: Vec<(f32, i32)> = vec![];
+        for row in 0..self.height {
+            coverage.iter_mut().for_each(|each| *each = 0.0);
+
+            for subscanline in 0..SUBSCANLINES {
+                let y = row as f32 + (subscanline as f32 + 0.5) / SUBSCANLINES as f32;
+                crossings.clear();
+                for ((x0, y0), (x1, y1)) in &edges {
+                    let (top, bottom, direction) = if y0 < y1 { (y0, y1, 1) } else { (y1, y0, -1) };
+                    if y >= *top && y < *bottom {
+                        let x = x0 + (y - y0) * (x1 - x0) / (y1 - y0);
+                        crossings.push((x, direction));
+                    }
+                }
+                crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                for pair in crossings.windows(2) {
+                    winding += pair[0].1;
+                    if winding != 0 {
+                        self.cover_span(&mut coverage, pair[0].0, pair[1].0);
+                    }
+                }
+            }
+
+            for (column, amount) in coverage.iter().enumerate() {
+                let amount = amount.min(1.0);
+                if amount > 0.0 {
+                    let pixel = &mut self.pixels[row * self.width + column];
+                    let inverse = 1.0 - source[3] * amount;
+                    for channel in 0..4 {
+                        pixel[channel] = source[channel] * amount + pixel[channel] * inverse;
+                    }
+                }
+            }
+        }
+    }
+
+    fn cover_span(&self, coverage: &mut [f32], from: f32, to: f32) {
+        let from = from.clamp(0.0, self.width as f32);
+        let to = to.clamp(0.0, self.width as f32);
+        if to <= from {
+            return;
+        }
+
+        let first = from.floor() as usize;
+        let last = (to.ceil() as usize).min(self.width);
+        for (column, amount) in coverage.iter_mut().enumerate().take(last).skip(first) {
+            let overlap = to.min(column as f32 + 1.0) - from.max(column as f32);
+            *amount += overlap / SUBSCANLINES as f32;
+        }
+    }
+
+    fn into_bitmap(self) -> PhlowBitmap {
+        let pixels = self
+            .pixels
+            .iter()
+            .flat_map(|[red, green, blue, alpha]| {
+                let unpremultiply = |channel: f32| {
+                    if *alpha > 0.0 {
+                        ((channel / alpha).clamp(0.0, 1.0) * 255.0).round() as u8
+                    } else {
+                        0
+                    }
+                };
+                [
+                    unpremultiply(*red),
+                    unpremultiply(*green),
+                    unpremultiply(*blue),
+                    (alpha.clamp(0.0, 1.0) * 255.0).round() as u8,
+                ]
+            })
+            .collect::<Vec<u8>>();
+
+        PhlowBitmap::packed(
+            pixels,
+            self.width as i32,
+            self.height as i32,
+            PixelFormat::RGBA8888,
+        )
+        .unwrap_or_default()
+    }
+}
+
+/// Flatten the shape into outlines, filling implicitly closes open outlines
+fn shape_outlines(shape: &PhlowShape) -> Vec<Outline> {
+    let closed = |points: Polygon| Outline {
+        points,
+        closed: true,
+    };
+    match shape {
+        PhlowShape::Line { from, to } => vec![Outline {
+            points: vec![*from, *to],
+            closed: false,
+        }],
+        PhlowShape::Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } => vec![closed(vec![
+            (*x, *y),
+            (x + width, *y),
+            (x + width, y + height),
+            (*x, y + height),
+        ])],
+        PhlowShape::Circle { x, y, radius } => vec![closed(circle_polygon(*x, *y, *radius))],
+        PhlowShape::Path(path) => path_outlines(path),
+        PhlowShape::Text { .. } => vec![],
+    }
+}
+
+fn circle_polygon(x: f32, y: f32, radius: f32) -> Polygon {
+    let steps = ((radius * 2.0).ceil() as usize).clamp(16, 256);
+    (0..steps)
+        .map(|step| {
+            let (sin, cos) = (step as f32 / steps as f32 * TAU).sin_cos();
+            (x + radius * cos, y + radius * sin)
+        })
+        .collect()
+}
+
+fn path_outlines(path: &PhlowPath) -> Vec<Outline> {
+    let mut outlines: Vec<Outline> = vec![];
+    let mut current: Polygon = vec![];
+    let mut position = (0.0, 0.0);
+
+    for segment in path.get_segments() {
+        match *segment {
+            PhlowPathSegment::MoveTo(x, y) => {
+                if current.len() > 1 {
+                    outlines.push(Outline {
+                        points: std::mem::take(&mut current),
+                        closed: false,
+                    });
+                }
+                current = vec![(x, y)];
+                position = (x, y);
+            }
+            PhlowPathSegment::LineTo(x, y) => {
+                if current.is_empty() {
+                    current.push(position);
+                }
+                current.push((x, y));
+                position = (x, y);
+            }
+            PhlowPathSegment::QuadTo(cx, cy, x, y) => {
+                if current.is_empty() {
+                    current.push(position);
+                }
+                let (x0, y0) = position;
+                current.extend((1..=CURVE_STEPS).map(|step| {
+                    let t = step as f32 / CURVE_STEPS as f32;
+                    let u = 1.0 - t;
+                    (
+                        u * u * x0 + 2.0 * u * t * cx + t * t * x,
+                        u * u * y0 + 2.0 * u * t * cy + t * t * y,
+                    )
+                }));
+                position = (x, y);
+            }
+            PhlowPathSegment::CubicTo(c1x, c1y, c2x, c2y, x, y) => {
+                if current.is_empty() {
+                    current.push(position);
+                }
+                let (x0, y0) = position;
+                current.extend((1..=CURVE_STEPS).map(|step| {
+                    let t = step as f32 / CURVE_STEPS as f32;
+                    let u = 1.0 - t;
+                    (
+                        u * u * u * x0
+                            + 3.0 * u * u * t * c1x
+                            + 3.0 * u * t * t * c2x
+                            + t * t * t * x,
+                        u * u * u * y0
+                            + 3.0 * u * u * t * c1y
+                            + 3.0 * u * t * t * c2y
+                            + t * t * t * y,
+                    )
+                }));
+                position = (x, y);
+            }
+            PhlowPathSegment::Close => {
+                if let Some(first) = current.first() {
+                    position = *first;
+                }
+                if current.len() > 1 {
+                    outlines.push(Outline {
+                        points: std::mem::take(&mut current),
+                        closed: true,
+                    });
+                }
+            }
+        }
+    }
+    if current.len() > 1 {
+        outlines.push(Outline {
+            points: current,
+            closed: false,
+        });
+    }
+    outlines
+}
+
+/// Outline every segment of the polylines with a rectangle and every vertex with a circle,
+/// all oriented the same way so that their union is filled with the non-zero winding rule
+fn stroke_polygons(outlines: &[Outline], half_width: f32) -> Vec<Polygon> {
+    let mut polygons = vec![];
+    if half_width <= 0.0 {
+        return polygons;
+    }
+
+    for Outline { points, closed } in outlines {
+        let polyline = points;
+        let segments = if *closed {
+            polyline.len()
+        } else {
+            polyline.len().saturating_sub(1)
+        };
+
+        for index in 0..segments {
+            let (x0, y0) = polyline[index];
+            let (x1, y1) = polyline[(index + 1) % polyline.len()];
+            let length = ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt();
+            if length == 0.0 {
+                continue;
+            }
+            let (nx, ny) = (
+                -(y1 - y0) / length * half_width,
+                (x1 - x0) / length * half_width,
+            );
+            polygons.push(vec![
+                (x0 + nx, y0 + ny),
+                (x1 + nx, y1 + ny),
+                (x1 - nx, y1 - ny),
+                (x0 - nx, y0 - ny),
+            ]);
+        }
+
+        for (x, y) in polyline {
+            polygons.push(circle_polygon(*x, *y, half_width));
+        }
+    }
+
+    polygons
+        .into_iter()
+        .map(|mut polygon| {
+            if signed_area(&polygon) < 0.0 {
+                polygon.reverse();
+            }
+            polygon
+        })
+        .collect()
+}
+
+fn signed_area(polygon: &Polygon) -> f32 {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|((x0, y0), (x1, y1))| x0 * y1 - x1 * y0)
+        .sum::<f32>()
+        / 2.0
+}
+
+fn transform_polygons(polygons: &[Polygon], transform: &PhlowTransform) -> Vec<Polygon> {
+    polygons
+        .iter()
+        .map(|polygon| {
+            polygon
+                .iter()
+                .map(|(x, y)| transform.apply(*x, *y))
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,231 @@
+use std::any::Any;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{
+    AsyncComputation, CanvasComputation, PhlowBitmap, PhlowBitmapError, PhlowCanvas, PhlowObject,
+    PhlowView, PhlowViewMethod, SyncComputation, SyncMutComputation,
+};
+
+#[allow(unused)]
+#[derive(Clone)]
+pub struct PhlowCanvasView {
+    object: PhlowObject,
+    defining_method: PhlowViewMethod,
+    title: String,
+    priority: usize,
+    canvas_computation: CanvasComputation,
+}
+
+impl PhlowCanvasView {
+    pub fn new(object: PhlowObject, defining_method: PhlowViewMethod) -> Self {
+        Self {
+            title: defining_method.get_default_title().to_string(),
+            priority: defining_method.get_default_priority(),
+            object,
+            defining_method,
+            canvas_computation: CanvasComputation::Sync(std::sync::Arc::new(|_object| {
+                Some(PhlowCanvas::default())
+            })),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn priority(mut self, priority: usize) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn canvas<T: 'static>(
+        mut self,
+        canvas_block: impl SyncComputation<T, PhlowCanvas>,
+    ) -> Self {
+        self.canvas_computation = CanvasComputation::new_sync(canvas_block);
+        self
+    }
+
+    pub fn canvas_mut<T: 'static>(
+        mut self,
+        canvas_block: impl SyncMutComputation<T, PhlowCanvas>,
+    ) -> Self {
+        self.canvas_computation = CanvasComputation::new_sync_mut(canvas_block);
+        self
+    }
+
+    pub fn async_canvas<T: 'static>(
+        mut self,
+        canvas_block: impl AsyncComputation<T, PhlowCanvas>,
+    ) -> Self {
+        self.canvas_computation = CanvasComputation::new_async(canvas_block);
+        self
+    }
+
+    pub fn compute_canvas(&self) -> PhlowCanvas {
+        self.canvas_computation
+            .value_block_on(&self.object)
+            .unwrap_or_default()
+    }
+
+    pub async fn async_compute_canvas(&self) -> PhlowCanvas {
+        self.canvas_computation
+            .value_or_else(&self.object, PhlowCanvas::default)
+            .await
+    }
+
+    /// Rasterize the canvas for clients that can only display bitmaps
+    pub fn compute_bitmap(&self, scale: f32) -> Result<PhlowBitmap, PhlowBitmapError> {
+        self.compute_canvas().rasterize(scale)
+    }
+}
+
+impl Debug for PhlowCanvasView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhlowCanvasView").finish()
+    }
+}
+
+impl Display for PhlowCanvasView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.title.as_str())?;
+        if let Some(description) = self.defining_method.get_description() {
+            writeln!(f, "{}", description)?;
+        }
+        writeln!(f, "---------------------")?;
+
+        let canvas = self.compute_canvas();
+        writeln!(
+            f,
+            "Canvas: {}x{}; commands: {}",
+            canvas.get_width(),
+            canvas.get_height(),
+            canvas.get_commands().len()
+        )?;
+
+        Ok(())
+    }
+}
+
+impl PhlowView for PhlowCanvasView {
+    fn get_title(&self) -> &str {
+        self.title.as_str()
+    }
+
+    fn get_priority(&self) -> usize {
+        self.priority
+    }
+
+    fn get_view_type(&self) -> &str {
+        Self::view_type()
+    }
+
+    fn get_defining_method(&self) -> &PhlowViewMethod {
+        &self.defining_method
+    }
+
+    fn view_type() -> &'static str
+    where
+        Self: Sized,
+    {
+        "canvas_view"
+    }
+
+    fn object(&self) -> &PhlowObject {
+        &self.object
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn to_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    #[cfg(feature = "view-specification")]
+    fn as_view_specification_builder(&self) -> &dyn crate::AsPhlowViewSpecification {
+        self
+    }
+}
+
+#[allow(dead_code)]
+const _: () = {
+    fn assert_send<T: Send>() {}
+
+    // RFC 2056
+    fn assert_all() {
+        assert_send::<PhlowCanvasView>();
+    }
+};
+
+#[cfg(feature = "view-specification")]
+mod specification {
+    use serde::Serialize;
+
+    use crate::{
+        AsPhlowViewSpecification, PhlowViewSpecification, PhlowViewSpecificationDataTransport,
+        PhlowViewSpecificationListingItem,
+    };
+
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PhlowCanvasViewSpecification {
+        title: String,
+        priority: usize,
+        data_transport: PhlowViewSpecificationDataTransport,
+        svg: String,
+        method_selector: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip)]
+        phlow_view: PhlowCanvasView,
+    }
+
+    #[typetag::serialize(name = "GtPhlowCanvasViewSpecification")]
+    #[async_trait::async_trait]
+    impl PhlowViewSpecification for PhlowCanvasViewSpecification {
+        async fn retrieve_items(&self) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
+            vec![]
+        }
+
+        async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject> {
+            Some(item.clone())
+        }
+
+        async fn retrieve_included_data(&mut self) {
+            self.svg = self.phlow_view.async_compute_canvas().await.to_svg();
+        }
+
+        async fn retrieve_bitmap(&self) -> Option<PhlowBitmap> {
+            match self.phlow_view.async_compute_canvas().await.rasterize(1.0) {
+                Ok(bitmap) => Some(bitmap),
+                Err(error) => {
+                    log::warn!("{}", error);
+                    None
+                }
+            }
+        }
+
+        async fn retrieve_svg(&self) -> Option<String> {
+            Some(self.phlow_view.async_compute_canvas().await.to_svg())
+        }
+    }
+
+    impl AsPhlowViewSpecification for PhlowCanvasView {
+        fn create_view_specification(&self) -> Option<Box<dyn PhlowViewSpecification>> {
+            Some(Box::new(PhlowCanvasViewSpecification {
+                title: self.get_title().to_string(),
+                priority: self.get_priority(),
+                data_transport: PhlowViewSpecificationDataTransport::Included,
+                svg: "".to_string(),
+                method_selector: self.get_defining_method().full_method_name.clone(),
+                description: self.get_defining_method().description.clone(),
+                phlow_view: self.clone(),
+            }))
+        }
+    }
+}
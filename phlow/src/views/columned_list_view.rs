@@ -1,17 +1,62 @@
 use std::any::Any;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
+use futures_util::{Stream, StreamExt};
+
+use crate::views::items_source::ItemsSource;
 use crate::{
-    AsyncComputation, ItemComputation, ItemsComputation, PhlowObject, PhlowView, PhlowViewMethod,
-    SendComputation, SyncComputation, SyncMutComputation, TextComputation,
+    AsyncCancellableComputation, AsyncComputation, AsyncProgressComputation, AsyncRangeComputation,
+    BitmapComputation, ItemComputation, ItemsComputation, ItemsCountComputation,
+    ItemsProgressComputation, ItemsRangeComputation, PhlowBitmap, PhlowComputationError,
+    PhlowObject, PhlowProgress, PhlowStyle, PhlowView, PhlowViewMethod, SendComputation,
+    StyleComputation, SyncComputation, SyncMutComputation, SyncRangeComputation, TextComputation,
 };
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhlowColumnWidth {
+    /// Width in logical pixels
+    Fixed(f32),
+    /// Share of the remaining space relative to other columns
+    Relative(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+#[cfg_attr(feature = "view-specification", serde(rename_all = "lowercase"))]
+#[repr(u8)]
+pub enum PhlowColumnAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum PhlowColumnCellType {
+    #[default]
+    Text,
+    Number,
+    /// A bitmap thumbnail computed by the bitmap computation of the column
+    Bitmap,
+    /// A check box, the cell text is either `true` or `false`
+    Boolean,
+    /// A progress bar, the cell text is a fraction between `0.0` and `1.0`
+    Progress,
+}
+
 #[derive(Clone)]
 pub struct PhlowColumn {
     title: String,
     index: usize,
+    width: Option<PhlowColumnWidth>,
+    alignment: Option<PhlowColumnAlignment>,
+    cell_type: PhlowColumnCellType,
+    spawns_objects: bool,
     item_computation: ItemComputation,
     text_computation: TextComputation,
+    style_computation: Option<StyleComputation>,
+    bitmap_computation: Option<BitmapComputation>,
 }
 
 impl PhlowColumn {
@@ -19,8 +64,14 @@ impl PhlowColumn {
         Self {
             title: "Column".to_string(),
             index: 0,
+            width: None,
+            alignment: None,
+            cell_type: Default::default(),
+            spawns_objects: false,
             item_computation: Default::default(),
             text_computation: Default::default(),
+            style_computation: None,
+            bitmap_computation: None,
         }
     }
 
@@ -29,6 +80,32 @@ impl PhlowColumn {
         self
     }
 
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(PhlowColumnWidth::Fixed(width));
+        self
+    }
+
+    pub fn relative_width(mut self, weight: f32) -> Self {
+        self.width = Some(PhlowColumnWidth::Relative(weight));
+        self
+    }
+
+    pub fn align(mut self, alignment: PhlowColumnAlignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    pub fn cell_type(mut self, cell_type: PhlowColumnCellType) -> Self {
+        self.cell_type = cell_type;
+        self
+    }
+
+    /// When set, clicking a cell spawns its cell object instead of the row's sent object
+    pub fn spawns_objects(mut self, spawns_objects: bool) -> Self {
+        self.spawns_objects = spawns_objects;
+        self
+    }
+
     pub fn item<T: 'static>(
         mut self,
         item_computation: impl SyncComputation<T, PhlowObject>,
@@ -37,47 +114,185 @@ impl PhlowColumn {
         self
     }
 
+    pub fn async_item<T: 'static>(
+        mut self,
+        item_computation: impl AsyncComputation<T, PhlowObject>,
+    ) -> Self {
+        self.item_computation = ItemComputation::new_async(item_computation);
+        self
+    }
+
     pub fn text<T: 'static>(mut self, text_block: impl SyncComputation<T, String>) -> Self {
         self.text_computation = TextComputation::new_sync(text_block);
         self
     }
 
+    pub fn async_text<T: 'static>(mut self, text_block: impl AsyncComputation<T, String>) -> Self {
+        self.text_computation = TextComputation::new_async(text_block);
+        self
+    }
+
+    /// An error returned by the computation becomes the cell object, showing its message
+    pub fn try_item<T: 'static, E: Display + 'static>(
+        mut self,
+        item_computation: impl SyncComputation<T, Result<PhlowObject, E>>,
+    ) -> Self {
+        self.item_computation = ItemComputation::try_new_sync(item_computation);
+        self
+    }
+
+    /// An error returned by the computation is shown as the text of the cell
+    pub fn try_text<T: 'static, E: Display + 'static>(
+        mut self,
+        text_block: impl SyncComputation<T, Result<String, E>>,
+    ) -> Self {
+        self.text_computation = TextComputation::try_new_sync(text_block);
+        self
+    }
+
+    /// Style cells based on the cell object, for example to highlight errors
+    pub fn cell_style<T: 'static>(
+        mut self,
+        style_block: impl SyncComputation<T, PhlowStyle>,
+    ) -> Self {
+        self.style_computation = Some(StyleComputation::new_sync(style_block));
+        self
+    }
+
+    pub fn number<T: 'static>(self, number_block: impl SyncComputation<T, f64>) -> Self {
+        self.cell_type(PhlowColumnCellType::Number)
+            .text::<T>(move |cell| number_block(cell).to_string())
+    }
+
+    pub fn boolean<T: 'static>(self, boolean_block: impl SyncComputation<T, bool>) -> Self {
+        self.cell_type(PhlowColumnCellType::Boolean)
+            .text::<T>(move |cell| boolean_block(cell).to_string())
+    }
+
+    /// Display a progress bar, the computed fraction is clamped between `0.0` and `1.0`
+    pub fn progress<T: 'static>(self, progress_block: impl SyncComputation<T, f32>) -> Self {
+        self.cell_type(PhlowColumnCellType::Progress)
+            .text::<T>(move |cell| progress_block(cell).clamp(0.0, 1.0).to_string())
+    }
+
+    pub fn bitmap<T: 'static>(
+        mut self,
+        bitmap_block: impl SyncComputation<T, PhlowBitmap>,
+    ) -> Self {
+        self.bitmap_computation = Some(BitmapComputation::new_sync(bitmap_block));
+        self.cell_type(PhlowColumnCellType::Bitmap)
+    }
+
+    pub fn async_bitmap<T: 'static>(
+        mut self,
+        bitmap_block: impl AsyncComputation<T, PhlowBitmap>,
+    ) -> Self {
+        self.bitmap_computation = Some(BitmapComputation::new_async(bitmap_block));
+        self.cell_type(PhlowColumnCellType::Bitmap)
+    }
+
     pub fn get_title(&self) -> &str {
         self.title.as_str()
     }
 
+    pub fn get_width(&self) -> Option<PhlowColumnWidth> {
+        self.width
+    }
+
+    /// Return the explicitly set alignment or the default one for the cell type
+    pub fn get_alignment(&self) -> PhlowColumnAlignment {
+        self.alignment.unwrap_or(match self.cell_type {
+            PhlowColumnCellType::Number => PhlowColumnAlignment::Right,
+            PhlowColumnCellType::Text
+            | PhlowColumnCellType::Bitmap
+            | PhlowColumnCellType::Boolean
+            | PhlowColumnCellType::Progress => PhlowColumnAlignment::Left,
+        })
+    }
+
+    pub fn get_cell_type(&self) -> PhlowColumnCellType {
+        self.cell_type
+    }
+
+    pub fn get_spawns_objects(&self) -> bool {
+        self.spawns_objects
+    }
+
     pub fn compute_cell_item(&self, row_object: &PhlowObject) -> Option<PhlowObject> {
         self.item_computation.value_block_on(row_object)
     }
 
+    pub async fn async_compute_cell_item(&self, row_object: &PhlowObject) -> Option<PhlowObject> {
+        self.item_computation.value(row_object).await
+    }
+
     pub fn compute_cell_text(&self, cell_object: &PhlowObject) -> String {
         self.text_computation
             .value_block_on(cell_object)
             .unwrap_or_else(|| "Error coercing item type".to_string())
     }
+
+    pub async fn async_compute_cell_text(&self, cell_object: &PhlowObject) -> String {
+        self.text_computation
+            .value_or_else(cell_object, || "Error coercing item type".to_string())
+            .await
+    }
+
+    pub fn compute_cell_style(&self, cell_object: &PhlowObject) -> Option<PhlowStyle> {
+        self.style_computation
+            .as_ref()
+            .and_then(|computation| computation.value_block_on(cell_object))
+    }
+
+    pub async fn async_compute_cell_style(&self, cell_object: &PhlowObject) -> Option<PhlowStyle> {
+        match &self.style_computation {
+            None => None,
+            Some(computation) => computation.value(cell_object).await,
+        }
+    }
+
+    pub fn compute_cell_bitmap(&self, cell_object: &PhlowObject) -> Option<PhlowBitmap> {
+        self.bitmap_computation
+            .as_ref()
+            .and_then(|computation| computation.value_block_on(cell_object))
+    }
+
+    pub async fn async_compute_cell_bitmap(
+        &self,
+        cell_object: &PhlowObject,
+    ) -> Option<PhlowBitmap> {
+        match &self.bitmap_computation {
+            None => None,
+            Some(computation) => computation.value(cell_object).await,
+        }
+    }
+}
+
+impl Default for PhlowColumn {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[allow(unused)]
 #[derive(Clone)]
 pub struct PhlowColumnedListView {
-    object: PhlowObject,
     defining_method: PhlowViewMethod,
     title: String,
     priority: usize,
     columns: Vec<PhlowColumn>,
-    items_computation: ItemsComputation,
+    items_source: ItemsSource,
     send_computation: SendComputation,
 }
 
 impl PhlowColumnedListView {
     pub fn new(object: PhlowObject, defining_method: PhlowViewMethod) -> Self {
         Self {
-            object,
+            title: defining_method.get_default_title().to_string(),
+            priority: defining_method.get_default_priority(),
+            items_source: ItemsSource::new(object, &defining_method),
             defining_method,
-            title: "".to_string(),
-            priority: 10,
             columns: vec![],
-            items_computation: Default::default(),
             send_computation: Default::default(),
         }
     }
@@ -92,11 +307,26 @@ impl PhlowColumnedListView {
         self
     }
 
+    /// Remember the items computed for up to `cache_size` objects
+    /// until their values are replaced or mutated. Views built by the same view method share
+    /// the remembered values. Caching is disabled by default
+    pub fn cache_size(mut self, cache_size: usize) -> Self {
+        self.items_source.set_cache_size(cache_size);
+        self
+    }
+
+    /// Give up computations that take longer than the budget instead of the global one
+    pub fn time_budget(mut self, time_budget: Duration) -> Self {
+        self.items_source.set_time_budget(time_budget);
+        self
+    }
+
     pub fn items<T: 'static>(
         mut self,
         items_block: impl SyncComputation<T, Vec<PhlowObject>>,
     ) -> Self {
-        self.items_computation = ItemsComputation::new_sync(items_block);
+        self.items_source
+            .set_items_computation(ItemsComputation::new_sync(items_block));
         self
     }
 
@@ -104,7 +334,8 @@ impl PhlowColumnedListView {
         mut self,
         items_block: impl SyncMutComputation<T, Vec<PhlowObject>>,
     ) -> Self {
-        self.items_computation = ItemsComputation::new_sync_mut(items_block);
+        self.items_source
+            .set_items_computation(ItemsComputation::new_sync_mut(items_block));
         self
     }
 
@@ -112,7 +343,78 @@ impl PhlowColumnedListView {
         mut self,
         items_block: impl AsyncComputation<T, Vec<PhlowObject>>,
     ) -> Self {
-        self.items_computation = ItemsComputation::new_async(items_block);
+        self.items_source
+            .set_items_computation(ItemsComputation::new_async(items_block));
+        self
+    }
+
+    /// The computation receives a token that is cancelled once the time budget of the view
+    /// is exceeded, long running computations should check it and stop early
+    pub fn async_items_cancellable<T: 'static>(
+        mut self,
+        items_block: impl AsyncCancellableComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.items_source
+            .set_items_computation(ItemsComputation::new_async_cancellable(items_block));
+        self
+    }
+
+    /// An error returned by the computation is shown as a single item carrying its message
+    pub fn try_items<T: 'static, E: Display + 'static>(
+        mut self,
+        items_block: impl SyncComputation<T, Result<Vec<PhlowObject>, E>>,
+    ) -> Self {
+        self.items_source
+            .set_items_computation(ItemsComputation::try_new_sync(items_block));
+        self
+    }
+
+    pub fn async_try_items<T: 'static, E: Display + 'static>(
+        mut self,
+        items_block: impl AsyncComputation<T, Result<Vec<PhlowObject>, E>>,
+    ) -> Self {
+        self.items_source
+            .set_items_computation(ItemsComputation::try_new_async(items_block));
+        self
+    }
+
+    /// The computation reports its progress and may emit items before it is finished,
+    /// the emitted items are streamed by [`PhlowColumnedListView::async_compute_items`] right
+    /// away. Every computation reports to a progress of its own, see
+    /// [`PhlowColumnedListView::async_compute_items_with_progress`]
+    pub fn async_items_with_progress<T: 'static>(
+        mut self,
+        items_block: impl AsyncProgressComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.items_source
+            .set_items_progress_computation(ItemsProgressComputation::new_async(items_block));
+        self
+    }
+
+    /// Compute at most `count` items starting from the `start` index.
+    /// When set, items are computed lazily page by page instead of all at once.
+    pub fn items_range<T: 'static>(
+        mut self,
+        items_block: impl SyncRangeComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.items_source
+            .set_items_range_computation(ItemsRangeComputation::new_sync(items_block));
+        self
+    }
+
+    pub fn async_items_range<T: 'static>(
+        mut self,
+        items_block: impl AsyncRangeComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.items_source
+            .set_items_range_computation(ItemsRangeComputation::new_async(items_block));
+        self
+    }
+
+    /// Compute the total amount of items without computing the items themselves
+    pub fn items_count<T: 'static>(mut self, count_block: impl SyncComputation<T, usize>) -> Self {
+        self.items_source
+            .set_items_count_computation(ItemsCountComputation::new_sync(count_block));
         self
     }
 
@@ -145,22 +447,95 @@ impl PhlowColumnedListView {
         self
     }
 
+    pub fn async_send<T: 'static>(
+        mut self,
+        item_send_block: impl AsyncComputation<T, PhlowObject>,
+    ) -> Self {
+        self.send_computation = SendComputation::new_async(item_send_block);
+        self
+    }
+
     pub fn compute_items(&self) -> Vec<PhlowObject> {
-        self.items_computation
-            .value_block_on(&self.object)
-            .unwrap_or_default()
+        self.items_source.values_block_on(PhlowProgress::new())
     }
 
-    pub async fn async_compute_items(&self) -> Vec<PhlowObject> {
-        self.items_computation
-            .value_or_else(&self.object, || vec![])
+    pub fn async_compute_items(&self) -> impl Stream<Item = PhlowObject> + '_ {
+        self.async_compute_items_with_progress(PhlowProgress::new())
+    }
+
+    /// Stream the items while reporting the progress of their computation to the given
+    /// progress, created by [`PhlowView::new_items_progress`]
+    pub fn async_compute_items_with_progress(
+        &self,
+        progress: PhlowProgress,
+    ) -> impl Stream<Item = PhlowObject> + '_ {
+        self.items_source.stream(progress)
+    }
+
+    pub async fn async_compute_items_range(&self, start: usize, count: usize) -> Vec<PhlowObject> {
+        self.items_source
+            .range(start, count, PhlowProgress::new())
             .await
     }
 
+    pub fn compute_items_range(&self, start: usize, count: usize) -> Vec<PhlowObject> {
+        self.items_source
+            .range_block_on(start, count, PhlowProgress::new())
+    }
+
+    /// Return the total amount of items if it is known without computing all items
+    pub async fn async_compute_items_count(&self) -> Option<usize> {
+        self.items_source.count(PhlowProgress::new()).await
+    }
+
+    pub fn compute_items_count(&self) -> Option<usize> {
+        self.items_source.count_block_on(PhlowProgress::new())
+    }
+
+    /// Evaluate the block with a copy of the view, giving up once the time budget of the view
+    /// is exceeded. The block runs on a worker thread only if the inspected object is thread safe
+    fn within_time_budget<R: Send + 'static>(
+        &self,
+        block: impl FnOnce(Self) -> R + Send + 'static,
+    ) -> Result<R, PhlowComputationError> {
+        let view = self.clone();
+        self.items_source
+            .within_time_budget(&[], move || block(view))
+    }
+
+    /// Compute all items, giving up once the time budget of the view is exceeded
+    pub fn try_compute_items(&self) -> Result<Vec<PhlowObject>, PhlowComputationError> {
+        self.try_compute_items_with_progress(PhlowProgress::new())
+    }
+
+    pub fn try_compute_items_with_progress(
+        &self,
+        progress: PhlowProgress,
+    ) -> Result<Vec<PhlowObject>, PhlowComputationError> {
+        self.items_source.try_values_block_on(progress)
+    }
+
+    pub fn try_compute_items_range(
+        &self,
+        start: usize,
+        count: usize,
+    ) -> Result<Vec<PhlowObject>, PhlowComputationError> {
+        self.items_source
+            .try_range_block_on(start, count, PhlowProgress::new())
+    }
+
+    pub fn try_compute_items_count(&self) -> Result<Option<usize>, PhlowComputationError> {
+        self.items_source.try_count_block_on(PhlowProgress::new())
+    }
+
     pub fn compute_item_send(&self, item: &PhlowObject) -> Option<PhlowObject> {
         self.send_computation.value_block_on(item)
     }
 
+    pub async fn async_compute_item_send(&self, item: &PhlowObject) -> Option<PhlowObject> {
+        self.send_computation.value(item).await
+    }
+
     pub fn get_columns(&self) -> &[PhlowColumn] {
         self.columns.as_slice()
     }
@@ -175,6 +550,9 @@ impl Debug for PhlowColumnedListView {
 impl Display for PhlowColumnedListView {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{}", self.get_title())?;
+        if let Some(description) = self.defining_method.get_description() {
+            writeln!(f, "{}", description)?;
+        }
         writeln!(f, "{}", "---------------------",)?;
 
         for column in &self.columns {
@@ -185,20 +563,47 @@ impl Display for PhlowColumnedListView {
         }
         writeln!(f, "")?;
 
-        let items = self.compute_items();
-        for row_item in items {
-            for column in &self.columns {
-                let cell_item = column.compute_cell_item(&row_item);
+        // the items and the texts and styles of their cells are computed within a single
+        // time budget
+        let rows = self.within_time_budget(|view| {
+            view.compute_items()
+                .into_iter()
+                .map(|row_item| {
+                    view.columns
+                        .iter()
+                        .map(|column| match column.compute_cell_item(&row_item) {
+                            None => ("Error coercing item type".to_string(), None),
+                            Some(cell_item) => (
+                                column.compute_cell_text(&cell_item),
+                                column.compute_cell_style(&cell_item),
+                            ),
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        });
+        let rows = match rows {
+            Ok(rows) => rows,
+            Err(error) => return writeln!(f, "{}", error),
+        };
+
+        for cells in rows {
+            for (column, (cell_text, cell_style)) in self.columns.iter().zip(cells) {
                 if column.index > 0 {
                     write!(f, " | ")?;
                 }
 
-                let cell_text = cell_item
-                    .as_ref()
-                    .map(|valid_cell_item| column.compute_cell_text(valid_cell_item))
-                    .unwrap_or_else(|| "Error coercing item type".to_string());
+                // pad before painting, escape codes would count towards the width otherwise
+                let cell_text = match column.get_alignment() {
+                    PhlowColumnAlignment::Left => format!("{0: <10}", cell_text),
+                    PhlowColumnAlignment::Center => format!("{0: ^10}", cell_text),
+                    PhlowColumnAlignment::Right => format!("{0: >10}", cell_text),
+                };
 
-                write!(f, "{0: <10}", cell_text)?;
+                match cell_style {
+                    None => write!(f, "{}", cell_text)?,
+                    Some(style) => write!(f, "{}", style.paint(&cell_text))?,
+                }
             }
             writeln!(f, "")?;
         }
@@ -231,8 +636,16 @@ impl PhlowView for PhlowColumnedListView {
         "columned_list_view"
     }
 
+    fn get_time_budget(&self) -> Option<Duration> {
+        self.items_source.get_time_budget()
+    }
+
+    fn new_items_progress(&self) -> Option<PhlowProgress> {
+        self.items_source.new_items_progress()
+    }
+
     fn object(&self) -> &PhlowObject {
-        &self.object
+        self.items_source.object()
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -251,10 +664,13 @@ impl PhlowView for PhlowColumnedListView {
 
 #[cfg(feature = "view-specification")]
 mod specification {
+    use futures_util::stream;
     use serde::Serialize;
 
+    use crate::views::bitmap_view::PhlowBitmapSpecification;
     use crate::views::view_specification::{
-        PhlowViewSpecificationRowValue, PhlowViewSpecificationTextualItemValue,
+        PhlowViewSpecificationBitmapItemValue, PhlowViewSpecificationRowValue,
+        PhlowViewSpecificationTextualItemValue,
     };
     use crate::{
         AsPhlowViewSpecification, PhlowViewSpecification, PhlowViewSpecificationDataTransport,
@@ -270,9 +686,14 @@ mod specification {
         priority: usize,
         data_transport: PhlowViewSpecificationDataTransport,
         method_selector: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
         column_specifications: Vec<PhlowColumnSpecification>,
         #[serde(skip)]
         phlow_view: PhlowColumnedListView,
+        // the items of a specification are computed with a progress of their own
+        #[serde(skip)]
+        progress: Option<PhlowProgress>,
     }
 
     #[derive(Debug, Clone, Serialize)]
@@ -280,46 +701,124 @@ mod specification {
     pub struct PhlowColumnSpecification {
         title: String,
         cell_width: Option<f32>,
+        cell_weight: Option<f32>,
+        alignment: PhlowColumnAlignment,
         spawns_objects: bool,
         r#type: PhlowViewSpecificationListingType,
         properties: Vec<String>,
     }
 
+    impl PhlowColumnSpecification {
+        fn new(column: &PhlowColumn) -> Self {
+            let (cell_width, cell_weight) = match column.get_width() {
+                None => (None, None),
+                Some(PhlowColumnWidth::Fixed(width)) => (Some(width), None),
+                Some(PhlowColumnWidth::Relative(weight)) => (None, Some(weight)),
+            };
+
+            Self {
+                title: column.get_title().to_string(),
+                cell_width,
+                cell_weight,
+                alignment: column.get_alignment(),
+                spawns_objects: column.get_spawns_objects(),
+                r#type: column.get_cell_type().into(),
+                properties: vec![],
+            }
+        }
+    }
+
     #[typetag::serialize(name = "GtPhlowColumnedListViewSpecification")]
     #[async_trait::async_trait]
     impl PhlowViewSpecification for PhlowColumnedListViewSpecification {
         async fn retrieve_items(&self) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
             self.phlow_view
-                .compute_items()
-                .into_iter()
-                .map(|each| {
-                    Box::new(PhlowViewSpecificationRowValue {
-                        phlow_object: each.clone(),
-                        column_values: self
-                            .phlow_view
-                            .columns
-                            .iter()
-                            .map(|column| {
-                                column
-                                    .compute_cell_item(&each)
-                                    .map(|cell_object| column.compute_cell_text(&cell_object))
-                                    .unwrap_or_else(|| "".to_string())
-                            })
-                            .map(|cell_text| PhlowViewSpecificationTextualItemValue {
-                                phlow_object: each.clone(),
-                                item_text: cell_text,
-                            })
-                            .map(|value| {
-                                Box::new(value) as Box<dyn PhlowViewSpecificationListingItem>
-                            })
-                            .collect(),
-                    }) as Box<dyn PhlowViewSpecificationListingItem>
-                })
+                .items_source
+                .stream(self.progress())
+                .then(|each| self.row_value(each))
+                .collect()
+                .await
+        }
+
+        async fn retrieve_items_range(
+            &self,
+            offset: usize,
+            limit: usize,
+        ) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
+            let items = self
+                .phlow_view
+                .items_source
+                .range(offset, limit, self.progress())
+                .await;
+            stream::iter(items)
+                .then(|each| self.row_value(each))
                 .collect()
+                .await
+        }
+
+        async fn retrieve_items_count(&self) -> Option<usize> {
+            self.phlow_view.items_source.count(self.progress()).await
         }
 
         async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject> {
-            self.phlow_view.compute_item_send(item)
+            self.phlow_view.async_compute_item_send(item).await
+        }
+
+        fn get_items_progress(&self) -> Option<PhlowProgress> {
+            self.progress.clone()
+        }
+    }
+
+    impl PhlowColumnedListViewSpecification {
+        fn progress(&self) -> PhlowProgress {
+            self.progress.clone().unwrap_or_default()
+        }
+
+        async fn row_value(&self, each: PhlowObject) -> Box<dyn PhlowViewSpecificationListingItem> {
+            let mut column_values = vec![];
+            let mut spawned_objects = vec![];
+
+            for column in &self.phlow_view.columns {
+                let cell_object = column.async_compute_cell_item(&each).await;
+                column_values.push(Self::cell_value(column, &each, cell_object.as_ref()).await);
+                spawned_objects.push(cell_object.filter(|_| column.get_spawns_objects()));
+            }
+
+            Box::new(PhlowViewSpecificationRowValue {
+                phlow_object: each,
+                column_values,
+                spawned_objects,
+            })
+        }
+
+        async fn cell_value(
+            column: &PhlowColumn,
+            row_object: &PhlowObject,
+            cell_object: Option<&PhlowObject>,
+        ) -> Box<dyn PhlowViewSpecificationListingItem> {
+            let cell_object = match cell_object {
+                None => {
+                    return Box::new(PhlowViewSpecificationTextualItemValue {
+                        phlow_object: row_object.clone(),
+                        item_text: "".to_string(),
+                        item_style: None,
+                    })
+                }
+                Some(cell_object) => cell_object,
+            };
+
+            if let Some(bitmap) = column.async_compute_cell_bitmap(cell_object).await {
+                return Box::new(PhlowViewSpecificationBitmapItemValue {
+                    phlow_object: cell_object.clone(),
+                    bitmap: PhlowBitmapSpecification::new(bitmap),
+                });
+            }
+
+            Box::new(PhlowViewSpecificationTextualItemValue {
+                phlow_object: cell_object.clone(),
+                item_text: column.async_compute_cell_text(cell_object).await,
+                item_style: column.async_compute_cell_style(cell_object).await,
+            })
         }
     }
 
@@ -330,18 +829,14 @@ mod specification {
                 priority: self.get_priority(),
                 data_transport: PhlowViewSpecificationDataTransport::Lazy,
                 method_selector: self.get_defining_method().full_method_name.clone(),
+                description: self.get_defining_method().description.clone(),
                 column_specifications: self
                     .columns
                     .iter()
-                    .map(|column| PhlowColumnSpecification {
-                        title: column.get_title().to_string(),
-                        cell_width: None,
-                        spawns_objects: false,
-                        r#type: PhlowViewSpecificationListingType::Text,
-                        properties: vec![],
-                    })
+                    .map(PhlowColumnSpecification::new)
                     .collect(),
                 phlow_view: self.clone(),
+                progress: self.new_items_progress(),
             }))
         }
     }
@@ -0,0 +1,305 @@
+use std::any::Any;
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
+
+use crate::{
+    downcast_view_ref, PhlowObject, PhlowProtoView, PhlowView, PhlowViewMethod, SyncComputation,
+    TypedPhlowObject,
+};
+
+/// Separates the selector of a composite view from the index of its sub-view,
+/// for example `Extensions::summary_for#1`
+pub const SUB_VIEW_SEPARATOR: char = '#';
+
+type SubViewFn = dyn Fn(&PhlowObject, &PhlowViewMethod) -> Option<Box<dyn PhlowView>> + Send + Sync;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+#[cfg_attr(feature = "view-specification", serde(rename_all = "lowercase"))]
+#[repr(u8)]
+pub enum PhlowCompositeLayout {
+    /// Sub-views are displayed one below another
+    #[default]
+    Stacked,
+    Tabs,
+}
+
+#[allow(unused)]
+#[derive(Clone)]
+pub struct PhlowCompositeView {
+    object: PhlowObject,
+    defining_method: PhlowViewMethod,
+    title: String,
+    priority: usize,
+    layout: PhlowCompositeLayout,
+    sub_views: Vec<Arc<SubViewFn>>,
+}
+
+impl PhlowCompositeView {
+    pub fn new(object: PhlowObject, defining_method: PhlowViewMethod) -> Self {
+        Self {
+            title: defining_method.get_default_title().to_string(),
+            priority: defining_method.get_default_priority(),
+            object,
+            defining_method,
+            layout: Default::default(),
+            sub_views: vec![],
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn priority(mut self, priority: usize) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn stacked(mut self) -> Self {
+        self.layout = PhlowCompositeLayout::Stacked;
+        self
+    }
+
+    pub fn tabs(mut self) -> Self {
+        self.layout = PhlowCompositeLayout::Tabs;
+        self
+    }
+
+    /// Add a sub-view of the same object
+    pub fn view<V: PhlowView>(
+        mut self,
+        view_block: impl Fn(PhlowProtoView) -> V + Send + Sync + 'static,
+    ) -> Self {
+        self.sub_views.push(Arc::new(move |object, method| {
+            let view: Box<dyn PhlowView> = Box::new(view_block(PhlowProtoView::new(
+                object.clone(),
+                method.clone(),
+            )));
+            Some(view)
+        }));
+        self
+    }
+
+    /// Add a sub-view of an object computed from the inspected one
+    pub fn object_view<T: 'static, V: PhlowView>(
+        mut self,
+        object_block: impl SyncComputation<T, PhlowObject>,
+        view_block: impl Fn(PhlowProtoView) -> V + Send + Sync + 'static,
+    ) -> Self {
+        self.sub_views.push(Arc::new(move |object, method| {
+            let sub_object = object
+                .value_ref::<T>()
+                .map(|reference| object_block(TypedPhlowObject::new(object, &reference)))?;
+            let view: Box<dyn PhlowView> =
+                Box::new(view_block(PhlowProtoView::new(sub_object, method.clone())));
+            Some(view)
+        }));
+        self
+    }
+
+    /// Add a view defined by the extensions of an object computed from the inspected one
+    pub fn object_view_named<T: 'static>(
+        mut self,
+        object_block: impl SyncComputation<T, PhlowObject>,
+        view_method_name: impl Into<String>,
+    ) -> Self {
+        let view_method_name = view_method_name.into();
+        self.sub_views.push(Arc::new(move |object, method| {
+            let sub_object = object
+                .value_ref::<T>()
+                .map(|reference| object_block(TypedPhlowObject::new(object, &reference)))?;
+            let mut sub_method = sub_object
+                .phlow_view_methods()
+                .into_iter()
+                .find(|each| each.method_name == view_method_name)?;
+            sub_method.full_method_name = method.full_method_name.clone();
+            sub_method.as_view(&sub_object)
+        }));
+        self
+    }
+
+    pub fn get_layout(&self) -> PhlowCompositeLayout {
+        self.layout
+    }
+
+    pub fn sub_views_count(&self) -> usize {
+        self.sub_views.len()
+    }
+
+    /// Build the sub-view at the index. Its defining method is named after the composite view
+    /// followed by the index, which lets clients address the sub-view like any other view
+    pub fn compute_view_at(&self, index: usize) -> Option<Box<dyn PhlowView>> {
+        let sub_view = self.sub_views.get(index)?;
+        let mut method = self.defining_method.clone();
+        method.full_method_name =
+            format!("{}{}{}", method.full_method_name, SUB_VIEW_SEPARATOR, index);
+        sub_view(&self.object, &method)
+    }
+
+    pub fn compute_views(&self) -> Vec<Box<dyn PhlowView>> {
+        (0..self.sub_views.len())
+            .filter_map(|index| self.compute_view_at(index))
+            .collect()
+    }
+
+    /// Find a view by its selector, descending into composite views for each sub-view index
+    pub fn find_view(views: Vec<Box<dyn PhlowView>>, selector: &str) -> Option<Box<dyn PhlowView>> {
+        let mut parts = selector.split(SUB_VIEW_SEPARATOR);
+        let full_method_name = parts.next()?;
+        let mut view = views
+            .into_iter()
+            .find(|each| each.get_defining_method().full_method_name == full_method_name)?;
+
+        for index in parts {
+            let index = index.parse::<usize>().ok()?;
+            view = downcast_view_ref::<PhlowCompositeView>(&view)
+                .ok()?
+                .compute_view_at(index)?;
+        }
+        Some(view)
+    }
+}
+
+impl Debug for PhlowCompositeView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhlowCompositeView").finish()
+    }
+}
+
+impl Display for PhlowCompositeView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.title.as_str())?;
+        if let Some(description) = self.defining_method.get_description() {
+            writeln!(f, "{}", description)?;
+        }
+        writeln!(f, "=====================")?;
+
+        for (index, view) in self.compute_views().into_iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", view)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PhlowView for PhlowCompositeView {
+    fn get_title(&self) -> &str {
+        self.title.as_str()
+    }
+
+    fn get_priority(&self) -> usize {
+        self.priority
+    }
+
+    fn get_view_type(&self) -> &str {
+        Self::view_type()
+    }
+
+    fn get_defining_method(&self) -> &PhlowViewMethod {
+        &self.defining_method
+    }
+
+    fn view_type() -> &'static str
+    where
+        Self: Sized,
+    {
+        "composite_view"
+    }
+
+    fn object(&self) -> &PhlowObject {
+        &self.object
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn to_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    #[cfg(feature = "view-specification")]
+    fn as_view_specification_builder(&self) -> &dyn crate::AsPhlowViewSpecification {
+        self
+    }
+}
+
+#[allow(dead_code)]
+const _: () = {
+    fn assert_send<T: Send>() {}
+
+    // RFC 2056
+    fn assert_all() {
+        assert_send::<PhlowCompositeView>();
+    }
+};
+
+#[cfg(feature = "view-specification")]
+mod specification {
+    use serde::Serialize;
+
+    use crate::{
+        AsPhlowViewSpecification, PhlowViewSpecification, PhlowViewSpecificationDataTransport,
+        PhlowViewSpecificationListingItem,
+    };
+
+    use super::*;
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PhlowCompositeViewSpecification {
+        title: String,
+        priority: usize,
+        data_transport: PhlowViewSpecificationDataTransport,
+        layout: PhlowCompositeLayout,
+        /// Specifications of the sub-views, their method selectors address them by index
+        views: Vec<Box<dyn PhlowViewSpecification>>,
+        method_selector: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+    }
+
+    #[typetag::serialize(name = "GtPhlowCompositeViewSpecification")]
+    #[async_trait::async_trait]
+    impl PhlowViewSpecification for PhlowCompositeViewSpecification {
+        async fn retrieve_items(&self) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
+            vec![]
+        }
+
+        async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject> {
+            Some(item.clone())
+        }
+
+        async fn retrieve_included_data(&mut self) {
+            for view in self.views.iter_mut() {
+                view.retrieve_included_data().await;
+            }
+        }
+    }
+
+    impl AsPhlowViewSpecification for PhlowCompositeView {
+        fn create_view_specification(&self) -> Option<Box<dyn PhlowViewSpecification>> {
+            Some(Box::new(PhlowCompositeViewSpecification {
+                title: self.get_title().to_string(),
+                priority: self.get_priority(),
+                data_transport: PhlowViewSpecificationDataTransport::Included,
+                layout: self.get_layout(),
+                views: self
+                    .compute_views()
+                    .iter()
+                    .filter_map(|view| {
+                        view.as_view_specification_builder()
+                            .create_view_specification()
+                    })
+                    .collect(),
+                method_selector: self.get_defining_method().full_method_name.clone(),
+                description: self.get_defining_method().description.clone(),
+            }))
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use futures_channel::oneshot;
+use futures_util::FutureExt;
+use parking_lot::{Condvar, Mutex, MutexGuard};
+
+use crate::PhlowObject;
+
+/// Zero means that computations are not limited in time
+static GLOBAL_TIME_BUDGET_MILLIS: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static CURRENT_CANCELLATION_TOKEN: RefCell<Option<PhlowCancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Limit the time of computations of views that do not define their own time budget
+pub fn set_global_time_budget(budget: Option<Duration>) {
+    let millis = budget.map_or(0, |budget| (budget.as_millis() as u64).max(1));
+    GLOBAL_TIME_BUDGET_MILLIS.store(millis, Ordering::Release);
+}
+
+pub fn global_time_budget() -> Option<Duration> {
+    match GLOBAL_TIME_BUDGET_MILLIS.load(Ordering::Acquire) {
+        0 => None,
+        millis => Some(Duration::from_millis(millis)),
+    }
+}
+
+/// Why a computation did not produce a value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhlowComputationError {
+    /// The computation did not finish within its time budget
+    TimedOut(Duration),
+    Cancelled,
+    /// The computation returned an error, described by the message
+    Failed(String),
+}
+
+impl Display for PhlowComputationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TimedOut(budget) => write!(
+                f,
+                "Computation did not finish within {} ms",
+                budget.as_millis()
+            ),
+            Self::Cancelled => write!(f, "Computation was cancelled"),
+            Self::Failed(message) => write!(f, "Error: {}", message),
+        }
+    }
+}
+
+impl Error for PhlowComputationError {}
+
+/// Cooperative cancellation of a computation. The token is cancelled once the computation
+/// exceeds its time budget, long running computations should check it and stop early
+#[derive(Debug, Clone, Default)]
+pub struct PhlowCancellationToken(Arc<AtomicBool>);
+
+impl PhlowCancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the token of a computation running on the current thread,
+    /// or a token that is never cancelled outside of computations with a time budget
+    pub fn current() -> Self {
+        CURRENT_CANCELLATION_TOKEN
+            .with(|token| token.borrow().clone())
+            .unwrap_or_default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    /// Return true if the current thread evaluates a computation with a time budget
+    pub fn is_within_computation() -> bool {
+        CURRENT_CANCELLATION_TOKEN.with(|token| token.borrow().is_some())
+    }
+
+    /// Evaluate the block with this token being the current one
+    pub fn run<R>(&self, block: impl FnOnce() -> R) -> R {
+        let previous =
+            CURRENT_CANCELLATION_TOKEN.with(|token| token.borrow_mut().replace(self.clone()));
+        let result = block();
+        CURRENT_CANCELLATION_TOKEN.with(|token| *token.borrow_mut() = previous);
+        result
+    }
+}
+
+/// Evaluate the block on a worker thread and wait at most for the budget.
+/// An exceeded computation is cancelled and left running in the background,
+/// the block is evaluated in place if there is no budget. Computations started by
+/// a budgeted computation are evaluated in place within the budget of the outer one
+pub fn block_on_within_time_budget<R: Send + 'static>(
+    budget: Option<Duration>,
+    block: impl FnOnce() -> R + Send + 'static,
+) -> Result<R, PhlowComputationError> {
+    match budget {
+        Some(budget) if !PhlowCancellationToken::is_within_computation() => {
+            futures_executor::block_on(spawn_budgeted(budget, block))
+        }
+        _ => Ok(block()),
+    }
+}
+
+/// Asynchronously wait for the block evaluated on a worker thread for at most the budget
+pub async fn within_time_budget<R: Send + 'static>(
+    budget: Option<Duration>,
+    block: impl FnOnce() -> R + Send + 'static,
+) -> Result<R, PhlowComputationError> {
+    match budget {
+        Some(budget) if !PhlowCancellationToken::is_within_computation() => {
+            spawn_budgeted(budget, block).await
+        }
+        _ => Ok(block()),
+    }
+}
+
+/// Evaluate the block on the current thread, for computations over values that must not be
+/// used from other threads. The computation can not be interrupted, its token is cancelled
+/// once the budget is exceeded and a value computed too late is given up
+pub fn block_in_place_within_time_budget<R>(
+    budget: Option<Duration>,
+    block: impl FnOnce() -> R,
+) -> Result<R, PhlowComputationError> {
+    let budget = match budget {
+        Some(budget) if !PhlowCancellationToken::is_within_computation() => budget,
+        _ => return Ok(block()),
+    };
+
+    let token = PhlowCancellationToken::new();
+    let deadline_token = token.clone();
+    deadlines().add(Instant::now() + budget, move || deadline_token.cancel());
+
+    let value = token.run(block);
+    if token.is_cancelled() {
+        Err(PhlowComputationError::TimedOut(budget))
+    } else {
+        Ok(value)
+    }
+}
+
+/// Evaluate the block within the budget on a worker thread if all objects it uses are
+/// thread safe, and in place otherwise, see [`PhlowObject::is_thread_safe`]
+pub fn block_on_within_time_budget_of<R: Send + 'static>(
+    objects: &[&PhlowObject],
+    budget: Option<Duration>,
+    block: impl FnOnce() -> R + Send + 'static,
+) -> Result<R, PhlowComputationError> {
+    if objects.iter().all(|object| object.is_thread_safe()) {
+        block_on_within_time_budget(budget, block)
+    } else {
+        block_in_place_within_time_budget(budget, block)
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Budgeted computations are evaluated by a bounded pool of worker threads shared by all views.
+/// An exceeded computation keeps its worker busy until it notices the cancellation,
+/// a computation waiting for a free worker spends its budget while waiting
+struct ComputationPool {
+    jobs: Mutex<mpsc::Sender<Job>>,
+}
+
+impl ComputationPool {
+    fn new(workers: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for index in 0..workers {
+            let receiver = receiver.clone();
+            let worker = thread::Builder::new()
+                .name(format!("phlow-computation-{}", index))
+                .spawn(move || loop {
+                    let job = receiver.lock().recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => break,
+                    }
+                });
+            if let Err(error) = worker {
+                log::error!("Failed to spawn a computation thread: {}", error);
+            }
+        }
+        Self {
+            jobs: Mutex::new(sender),
+        }
+    }
+
+    fn execute(&self, job: Job) {
+        // a job that can not be sent is dropped, its computation is then cancelled
+        let _ = self.jobs.lock().send(job);
+    }
+}
+
+fn computation_pool() -> &'static ComputationPool {
+    static POOL: OnceLock<ComputationPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        let workers = thread::available_parallelism().map_or(4, |workers| workers.get());
+        ComputationPool::new(workers.max(4))
+    })
+}
+
+type DeadlineAction = Box<dyn FnOnce() + Send>;
+
+/// A single thread that performs the actions of all budgeted computations once their
+/// deadline has passed, whether or not the computations finished in time
+struct Deadlines {
+    pending: Mutex<Vec<(Instant, DeadlineAction)>>,
+    changed: Condvar,
+}
+
+impl Deadlines {
+    fn add(&self, deadline: Instant, action: impl FnOnce() + Send + 'static) {
+        self.pending.lock().push((deadline, Box::new(action)));
+        self.changed.notify_one();
+    }
+
+    fn run(&self) {
+        let mut pending = self.pending.lock();
+        loop {
+            let now = Instant::now();
+            let (passed, waiting) = std::mem::take(&mut *pending)
+                .into_iter()
+                .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+            *pending = waiting;
+
+            if !passed.is_empty() {
+                MutexGuard::unlocked(&mut pending, || {
+                    passed.into_iter().for_each(|(_, action)| action())
+                });
+                continue;
+            }
+
+            match pending.iter().map(|(deadline, _)| *deadline).min() {
+                None => self.changed.wait(&mut pending),
+                Some(deadline) => {
+                    self.changed.wait_until(&mut pending, deadline);
+                }
+            }
+        }
+    }
+}
+
+fn deadlines() -> &'static Deadlines {
+    static DEADLINES: OnceLock<Deadlines> = OnceLock::new();
+    DEADLINES.get_or_init(|| {
+        // the thread waits for the initialization to finish
+        if let Err(error) = thread::Builder::new()
+            .name("phlow-deadlines".to_string())
+            .spawn(|| deadlines().run())
+        {
+            log::error!(
+                "Failed to spawn the computation deadlines thread: {}",
+                error
+            );
+        }
+        Deadlines {
+            pending: Mutex::new(vec![]),
+            changed: Condvar::new(),
+        }
+    })
+}
+
+/// Evaluate the block on the computation pool. The result is either the value,
+/// or the error that the budget was exceeded, whichever comes first
+fn spawn_budgeted<R: Send + 'static>(
+    budget: Duration,
+    block: impl FnOnce() -> R + Send + 'static,
+) -> impl Future<Output = Result<R, PhlowComputationError>> {
+    let token = PhlowCancellationToken::new();
+    let (sender, receiver) = oneshot::channel();
+    let sender = Arc::new(Mutex::new(Some(sender)));
+
+    let deadline_token = token.clone();
+    let deadline_sender = sender.clone();
+    deadlines().add(Instant::now() + budget, move || {
+        deadline_token.cancel();
+        if let Some(sender) = deadline_sender.lock().take() {
+            let _ = sender.send(Err(PhlowComputationError::TimedOut(budget)));
+        }
+    });
+
+    computation_pool().execute(Box::new(move || {
+        // the budget was exceeded while waiting for a free worker
+        if token.is_cancelled() {
+            return;
+        }
+        let value = token.run(block);
+        if let Some(sender) = sender.lock().take() {
+            let _ = sender.send(Ok(value));
+        }
+    }));
+
+    receiver.map(|result| result.unwrap_or(Err(PhlowComputationError::Cancelled)))
+}
@@ -0,0 +1,106 @@
+use std::any::{Any, TypeId};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, OnceLock};
+
+use parking_lot::Mutex;
+
+use crate::{PhlowObject, PhlowObjectIdentity};
+
+struct ComputationCacheEntry<Return> {
+    identity: PhlowObjectIdentity,
+    version: u64,
+    value: Return,
+}
+
+/// Remembers computed values of a limited amount of objects, evicting the least recently used.
+/// Entries are keyed by the identity of an object and become stale once its value is
+/// replaced or mutated, or once the object is no longer used. Cached items that refer to
+/// the object as their parent do not count as a use, see [`PhlowObjectIdentity::is_used`]
+pub struct ComputationCache<Return> {
+    capacity: usize,
+    clone_value: fn(&Return) -> Return,
+    // the most recently used entries are at the front
+    entries: Mutex<VecDeque<ComputationCacheEntry<Return>>>,
+}
+
+type SharedCaches = Mutex<HashMap<(TypeId, String, usize), Arc<dyn Any + Send + Sync>>>;
+
+impl<Return: Clone> ComputationCache<Return> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            clone_value: Return::clone,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+}
+
+impl<Return: Clone + Send + Sync + 'static> ComputationCache<Return> {
+    /// Return the cache registered under the key, creating it on first use.
+    /// Views are built anew for every request, sharing the cache of their view method
+    /// lets a rebuilt view find the values computed by the previous ones
+    pub fn shared(key: &str, capacity: usize) -> Arc<Self> {
+        static SHARED_CACHES: OnceLock<SharedCaches> = OnceLock::new();
+        let mut caches = SHARED_CACHES.get_or_init(Default::default).lock();
+        let cache = caches
+            .entry((TypeId::of::<Return>(), key.to_string(), capacity))
+            .or_insert_with(|| Arc::new(Self::new(capacity)))
+            .clone();
+        // the type of the values is part of the key
+        cache.downcast::<Self>().unwrap()
+    }
+}
+
+impl<Return> ComputationCache<Return> {
+    pub fn get_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().is_empty()
+    }
+
+    /// Return a value computed for the current version of the object
+    pub fn get(&self, object: &PhlowObject) -> Option<Return> {
+        let mut entries = self.entries.lock();
+        let index = entries
+            .iter()
+            .position(|entry| entry.identity.is_identity_of(object))?;
+        if entries[index].version != object.version() {
+            entries.remove(index);
+            return None;
+        }
+        let entry = entries.remove(index)?;
+        let value = (self.clone_value)(&entry.value);
+        entries.push_front(entry);
+        Some(value)
+    }
+
+    /// Remember a copy of the value computed for the given version of the object
+    pub fn insert(&self, object: &PhlowObject, version: u64, value: &Return) {
+        let value = (self.clone_value)(value);
+        let mut entries = self.entries.lock();
+        entries.retain(|entry| entry.identity.is_used() && !entry.identity.is_identity_of(object));
+        entries.truncate(self.capacity.saturating_sub(1));
+        entries.push_front(ComputationCacheEntry {
+            identity: object.identity(),
+            version,
+            value,
+        });
+    }
+
+    /// Forget the value computed for the object
+    pub fn invalidate(&self, object: &PhlowObject) {
+        self.entries
+            .lock()
+            .retain(|entry| !entry.identity.is_identity_of(object));
+    }
+
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+}
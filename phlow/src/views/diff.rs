@@ -0,0 +1,320 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{
+    downcast_view_ref, PhlowColor, PhlowColumnedListView, PhlowListView, PhlowObject, PhlowStyle,
+    PhlowTextView, PhlowView,
+};
+
+/// How objects are turned into lines before comparing them
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum PhlowDiffMode {
+    /// Compare the `Display` output, falling back to `Debug`
+    #[default]
+    Display,
+    /// Compare the pretty-printed `Debug` output, falling back to `Display`
+    Debug,
+    /// Compare the items of the view with the given method name, one line per item
+    Items(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+#[cfg_attr(feature = "view-specification", serde(rename_all = "lowercase"))]
+pub enum PhlowDiffLineKind {
+    Unchanged,
+    Inserted,
+    Deleted,
+}
+
+impl PhlowDiffLineKind {
+    pub fn prefix(&self) -> char {
+        match self {
+            Self::Unchanged => ' ',
+            Self::Inserted => '+',
+            Self::Deleted => '-',
+        }
+    }
+
+    /// Default highlighting of changed lines
+    pub fn style(&self) -> Option<PhlowStyle> {
+        match self {
+            Self::Unchanged => None,
+            Self::Inserted => Some(PhlowStyle::new().foreground(PhlowColor::GREEN)),
+            Self::Deleted => Some(PhlowStyle::new().foreground(PhlowColor::RED)),
+        }
+    }
+}
+
+/// A line of the diff with its zero-based index in the left and the right lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+#[cfg_attr(feature = "view-specification", serde(rename_all = "camelCase"))]
+pub struct PhlowDiffLine {
+    pub kind: PhlowDiffLineKind,
+    pub text: String,
+    pub left_index: Option<usize>,
+    pub right_index: Option<usize>,
+}
+
+/// A pair of objects to compare, the left one is considered to be the expected value
+#[derive(Debug, Clone)]
+pub struct PhlowDiff {
+    left: PhlowObject,
+    right: PhlowObject,
+    mode: PhlowDiffMode,
+}
+
+impl PhlowDiff {
+    pub fn new(left: PhlowObject, right: PhlowObject) -> Self {
+        Self {
+            left,
+            right,
+            mode: Default::default(),
+        }
+    }
+
+    pub fn mode(mut self, mode: PhlowDiffMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn by_display(self) -> Self {
+        self.mode(PhlowDiffMode::Display)
+    }
+
+    pub fn by_debug(self) -> Self {
+        self.mode(PhlowDiffMode::Debug)
+    }
+
+    pub fn by_items(self, view_method_name: impl Into<String>) -> Self {
+        self.mode(PhlowDiffMode::Items(view_method_name.into()))
+    }
+
+    pub fn get_left(&self) -> &PhlowObject {
+        &self.left
+    }
+
+    pub fn get_right(&self) -> &PhlowObject {
+        &self.right
+    }
+
+    pub fn get_mode(&self) -> &PhlowDiffMode {
+        &self.mode
+    }
+
+    pub fn compute_lines(&self) -> Vec<PhlowDiffLine> {
+        let left = object_lines(&self.left, &self.mode);
+        let right = object_lines(&self.right, &self.mode);
+        diff_lines(&left, &right)
+    }
+}
+
+impl Display for PhlowDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Diff of {} and {}",
+            self.left.value_type_name(),
+            self.right.value_type_name()
+        )
+    }
+}
+
+fn object_lines(object: &PhlowObject, mode: &PhlowDiffMode) -> Vec<String> {
+    let text = match mode {
+        PhlowDiffMode::Display => object
+            .to_display_string()
+            .unwrap_or_else(|| object.to_string()),
+        PhlowDiffMode::Debug => object
+            .to_debug_string()
+            .unwrap_or_else(|| object.to_string()),
+        PhlowDiffMode::Items(view_method_name) => {
+            return object
+                .phlow_view_named(view_method_name)
+                .map(view_lines)
+                .unwrap_or_default()
+        }
+    };
+    text.lines().map(|line| line.to_string()).collect()
+}
+
+/// Describe every item of a list-like view with a line of text
+fn view_lines(view: Box<dyn PhlowView>) -> Vec<String> {
+    if let Ok(list) = downcast_view_ref::<PhlowListView>(&view) {
+        return list
+            .compute_items_sync()
+            .iter()
+            .map(|item| list.compute_item_text_sync(item))
+            .collect();
+    }
+    if let Ok(columned_list) = downcast_view_ref::<PhlowColumnedListView>(&view) {
+        return columned_list
+            .compute_items()
+            .iter()
+            .map(|item| {
+                columned_list
+                    .get_columns()
+                    .iter()
+                    .map(|column| match column.compute_cell_item(item) {
+                        None => "".to_string(),
+                        Some(cell) => column.compute_cell_text(&cell),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            })
+            .collect();
+    }
+    if let Ok(text) = downcast_view_ref::<PhlowTextView>(&view) {
+        return text
+            .compute_text()
+            .lines()
+            .map(|line| line.to_string())
+            .collect();
+    }
+    view.to_string()
+        .lines()
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// Compute the shortest edit script between two sequences of lines with the Myers algorithm.
+/// Deletions are listed before insertions within a changed block
+pub fn diff_lines(left: &[String], right: &[String]) -> Vec<PhlowDiffLine> {
+    let n = left.len() as isize;
+    let m = right.len() as isize;
+    let max = (n + m) as usize;
+    // furthest reaching x for each diagonal k, offset by max to allow negative k
+    let mut v = vec![0isize; 2 * max + 2];
+    let mut trace: Vec<Vec<isize>> = vec![];
+    let index = |k: isize| (k + max as isize) as usize;
+
+    'search: for d in 0..=max as isize {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[index(k - 1)] < v[index(k + 1)]) {
+                v[index(k + 1)]
+            } else {
+                v[index(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && left[x as usize] == right[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let previous_k = if k == -d || (k != d && v[index(k - 1)] < v[index(k + 1)]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let previous_x = v[index(previous_k)];
+        let previous_y = previous_x - previous_k;
+
+        while x > previous_x && y > previous_y {
+            x -= 1;
+            y -= 1;
+            lines.push(PhlowDiffLine {
+                kind: PhlowDiffLineKind::Unchanged,
+                text: left[x as usize].clone(),
+                left_index: Some(x as usize),
+                right_index: Some(y as usize),
+            });
+        }
+        if d > 0 {
+            if x == previous_x {
+                lines.push(PhlowDiffLine {
+                    kind: PhlowDiffLineKind::Inserted,
+                    text: right[previous_y as usize].clone(),
+                    left_index: None,
+                    right_index: Some(previous_y as usize),
+                });
+            } else {
+                lines.push(PhlowDiffLine {
+                    kind: PhlowDiffLineKind::Deleted,
+                    text: left[previous_x as usize].clone(),
+                    left_index: Some(previous_x as usize),
+                    right_index: None,
+                });
+            }
+        }
+        x = previous_x;
+        y = previous_y;
+    }
+    lines.reverse();
+    lines
+}
+
+/// Render the lines as a unified diff with the given amount of unchanged lines around changes.
+/// Returns an empty string if there are no changes
+pub fn unified_diff(lines: &[PhlowDiffLine], context: usize) -> String {
+    let changes = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.kind != PhlowDiffLineKind::Unchanged)
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    if changes.is_empty() {
+        return "".to_string();
+    }
+
+    // merge changes whose context overlaps into hunks of line ranges
+    let mut hunks: Vec<(usize, usize)> = vec![];
+    for change in changes {
+        let start = change.saturating_sub(context);
+        let end = (change + context + 1).min(lines.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut diff = "--- left\n+++ right\n".to_string();
+    for (start, end) in hunks {
+        let hunk = &lines[start..end];
+        // amount of left and right lines preceding the hunk
+        let left_before = lines[..start]
+            .iter()
+            .filter(|line| line.kind != PhlowDiffLineKind::Inserted)
+            .count();
+        let right_before = lines[..start]
+            .iter()
+            .filter(|line| line.kind != PhlowDiffLineKind::Deleted)
+            .count();
+        let left_count = hunk
+            .iter()
+            .filter(|line| line.kind != PhlowDiffLineKind::Inserted)
+            .count();
+        let right_count = hunk
+            .iter()
+            .filter(|line| line.kind != PhlowDiffLineKind::Deleted)
+            .count();
+        // empty ranges refer to the line before them
+        let range_start =
+            |before: usize, count: usize| if count == 0 { before } else { before + 1 };
+
+        diff.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            range_start(left_before, left_count),
+            left_count,
+            range_start(right_before, right_count),
+            right_count
+        ));
+        for line in hunk {
+            diff.push(line.kind.prefix());
+            diff.push_str(&line.text);
+            diff.push('\n');
+        }
+    }
+    diff
+}
@@ -0,0 +1,234 @@
+use std::any::Any;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{
+    unified_diff, AsyncComputation, DiffComputation, PhlowDiff, PhlowDiffLine, PhlowObject,
+    PhlowView, PhlowViewMethod, SyncComputation,
+};
+
+#[allow(unused)]
+#[derive(Clone)]
+pub struct PhlowDiffView {
+    object: PhlowObject,
+    defining_method: PhlowViewMethod,
+    title: String,
+    priority: usize,
+    context_lines: usize,
+    diff_computation: Option<DiffComputation>,
+}
+
+impl PhlowDiffView {
+    pub const DEFAULT_CONTEXT_LINES: usize = 3;
+
+    pub fn new(object: PhlowObject, defining_method: PhlowViewMethod) -> Self {
+        Self {
+            title: defining_method.get_default_title().to_string(),
+            priority: defining_method.get_default_priority(),
+            object,
+            defining_method,
+            context_lines: Self::DEFAULT_CONTEXT_LINES,
+            diff_computation: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn priority(mut self, priority: usize) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Amount of unchanged lines displayed around changes in the unified diff
+    pub fn context_lines(mut self, context_lines: usize) -> Self {
+        self.context_lines = context_lines;
+        self
+    }
+
+    pub fn diff<T: 'static>(mut self, diff_block: impl SyncComputation<T, PhlowDiff>) -> Self {
+        self.diff_computation = Some(DiffComputation::new_sync(diff_block));
+        self
+    }
+
+    pub fn async_diff<T: 'static>(
+        mut self,
+        diff_block: impl AsyncComputation<T, PhlowDiff>,
+    ) -> Self {
+        self.diff_computation = Some(DiffComputation::new_async(diff_block));
+        self
+    }
+
+    pub fn get_context_lines(&self) -> usize {
+        self.context_lines
+    }
+
+    /// Lines of both objects, an object is compared with itself if there is no diff computation
+    pub fn compute_lines(&self) -> Vec<PhlowDiffLine> {
+        self.diff_computation
+            .as_ref()
+            .and_then(|computation| computation.value_block_on(&self.object))
+            .unwrap_or_else(|| PhlowDiff::new(self.object.clone(), self.object.clone()))
+            .compute_lines()
+    }
+
+    pub async fn async_compute_lines(&self) -> Vec<PhlowDiffLine> {
+        let diff = match &self.diff_computation {
+            None => None,
+            Some(computation) => computation.value(&self.object).await,
+        };
+        diff.unwrap_or_else(|| PhlowDiff::new(self.object.clone(), self.object.clone()))
+            .compute_lines()
+    }
+
+    pub fn compute_unified_diff(&self) -> String {
+        unified_diff(&self.compute_lines(), self.context_lines)
+    }
+}
+
+impl Debug for PhlowDiffView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhlowDiffView").finish()
+    }
+}
+
+impl Display for PhlowDiffView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.title.as_str())?;
+        if let Some(description) = self.defining_method.get_description() {
+            writeln!(f, "{}", description)?;
+        }
+        writeln!(f, "---------------------")?;
+        write!(f, "{}", self.compute_unified_diff())?;
+
+        Ok(())
+    }
+}
+
+impl PhlowView for PhlowDiffView {
+    fn get_title(&self) -> &str {
+        self.title.as_str()
+    }
+
+    fn get_priority(&self) -> usize {
+        self.priority
+    }
+
+    fn get_view_type(&self) -> &str {
+        Self::view_type()
+    }
+
+    fn get_defining_method(&self) -> &PhlowViewMethod {
+        &self.defining_method
+    }
+
+    fn view_type() -> &'static str
+    where
+        Self: Sized,
+    {
+        "diff_view"
+    }
+
+    fn object(&self) -> &PhlowObject {
+        &self.object
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn to_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    #[cfg(feature = "view-specification")]
+    fn as_view_specification_builder(&self) -> &dyn crate::AsPhlowViewSpecification {
+        self
+    }
+}
+
+#[allow(dead_code)]
+const _: () = {
+    fn assert_send<T: Send>() {}
+
+    // RFC 2056
+    fn assert_all() {
+        assert_send::<PhlowDiffView>();
+    }
+};
+
+#[cfg(feature = "view-specification")]
+mod specification {
+    use serde::Serialize;
+
+    use crate::{
+        AsPhlowViewSpecification, PhlowStyle, PhlowViewSpecification,
+        PhlowViewSpecificationDataTransport, PhlowViewSpecificationListingItem,
+    };
+
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PhlowDiffViewSpecificationLine {
+        #[serde(flatten)]
+        line: PhlowDiffLine,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<PhlowStyle>,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PhlowDiffViewSpecification {
+        title: String,
+        priority: usize,
+        data_transport: PhlowViewSpecificationDataTransport,
+        lines: Vec<PhlowDiffViewSpecificationLine>,
+        unified_diff: String,
+        method_selector: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip)]
+        phlow_view: PhlowDiffView,
+    }
+
+    #[typetag::serialize(name = "GtPhlowDiffViewSpecification")]
+    #[async_trait::async_trait]
+    impl PhlowViewSpecification for PhlowDiffViewSpecification {
+        async fn retrieve_items(&self) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
+            vec![]
+        }
+
+        async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject> {
+            Some(item.clone())
+        }
+
+        async fn retrieve_included_data(&mut self) {
+            let lines = self.phlow_view.async_compute_lines().await;
+            self.unified_diff = unified_diff(&lines, self.phlow_view.get_context_lines());
+            self.lines = lines
+                .into_iter()
+                .map(|line| PhlowDiffViewSpecificationLine {
+                    style: line.kind.style(),
+                    line,
+                })
+                .collect();
+        }
+    }
+
+    impl AsPhlowViewSpecification for PhlowDiffView {
+        fn create_view_specification(&self) -> Option<Box<dyn PhlowViewSpecification>> {
+            Some(Box::new(PhlowDiffViewSpecification {
+                title: self.get_title().to_string(),
+                priority: self.get_priority(),
+                data_transport: PhlowViewSpecificationDataTransport::Included,
+                lines: vec![],
+                unified_diff: "".to_string(),
+                method_selector: self.get_defining_method().full_method_name.clone(),
+                description: self.get_defining_method().description.clone(),
+                phlow_view: self.clone(),
+            }))
+        }
+    }
+}
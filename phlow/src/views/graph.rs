@@ -0,0 +1,119 @@
+use crate::PhlowObject;
+
+/// A labelled reference from a node to another object.
+/// The target becomes a node of the graph if it is not one already
+#[derive(Debug, Clone)]
+pub struct PhlowGraphEdge {
+    label: String,
+    target: PhlowObject,
+}
+
+impl PhlowGraphEdge {
+    pub fn new(target: PhlowObject) -> Self {
+        Self {
+            label: "".to_string(),
+            target,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn get_label(&self) -> &str {
+        self.label.as_str()
+    }
+
+    pub fn get_target(&self) -> &PhlowObject {
+        &self.target
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PhlowGraphNode {
+    pub object: PhlowObject,
+    /// Identifies the node, objects with the same key are the same node
+    pub key: String,
+    pub text: String,
+}
+
+/// An edge between nodes referenced by their indices
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+pub struct PhlowGraphLink {
+    pub from: usize,
+    pub to: usize,
+    pub label: String,
+}
+
+/// Nodes and edges without any layout information
+#[derive(Debug, Clone, Default)]
+pub struct PhlowGraph {
+    nodes: Vec<PhlowGraphNode>,
+    links: Vec<PhlowGraphLink>,
+}
+
+impl PhlowGraph {
+    /// Add a node unless there is a node with the same key, return the index of the node
+    pub fn add_node(&mut self, object: PhlowObject, key: String, text: String) -> (usize, bool) {
+        match self.index_of(&key) {
+            Some(index) => (index, false),
+            None => {
+                self.nodes.push(PhlowGraphNode { object, key, text });
+                (self.nodes.len() - 1, true)
+            }
+        }
+    }
+
+    pub fn add_link(&mut self, from: usize, to: usize, label: impl Into<String>) {
+        self.links.push(PhlowGraphLink {
+            from,
+            to,
+            label: label.into(),
+        });
+    }
+
+    pub fn index_of(&self, key: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| node.key == key)
+    }
+
+    pub fn get_nodes(&self) -> &[PhlowGraphNode] {
+        self.nodes.as_slice()
+    }
+
+    pub fn get_links(&self) -> &[PhlowGraphLink] {
+        self.links.as_slice()
+    }
+
+    pub fn links_from(&self, index: usize) -> impl Iterator<Item = &PhlowGraphLink> {
+        self.links.iter().filter(move |link| link.from == index)
+    }
+
+    /// Export as a directed graph in the Graphviz DOT language
+    pub fn to_dot(&self) -> String {
+        let mut dot = "digraph {\n".to_string();
+        for (index, node) in self.nodes.iter().enumerate() {
+            dot.push_str(&format!(
+                "  n{} [label=\"{}\"];\n",
+                index,
+                escape_dot(&node.text)
+            ));
+        }
+        for link in &self.links {
+            dot.push_str(&format!("  n{} -> n{}", link.from, link.to));
+            if !link.label.is_empty() {
+                dot.push_str(&format!(" [label=\"{}\"]", escape_dot(&link.label)));
+            }
+            dot.push_str(";\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
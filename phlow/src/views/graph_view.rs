@@ -0,0 +1,349 @@
+use std::any::Any;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+
+use crate::{
+    AsyncComputation, EdgesComputation, ItemsComputation, PhlowGraph, PhlowGraphEdge, PhlowObject,
+    PhlowView, PhlowViewMethod, SendComputation, SyncComputation, TextComputation,
+};
+
+#[allow(unused)]
+#[derive(Clone)]
+pub struct PhlowGraphView {
+    object: PhlowObject,
+    defining_method: PhlowViewMethod,
+    title: String,
+    priority: usize,
+    max_nodes: usize,
+    nodes_computation: ItemsComputation,
+    node_key_computation: TextComputation,
+    node_text_computation: TextComputation,
+    edges_computation: Option<EdgesComputation>,
+    send_computation: SendComputation,
+}
+
+impl PhlowGraphView {
+    pub const DEFAULT_MAX_NODES: usize = 256;
+
+    pub fn new(object: PhlowObject, defining_method: PhlowViewMethod) -> Self {
+        Self {
+            title: defining_method.get_default_title().to_string(),
+            priority: defining_method.get_default_priority(),
+            object,
+            defining_method,
+            max_nodes: Self::DEFAULT_MAX_NODES,
+            nodes_computation: Default::default(),
+            node_key_computation: Default::default(),
+            node_text_computation: Default::default(),
+            edges_computation: None,
+            send_computation: Default::default(),
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn priority(mut self, priority: usize) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Stop following edges once the graph has that many nodes
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    /// Nodes to start from, objects referenced by their edges are added to the graph as well
+    pub fn nodes<T: 'static>(
+        mut self,
+        nodes_block: impl SyncComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.nodes_computation = ItemsComputation::new_sync(nodes_block);
+        self
+    }
+
+    pub fn async_nodes<T: 'static>(
+        mut self,
+        nodes_block: impl AsyncComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.nodes_computation = ItemsComputation::new_async(nodes_block);
+        self
+    }
+
+    /// Identify nodes, for example by the address of a shared value, to detect cycles.
+    /// Nodes are identified by their textual representation by default
+    pub fn node_key<T: 'static>(mut self, key_block: impl SyncComputation<T, String>) -> Self {
+        self.node_key_computation = TextComputation::new_sync(key_block);
+        self
+    }
+
+    pub fn node_text<T: 'static>(mut self, text_block: impl SyncComputation<T, String>) -> Self {
+        self.node_text_computation = TextComputation::new_sync(text_block);
+        self
+    }
+
+    pub fn edges<T: 'static>(
+        mut self,
+        edges_block: impl SyncComputation<T, Vec<PhlowGraphEdge>>,
+    ) -> Self {
+        self.edges_computation = Some(EdgesComputation::new_sync(edges_block));
+        self
+    }
+
+    pub fn async_edges<T: 'static>(
+        mut self,
+        edges_block: impl AsyncComputation<T, Vec<PhlowGraphEdge>>,
+    ) -> Self {
+        self.edges_computation = Some(EdgesComputation::new_async(edges_block));
+        self
+    }
+
+    pub fn send<T: 'static>(mut self, send_block: impl SyncComputation<T, PhlowObject>) -> Self {
+        self.send_computation = SendComputation::new_sync(send_block);
+        self
+    }
+
+    /// Traverse the edges breadth first starting from the nodes
+    pub async fn compute_graph(&self) -> PhlowGraph {
+        let mut graph = PhlowGraph::default();
+        let mut queue = VecDeque::new();
+
+        for node in self
+            .nodes_computation
+            .value_or_else(&self.object, Vec::new)
+            .await
+        {
+            if let (index, true) = self.add_node(&mut graph, node).await {
+                queue.push_back(index);
+            }
+        }
+
+        while let Some(from) = queue.pop_front() {
+            let edges = match &self.edges_computation {
+                None => vec![],
+                Some(computation) => computation
+                    .value(&graph.get_nodes()[from].object)
+                    .await
+                    .unwrap_or_default(),
+            };
+
+            for edge in edges {
+                let key = self.compute_node_key(edge.get_target()).await;
+                let to = match graph.index_of(&key) {
+                    Some(index) => index,
+                    None if graph.get_nodes().len() < self.max_nodes => {
+                        let (index, _) = self.add_node(&mut graph, edge.get_target().clone()).await;
+                        queue.push_back(index);
+                        index
+                    }
+                    None => continue,
+                };
+                graph.add_link(from, to, edge.get_label());
+            }
+        }
+
+        graph
+    }
+
+    pub fn compute_graph_sync(&self) -> PhlowGraph {
+        futures_executor::block_on(self.compute_graph())
+    }
+
+    pub async fn compute_item_to_send(&self, item: &PhlowObject) -> Option<PhlowObject> {
+        self.send_computation.value(item).await
+    }
+
+    async fn compute_node_key(&self, node: &PhlowObject) -> String {
+        self.node_key_computation
+            .value_or_else(node, || node.to_string())
+            .await
+    }
+
+    async fn add_node(&self, graph: &mut PhlowGraph, node: PhlowObject) -> (usize, bool) {
+        let key = self.compute_node_key(&node).await;
+        let text = self
+            .node_text_computation
+            .value_or_else(&node, || node.to_string())
+            .await;
+        graph.add_node(node, key, text)
+    }
+}
+
+impl Debug for PhlowGraphView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhlowGraphView").finish()
+    }
+}
+
+impl Display for PhlowGraphView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.title.as_str())?;
+        if let Some(description) = self.defining_method.get_description() {
+            writeln!(f, "{}", description)?;
+        }
+        writeln!(f, "---------------------")?;
+
+        let graph = self.compute_graph_sync();
+        for (index, node) in graph.get_nodes().iter().enumerate() {
+            writeln!(f, "{0:>3} | {1}", index, node.text)?;
+            for link in graph.links_from(index) {
+                let arrow = if link.label.is_empty() {
+                    "-->".to_string()
+                } else {
+                    format!("--{}-->", link.label)
+                };
+                writeln!(
+                    f,
+                    "    | {} {}: {}",
+                    arrow,
+                    link.to,
+                    graph.get_nodes()[link.to].text
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PhlowView for PhlowGraphView {
+    fn get_title(&self) -> &str {
+        self.title.as_str()
+    }
+
+    fn get_priority(&self) -> usize {
+        self.priority
+    }
+
+    fn get_view_type(&self) -> &str {
+        Self::view_type()
+    }
+
+    fn get_defining_method(&self) -> &PhlowViewMethod {
+        &self.defining_method
+    }
+
+    fn view_type() -> &'static str
+    where
+        Self: Sized,
+    {
+        "graph_view"
+    }
+
+    fn object(&self) -> &PhlowObject {
+        &self.object
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn to_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    #[cfg(feature = "view-specification")]
+    fn as_view_specification_builder(&self) -> &dyn crate::AsPhlowViewSpecification {
+        self
+    }
+}
+
+#[allow(dead_code)]
+const _: () = {
+    fn assert_send<T: Send>() {}
+
+    // RFC 2056
+    fn assert_all() {
+        assert_send::<PhlowGraphView>();
+    }
+};
+
+#[cfg(feature = "view-specification")]
+mod specification {
+    use serde::Serialize;
+
+    use crate::{
+        AsPhlowViewSpecification, PhlowGraphLink, PhlowViewSpecification,
+        PhlowViewSpecificationDataTransport, PhlowViewSpecificationListingItem,
+    };
+
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PhlowGraphViewSpecification {
+        title: String,
+        priority: usize,
+        data_transport: PhlowViewSpecificationDataTransport,
+        method_selector: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip)]
+        phlow_view: PhlowGraphView,
+    }
+
+    /// A node with its index and outgoing edges, nodes are the items of the view
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PhlowViewSpecificationGraphNodeValue {
+        #[serde(skip)]
+        phlow_object: PhlowObject,
+        index: usize,
+        node_text: String,
+        edges: Vec<PhlowGraphLink>,
+    }
+
+    #[typetag::serialize(name = "graphNodeValue")]
+    impl PhlowViewSpecificationListingItem for PhlowViewSpecificationGraphNodeValue {
+        fn phlow_object(&self) -> &PhlowObject {
+            &self.phlow_object
+        }
+    }
+
+    #[typetag::serialize(name = "GtPhlowGraphViewSpecification")]
+    #[async_trait::async_trait]
+    impl PhlowViewSpecification for PhlowGraphViewSpecification {
+        async fn retrieve_items(&self) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
+            let graph = self.phlow_view.compute_graph().await;
+            graph
+                .get_nodes()
+                .iter()
+                .enumerate()
+                .map(|(index, node)| {
+                    let item: Box<dyn PhlowViewSpecificationListingItem> =
+                        Box::new(PhlowViewSpecificationGraphNodeValue {
+                            phlow_object: node.object.clone(),
+                            index,
+                            node_text: node.text.clone(),
+                            edges: graph.links_from(index).cloned().collect(),
+                        });
+                    item
+                })
+                .collect()
+        }
+
+        async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject> {
+            self.phlow_view.compute_item_to_send(item).await
+        }
+
+        async fn retrieve_dot(&self) -> Option<String> {
+            Some(self.phlow_view.compute_graph().await.to_dot())
+        }
+    }
+
+    impl AsPhlowViewSpecification for PhlowGraphView {
+        fn create_view_specification(&self) -> Option<Box<dyn PhlowViewSpecification>> {
+            Some(Box::new(PhlowGraphViewSpecification {
+                title: self.get_title().to_string(),
+                priority: self.get_priority(),
+                data_transport: PhlowViewSpecificationDataTransport::Lazy,
+                method_selector: self.get_defining_method().full_method_name.clone(),
+                description: self.get_defining_method().description.clone(),
+                phlow_view: self.clone(),
+            }))
+        }
+    }
+}
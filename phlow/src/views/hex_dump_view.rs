@@ -0,0 +1,541 @@
+use std::any::Any;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::{
+    AsyncComputation, AsyncRangeComputation, BytesRangeComputation, Computation,
+    ItemsCountComputation, PhlowObject, PhlowStyle, PhlowView, PhlowViewMethod, SyncComputation,
+    SyncRangeComputation, ITEMS_PAGE_SIZE,
+};
+
+pub type BytesComputation = Computation<Vec<u8>>;
+pub type HighlightsComputation = Computation<Vec<PhlowHexDumpHighlight>>;
+
+/// A range of bytes that should be emphasized, offsets are relative to the start of the buffer
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhlowHexDumpHighlight {
+    pub range: Range<usize>,
+    pub style: PhlowStyle,
+}
+
+impl PhlowHexDumpHighlight {
+    pub fn new(range: Range<usize>, style: PhlowStyle) -> Self {
+        Self { range, style }
+    }
+}
+
+/// A row of the hex dump starting at the `offset` within the buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhlowHexDumpRow {
+    offset: usize,
+    bytes: Vec<u8>,
+}
+
+impl PhlowHexDumpRow {
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn get_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Space separated hex pairs, padded to the width of a full row
+    pub fn hex(&self, bytes_per_row: usize) -> String {
+        (0..bytes_per_row)
+            .map(|index| match self.bytes.get(index) {
+                None => "  ".to_string(),
+                Some(byte) => format!("{:02x}", byte),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Printable ASCII characters, other bytes are shown as dots
+    pub fn ascii(&self) -> String {
+        self.bytes.iter().map(|byte| ascii_char(*byte)).collect()
+    }
+
+    /// Highlights clipped to this row, with ranges relative to the start of the row
+    pub fn highlights(&self, highlights: &[PhlowHexDumpHighlight]) -> Vec<PhlowHexDumpHighlight> {
+        let row_end = self.offset + self.bytes.len();
+        highlights
+            .iter()
+            .filter_map(|highlight| {
+                let start = highlight.range.start.max(self.offset);
+                let end = highlight.range.end.min(row_end);
+                (start < end).then(|| {
+                    PhlowHexDumpHighlight::new(
+                        start - self.offset..end - self.offset,
+                        highlight.style.clone(),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+fn ascii_char(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        byte as char
+    } else {
+        '.'
+    }
+}
+
+#[allow(unused)]
+#[derive(Clone)]
+pub struct PhlowHexDumpView {
+    object: PhlowObject,
+    defining_method: PhlowViewMethod,
+    title: String,
+    priority: usize,
+    bytes_per_row: usize,
+    bytes_computation: BytesComputation,
+    bytes_range_computation: Option<BytesRangeComputation>,
+    bytes_count_computation: Option<ItemsCountComputation>,
+    highlights_computation: Option<HighlightsComputation>,
+    cache_size: usize,
+}
+
+impl PhlowHexDumpView {
+    pub const DEFAULT_BYTES_PER_ROW: usize = 16;
+    /// Rows printed by [`Display`], the rest of the buffer is elided
+    pub const MAX_DISPLAYED_ROWS: usize = ITEMS_PAGE_SIZE;
+
+    pub fn new(object: PhlowObject, defining_method: PhlowViewMethod) -> Self {
+        Self {
+            title: defining_method.get_default_title().to_string(),
+            priority: defining_method.get_default_priority(),
+            object,
+            defining_method,
+            bytes_per_row: Self::DEFAULT_BYTES_PER_ROW,
+            bytes_computation: BytesComputation::Sync(Arc::new(|_object| Some(vec![]))),
+            bytes_range_computation: None,
+            bytes_count_computation: None,
+            highlights_computation: None,
+            cache_size: 0,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn priority(mut self, priority: usize) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Amount of bytes displayed per row, at least one
+    pub fn bytes_per_row(mut self, bytes_per_row: usize) -> Self {
+        self.bytes_per_row = bytes_per_row.max(1);
+        self
+    }
+
+    /// Remember the bytes, their count and the highlights computed for up to `cache_size`
+    /// objects until their values are replaced or mutated. Views built by the same view method
+    /// share the remembered values. Byte ranges are computed anew, caching is disabled by default
+    pub fn cache_size(mut self, cache_size: usize) -> Self {
+        self.cache_size = cache_size;
+        let bytes_computation = self.bytes_computation.clone();
+        self.bytes_computation = self.cached_computation(bytes_computation, "bytes");
+        self.bytes_count_computation = self
+            .bytes_count_computation
+            .take()
+            .map(|computation| self.cached_computation(computation, "bytes_count"));
+        self.highlights_computation = self
+            .highlights_computation
+            .take()
+            .map(|computation| self.cached_computation(computation, "highlights"));
+        self
+    }
+
+    // views are built anew for every request, so views of the same method share their caches
+    fn cached_computation<Return: Clone + Send + Sync + 'static>(
+        &self,
+        computation: Computation<Return>,
+        name: &str,
+    ) -> Computation<Return> {
+        let key = format!("{}/{}", self.defining_method.full_method_name, name);
+        computation.shared_cached(&key, self.cache_size)
+    }
+
+    pub fn bytes<T: 'static>(mut self, bytes_block: impl SyncComputation<T, Vec<u8>>) -> Self {
+        self.bytes_computation =
+            self.cached_computation(BytesComputation::new_sync(bytes_block), "bytes");
+        self
+    }
+
+    pub fn async_bytes<T: 'static>(
+        mut self,
+        bytes_block: impl AsyncComputation<T, Vec<u8>>,
+    ) -> Self {
+        self.bytes_computation =
+            self.cached_computation(BytesComputation::new_async(bytes_block), "bytes");
+        self
+    }
+
+    /// Compute at most `count` bytes starting from the `start` offset.
+    /// When set, large buffers are paged instead of being copied at once.
+    pub fn bytes_range<T: 'static>(
+        mut self,
+        bytes_block: impl SyncRangeComputation<T, Vec<u8>>,
+    ) -> Self {
+        self.bytes_range_computation = Some(BytesRangeComputation::new_sync(bytes_block));
+        self
+    }
+
+    pub fn async_bytes_range<T: 'static>(
+        mut self,
+        bytes_block: impl AsyncRangeComputation<T, Vec<u8>>,
+    ) -> Self {
+        self.bytes_range_computation = Some(BytesRangeComputation::new_async(bytes_block));
+        self
+    }
+
+    /// Compute the total amount of bytes without computing the bytes themselves
+    pub fn bytes_count<T: 'static>(mut self, count_block: impl SyncComputation<T, usize>) -> Self {
+        self.bytes_count_computation = Some(
+            self.cached_computation(ItemsCountComputation::new_sync(count_block), "bytes_count"),
+        );
+        self
+    }
+
+    pub fn highlights<T: 'static>(
+        mut self,
+        highlights_block: impl SyncComputation<T, Vec<PhlowHexDumpHighlight>>,
+    ) -> Self {
+        self.highlights_computation = Some(self.cached_computation(
+            HighlightsComputation::new_sync(highlights_block),
+            "highlights",
+        ));
+        self
+    }
+
+    pub fn get_bytes_per_row(&self) -> usize {
+        self.bytes_per_row
+    }
+
+    pub async fn compute_bytes(&self, start: usize, count: usize) -> Vec<u8> {
+        match &self.bytes_range_computation {
+            Some(range_computation) => range_computation.value(&self.object, start, count).await,
+            None => {
+                let mut bytes = self
+                    .bytes_computation
+                    .value_or_else(&self.object, Vec::new)
+                    .await;
+                bytes.drain(..start.min(bytes.len()));
+                bytes.truncate(count);
+                bytes
+            }
+        }
+    }
+
+    pub fn compute_bytes_sync(&self, start: usize, count: usize) -> Vec<u8> {
+        match &self.bytes_range_computation {
+            Some(range_computation) => range_computation.value_block_on(&self.object, start, count),
+            None => futures_executor::block_on(self.compute_bytes(start, count)),
+        }
+    }
+
+    /// Return the total amount of bytes if it is known without computing all bytes
+    pub async fn compute_bytes_count(&self) -> Option<usize> {
+        match (&self.bytes_count_computation, &self.bytes_range_computation) {
+            (Some(count_computation), _) => count_computation.value(&self.object).await,
+            (None, Some(_)) => None,
+            (None, None) => Some(
+                self.bytes_computation
+                    .value_or_else(&self.object, Vec::new)
+                    .await
+                    .len(),
+            ),
+        }
+    }
+
+    pub async fn compute_rows_count(&self) -> Option<usize> {
+        self.compute_bytes_count()
+            .await
+            .map(|count| count.div_ceil(self.bytes_per_row))
+    }
+
+    pub fn compute_rows_count_sync(&self) -> Option<usize> {
+        futures_executor::block_on(self.compute_rows_count())
+    }
+
+    /// Compute at most `count` rows starting from the row with the `start` index
+    pub async fn compute_rows(&self, start: usize, count: usize) -> Vec<PhlowHexDumpRow> {
+        let offset = start.saturating_mul(self.bytes_per_row);
+        let bytes = self
+            .compute_bytes(offset, count.saturating_mul(self.bytes_per_row))
+            .await;
+
+        bytes
+            .chunks(self.bytes_per_row)
+            .enumerate()
+            .map(|(index, bytes)| PhlowHexDumpRow {
+                offset: offset + index * self.bytes_per_row,
+                bytes: bytes.to_vec(),
+            })
+            .collect()
+    }
+
+    pub fn compute_rows_sync(&self, start: usize, count: usize) -> Vec<PhlowHexDumpRow> {
+        futures_executor::block_on(self.compute_rows(start, count))
+    }
+
+    pub async fn compute_highlights(&self) -> Vec<PhlowHexDumpHighlight> {
+        match &self.highlights_computation {
+            None => vec![],
+            Some(computation) => computation.value(&self.object).await.unwrap_or_default(),
+        }
+    }
+
+    pub fn compute_highlights_sync(&self) -> Vec<PhlowHexDumpHighlight> {
+        futures_executor::block_on(self.compute_highlights())
+    }
+}
+
+impl Debug for PhlowHexDumpView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhlowHexDumpView").finish()
+    }
+}
+
+impl Display for PhlowHexDumpView {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.title.as_str())?;
+        if let Some(description) = self.defining_method.get_description() {
+            writeln!(f, "{}", description)?;
+        }
+        writeln!(f, "---------------------")?;
+
+        let highlights = self.compute_highlights_sync();
+        // one more row than displayed tells whether the buffer is longer
+        let mut rows = self.compute_rows_sync(0, Self::MAX_DISPLAYED_ROWS + 1);
+        let is_elided = rows.len() > Self::MAX_DISPLAYED_ROWS;
+        rows.truncate(Self::MAX_DISPLAYED_ROWS);
+
+        for row in rows {
+            let row_highlights = row.highlights(&highlights);
+            let style_at = |index: usize| {
+                row_highlights
+                    .iter()
+                    .rev()
+                    .find(|highlight| highlight.range.contains(&index))
+                    .map(|highlight| &highlight.style)
+            };
+            let paint = |index: usize, text: String| match style_at(index) {
+                None => text,
+                Some(style) => style.paint(&text),
+            };
+
+            let hex = (0..self.bytes_per_row)
+                .map(|index| match row.bytes.get(index) {
+                    None => "  ".to_string(),
+                    Some(byte) => paint(index, format!("{:02x}", byte)),
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            let ascii = row
+                .bytes
+                .iter()
+                .enumerate()
+                .map(|(index, byte)| paint(index, ascii_char(*byte).to_string()))
+                .collect::<String>();
+
+            writeln!(f, "{:08x}  {}  |{}|", row.offset, hex, ascii)?;
+        }
+
+        if is_elided {
+            writeln!(f, "…")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PhlowView for PhlowHexDumpView {
+    fn get_title(&self) -> &str {
+        self.title.as_str()
+    }
+
+    fn get_priority(&self) -> usize {
+        self.priority
+    }
+
+    fn get_view_type(&self) -> &str {
+        Self::view_type()
+    }
+
+    fn get_defining_method(&self) -> &PhlowViewMethod {
+        &self.defining_method
+    }
+
+    fn view_type() -> &'static str
+    where
+        Self: Sized,
+    {
+        "hex_dump_view"
+    }
+
+    fn object(&self) -> &PhlowObject {
+        &self.object
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn to_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    #[cfg(feature = "view-specification")]
+    fn as_view_specification_builder(&self) -> &dyn crate::AsPhlowViewSpecification {
+        self
+    }
+}
+
+#[allow(dead_code)]
+const _: () = {
+    fn assert_send<T: Send>() {}
+
+    // RFC 2056
+    fn assert_all() {
+        assert_send::<PhlowHexDumpView>();
+    }
+};
+
+#[cfg(feature = "view-specification")]
+mod specification {
+    use serde::Serialize;
+
+    use crate::{
+        AsPhlowViewSpecification, PhlowViewSpecification, PhlowViewSpecificationDataTransport,
+        PhlowViewSpecificationListingItem,
+    };
+
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PhlowHexDumpViewSpecification {
+        title: String,
+        priority: usize,
+        data_transport: PhlowViewSpecificationDataTransport,
+        bytes_per_row: usize,
+        method_selector: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip)]
+        phlow_view: PhlowHexDumpView,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PhlowViewSpecificationHexDumpHighlight {
+        start: usize,
+        end: usize,
+        style: PhlowStyle,
+    }
+
+    #[derive(Debug, Clone, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PhlowViewSpecificationHexDumpRowValue {
+        #[serde(skip)]
+        phlow_object: PhlowObject,
+        offset: usize,
+        hex: String,
+        ascii: String,
+        /// Byte ranges relative to the start of the row
+        highlights: Vec<PhlowViewSpecificationHexDumpHighlight>,
+    }
+
+    #[typetag::serialize(name = "hexDumpRowValue")]
+    impl PhlowViewSpecificationListingItem for PhlowViewSpecificationHexDumpRowValue {
+        fn phlow_object(&self) -> &PhlowObject {
+            &self.phlow_object
+        }
+    }
+
+    #[typetag::serialize(name = "GtPhlowHexDumpViewSpecification")]
+    #[async_trait::async_trait]
+    impl PhlowViewSpecification for PhlowHexDumpViewSpecification {
+        async fn retrieve_items(&self) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
+            match self.phlow_view.compute_rows_count().await {
+                Some(count) => self.retrieve_items_range(0, count).await,
+                None => {
+                    let mut items = vec![];
+                    loop {
+                        let page = self
+                            .retrieve_items_range(items.len(), ITEMS_PAGE_SIZE)
+                            .await;
+                        if page.is_empty() {
+                            break items;
+                        }
+                        items.extend(page);
+                    }
+                }
+            }
+        }
+
+        async fn retrieve_items_range(
+            &self,
+            offset: usize,
+            limit: usize,
+        ) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
+            let highlights = self.phlow_view.compute_highlights().await;
+            let bytes_per_row = self.phlow_view.get_bytes_per_row();
+
+            self.phlow_view
+                .compute_rows(offset, limit)
+                .await
+                .into_iter()
+                .map(|row| {
+                    let item: Box<dyn PhlowViewSpecificationListingItem> =
+                        Box::new(PhlowViewSpecificationHexDumpRowValue {
+                            offset: row.get_offset(),
+                            hex: row.hex(bytes_per_row),
+                            ascii: row.ascii(),
+                            highlights: row
+                                .highlights(&highlights)
+                                .into_iter()
+                                .map(|highlight| PhlowViewSpecificationHexDumpHighlight {
+                                    start: highlight.range.start,
+                                    end: highlight.range.end,
+                                    style: highlight.style,
+                                })
+                                .collect(),
+                            phlow_object: PhlowObject::object(row, |_| vec![]),
+                        });
+                    item
+                })
+                .collect()
+        }
+
+        async fn retrieve_items_count(&self) -> Option<usize> {
+            self.phlow_view.compute_rows_count().await
+        }
+
+        async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject> {
+            Some(item.clone())
+        }
+    }
+
+    impl AsPhlowViewSpecification for PhlowHexDumpView {
+        fn create_view_specification(&self) -> Option<Box<dyn PhlowViewSpecification>> {
+            Some(Box::new(PhlowHexDumpViewSpecification {
+                title: self.get_title().to_string(),
+                priority: self.get_priority(),
+                data_transport: PhlowViewSpecificationDataTransport::Lazy,
+                bytes_per_row: self.get_bytes_per_row(),
+                method_selector: self.get_defining_method().full_method_name.clone(),
+                description: self.get_defining_method().description.clone(),
+                phlow_view: self.clone(),
+            }))
+        }
+    }
+}
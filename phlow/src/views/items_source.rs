@@ -0,0 +1,221 @@
+use std::time::Duration;
+
+use futures_util::{stream, FutureExt, Stream, StreamExt};
+
+use crate::{
+    block_on_within_time_budget_of, global_time_budget, Computation, ItemsComputation,
+    ItemsCountComputation, ItemsProgressComputation, ItemsRangeComputation, PhlowComputationError,
+    PhlowObject, PhlowProgress, PhlowViewMethod,
+};
+
+/// The items of a list-like view: how they are computed, paged, counted, cached and budgeted.
+/// A range computation takes precedence over a progress computation, which takes precedence
+/// over the computation of all items
+#[derive(Clone)]
+pub(crate) struct ItemsSource {
+    object: PhlowObject,
+    // views are built anew for every request, so views of the same method share their caches
+    cache_key: String,
+    items_computation: ItemsComputation,
+    items_range_computation: Option<ItemsRangeComputation>,
+    items_progress_computation: Option<ItemsProgressComputation>,
+    items_count_computation: Option<ItemsCountComputation>,
+    cache_size: usize,
+    time_budget: Option<Duration>,
+}
+
+impl ItemsSource {
+    pub(crate) fn new(object: PhlowObject, defining_method: &PhlowViewMethod) -> Self {
+        Self {
+            object,
+            cache_key: defining_method.full_method_name.clone(),
+            items_computation: Default::default(),
+            items_range_computation: None,
+            items_progress_computation: None,
+            items_count_computation: None,
+            cache_size: 0,
+            time_budget: None,
+        }
+    }
+
+    pub(crate) fn object(&self) -> &PhlowObject {
+        &self.object
+    }
+
+    /// Cache the items and their count with the new size
+    pub(crate) fn set_cache_size(&mut self, cache_size: usize) {
+        self.cache_size = cache_size;
+        let items_computation = std::mem::take(&mut self.items_computation);
+        self.items_computation = self.cached_computation(items_computation, "items");
+        self.items_count_computation = self
+            .items_count_computation
+            .take()
+            .map(|computation| self.cached_computation(computation, "items_count"));
+    }
+
+    /// Cache the computation in the cache shared by the views of the same method under `name`
+    pub(crate) fn cached_computation<Return: Clone + Send + Sync + 'static>(
+        &self,
+        computation: Computation<Return>,
+        name: &str,
+    ) -> Computation<Return> {
+        let key = format!("{}/{}", self.cache_key, name);
+        computation.shared_cached(&key, self.cache_size)
+    }
+
+    pub(crate) fn set_time_budget(&mut self, time_budget: Duration) {
+        self.time_budget = Some(time_budget);
+    }
+
+    pub(crate) fn get_time_budget(&self) -> Option<Duration> {
+        self.time_budget.or_else(global_time_budget)
+    }
+
+    pub(crate) fn set_items_computation(&mut self, computation: ItemsComputation) {
+        self.items_computation = self.cached_computation(computation, "items");
+    }
+
+    pub(crate) fn set_items_range_computation(&mut self, computation: ItemsRangeComputation) {
+        self.items_range_computation = Some(computation);
+    }
+
+    pub(crate) fn set_items_progress_computation(&mut self, computation: ItemsProgressComputation) {
+        self.items_progress_computation = Some(computation);
+    }
+
+    pub(crate) fn set_items_count_computation(&mut self, computation: ItemsCountComputation) {
+        self.items_count_computation = Some(self.cached_computation(computation, "items_count"));
+    }
+
+    /// A progress of its own for a computation of the items, if the computation reports one
+    pub(crate) fn new_items_progress(&self) -> Option<PhlowProgress> {
+        self.items_progress_computation
+            .as_ref()
+            .map(|_| PhlowProgress::new())
+    }
+
+    // the progress is only reported to by a progress computation, the others ignore it
+    pub(crate) fn stream(&self, progress: PhlowProgress) -> impl Stream<Item = PhlowObject> + '_ {
+        match (
+            &self.items_range_computation,
+            &self.items_progress_computation,
+        ) {
+            (Some(range_computation), _) => range_computation.stream(&self.object).left_stream(),
+            (None, Some(progress_computation)) => progress_computation
+                .stream(&self.object, progress)
+                .left_stream()
+                .right_stream(),
+            (None, None) => self
+                .items_computation
+                .value_or_else(&self.object, Vec::new)
+                .into_stream()
+                .map(stream::iter)
+                .flatten()
+                .right_stream()
+                .right_stream(),
+        }
+    }
+
+    pub(crate) fn values_block_on(&self, progress: PhlowProgress) -> Vec<PhlowObject> {
+        match (
+            &self.items_range_computation,
+            &self.items_progress_computation,
+        ) {
+            (Some(range_computation), _) => range_computation.values_block_on(&self.object),
+            (None, Some(_)) => futures_executor::block_on(self.stream(progress).collect()),
+            (None, None) => self
+                .items_computation
+                .value_block_on(&self.object)
+                .unwrap_or_default(),
+        }
+    }
+
+    pub(crate) async fn range(
+        &self,
+        start: usize,
+        count: usize,
+        progress: PhlowProgress,
+    ) -> Vec<PhlowObject> {
+        match &self.items_range_computation {
+            Some(range_computation) => range_computation.value(&self.object, start, count).await,
+            None => {
+                self.stream(progress)
+                    .skip(start)
+                    .take(count)
+                    .collect()
+                    .await
+            }
+        }
+    }
+
+    pub(crate) fn range_block_on(
+        &self,
+        start: usize,
+        count: usize,
+        progress: PhlowProgress,
+    ) -> Vec<PhlowObject> {
+        match &self.items_range_computation {
+            Some(range_computation) => range_computation.value_block_on(&self.object, start, count),
+            None => self
+                .values_block_on(progress)
+                .into_iter()
+                .skip(start)
+                .take(count)
+                .collect(),
+        }
+    }
+
+    /// Return the total amount of items if it is known without computing all items
+    pub(crate) async fn count(&self, progress: PhlowProgress) -> Option<usize> {
+        match (&self.items_count_computation, &self.items_range_computation) {
+            (Some(count_computation), _) => count_computation.value(&self.object).await,
+            (None, Some(_)) => None,
+            (None, None) => Some(self.stream(progress).count().await),
+        }
+    }
+
+    pub(crate) fn count_block_on(&self, progress: PhlowProgress) -> Option<usize> {
+        match (&self.items_count_computation, &self.items_range_computation) {
+            (Some(count_computation), _) => count_computation.value_block_on(&self.object),
+            (None, Some(_)) => None,
+            (None, None) => Some(self.values_block_on(progress).len()),
+        }
+    }
+
+    /// Evaluate the block, giving up once the time budget is exceeded. The block runs on
+    /// a worker thread only if the inspected object and the given items are thread safe
+    pub(crate) fn within_time_budget<R: Send + 'static>(
+        &self,
+        items: &[&PhlowObject],
+        block: impl FnOnce() -> R + Send + 'static,
+    ) -> Result<R, PhlowComputationError> {
+        let objects = [&[&self.object], items].concat();
+        block_on_within_time_budget_of(&objects, self.get_time_budget(), block)
+    }
+
+    pub(crate) fn try_values_block_on(
+        &self,
+        progress: PhlowProgress,
+    ) -> Result<Vec<PhlowObject>, PhlowComputationError> {
+        let source = self.clone();
+        self.within_time_budget(&[], move || source.values_block_on(progress))
+    }
+
+    pub(crate) fn try_range_block_on(
+        &self,
+        start: usize,
+        count: usize,
+        progress: PhlowProgress,
+    ) -> Result<Vec<PhlowObject>, PhlowComputationError> {
+        let source = self.clone();
+        self.within_time_budget(&[], move || source.range_block_on(start, count, progress))
+    }
+
+    pub(crate) fn try_count_block_on(
+        &self,
+        progress: PhlowProgress,
+    ) -> Result<Option<usize>, PhlowComputationError> {
+        let source = self.clone();
+        self.within_time_budget(&[], move || source.count_block_on(progress))
+    }
+}
@@ -1,34 +1,39 @@
 use std::any::Any;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
-use futures_util::{stream, FutureExt, Stream, StreamExt};
+use futures_util::Stream;
 
+use crate::views::items_source::ItemsSource;
 use crate::{
-    AsyncComputation, ItemsComputation, PhlowObject, PhlowView, PhlowViewMethod, SendComputation,
-    SyncComputation, SyncMutComputation, TextComputation,
+    AsyncCancellableComputation, AsyncComputation, AsyncProgressComputation, AsyncRangeComputation,
+    ItemsComputation, ItemsCountComputation, ItemsProgressComputation, ItemsRangeComputation,
+    PhlowComputationError, PhlowObject, PhlowProgress, PhlowStyle, PhlowView, PhlowViewMethod,
+    SendComputation, StyleComputation, SyncComputation, SyncMutComputation, SyncRangeComputation,
+    TextComputation,
 };
 
 #[allow(unused)]
 #[derive(Clone)]
 pub struct PhlowListView {
-    object: PhlowObject,
     defining_method: PhlowViewMethod,
     title: String,
     priority: usize,
-    items_computation: ItemsComputation,
+    items_source: ItemsSource,
     item_text_computation: TextComputation,
+    item_style_computation: Option<StyleComputation>,
     send_computation: SendComputation,
 }
 
 impl PhlowListView {
     pub fn new(object: PhlowObject, defining_method: PhlowViewMethod) -> Self {
         Self {
-            object,
+            title: defining_method.get_default_title().to_string(),
+            priority: defining_method.get_default_priority(),
+            items_source: ItemsSource::new(object, &defining_method),
             defining_method,
-            title: "".to_string(),
-            priority: 10,
-            items_computation: Default::default(),
             item_text_computation: Default::default(),
+            item_style_computation: None,
             send_computation: Default::default(),
         }
     }
@@ -43,11 +48,30 @@ impl PhlowListView {
         self
     }
 
+    /// Remember the items and item texts computed for up to `cache_size` objects
+    /// until their values are replaced or mutated. Views built by the same view method share
+    /// the remembered values. Caching is disabled by default
+    pub fn cache_size(mut self, cache_size: usize) -> Self {
+        self.items_source.set_cache_size(cache_size);
+        let item_text_computation = std::mem::take(&mut self.item_text_computation);
+        self.item_text_computation = self
+            .items_source
+            .cached_computation(item_text_computation, "item_text");
+        self
+    }
+
+    /// Give up computations that take longer than the budget instead of the global one
+    pub fn time_budget(mut self, time_budget: Duration) -> Self {
+        self.items_source.set_time_budget(time_budget);
+        self
+    }
+
     pub fn items<T: 'static>(
         mut self,
         items_block: impl SyncComputation<T, Vec<PhlowObject>>,
     ) -> Self {
-        self.items_computation = ItemsComputation::new_sync(items_block);
+        self.items_source
+            .set_items_computation(ItemsComputation::new_sync(items_block));
         self
     }
 
@@ -55,7 +79,8 @@ impl PhlowListView {
         mut self,
         items_block: impl SyncMutComputation<T, Vec<PhlowObject>>,
     ) -> Self {
-        self.items_computation = ItemsComputation::new_sync_mut(items_block);
+        self.items_source
+            .set_items_computation(ItemsComputation::new_sync_mut(items_block));
         self
     }
 
@@ -63,7 +88,78 @@ impl PhlowListView {
         mut self,
         items_block: impl AsyncComputation<T, Vec<PhlowObject>>,
     ) -> Self {
-        self.items_computation = ItemsComputation::new_async(items_block);
+        self.items_source
+            .set_items_computation(ItemsComputation::new_async(items_block));
+        self
+    }
+
+    /// The computation receives a token that is cancelled once the time budget of the view
+    /// is exceeded, long running computations should check it and stop early
+    pub fn async_items_cancellable<T: 'static>(
+        mut self,
+        items_block: impl AsyncCancellableComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.items_source
+            .set_items_computation(ItemsComputation::new_async_cancellable(items_block));
+        self
+    }
+
+    /// An error returned by the computation is shown as a single item carrying its message
+    pub fn try_items<T: 'static, E: Display + 'static>(
+        mut self,
+        items_block: impl SyncComputation<T, Result<Vec<PhlowObject>, E>>,
+    ) -> Self {
+        self.items_source
+            .set_items_computation(ItemsComputation::try_new_sync(items_block));
+        self
+    }
+
+    pub fn async_try_items<T: 'static, E: Display + 'static>(
+        mut self,
+        items_block: impl AsyncComputation<T, Result<Vec<PhlowObject>, E>>,
+    ) -> Self {
+        self.items_source
+            .set_items_computation(ItemsComputation::try_new_async(items_block));
+        self
+    }
+
+    /// The computation reports its progress and may emit items before it is finished,
+    /// the emitted items are streamed by [`PhlowListView::compute_items`] right away.
+    /// Every computation reports to a progress of its own, see
+    /// [`PhlowListView::compute_items_with_progress`]
+    pub fn async_items_with_progress<T: 'static>(
+        mut self,
+        items_block: impl AsyncProgressComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.items_source
+            .set_items_progress_computation(ItemsProgressComputation::new_async(items_block));
+        self
+    }
+
+    /// Compute at most `count` items starting from the `start` index.
+    /// When set, items are computed lazily page by page instead of all at once.
+    pub fn items_range<T: 'static>(
+        mut self,
+        items_block: impl SyncRangeComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.items_source
+            .set_items_range_computation(ItemsRangeComputation::new_sync(items_block));
+        self
+    }
+
+    pub fn async_items_range<T: 'static>(
+        mut self,
+        items_block: impl AsyncRangeComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        self.items_source
+            .set_items_range_computation(ItemsRangeComputation::new_async(items_block));
+        self
+    }
+
+    /// Compute the total amount of items without computing the items themselves
+    pub fn items_count<T: 'static>(mut self, count_block: impl SyncComputation<T, usize>) -> Self {
+        self.items_source
+            .set_items_count_computation(ItemsCountComputation::new_sync(count_block));
         self
     }
 
@@ -71,7 +167,28 @@ impl PhlowListView {
         mut self,
         item_text_block: impl SyncComputation<T, String>,
     ) -> Self {
-        self.item_text_computation = TextComputation::new_sync(item_text_block);
+        self.item_text_computation = self
+            .items_source
+            .cached_computation(TextComputation::new_sync(item_text_block), "item_text");
+        self
+    }
+
+    pub fn async_item_text<T: 'static>(
+        mut self,
+        item_text_block: impl AsyncComputation<T, String>,
+    ) -> Self {
+        self.item_text_computation = self
+            .items_source
+            .cached_computation(TextComputation::new_async(item_text_block), "item_text");
+        self
+    }
+
+    /// Style items, for example to highlight errors or dim inactive items
+    pub fn item_style<T: 'static>(
+        mut self,
+        item_style_block: impl SyncComputation<T, PhlowStyle>,
+    ) -> Self {
+        self.item_style_computation = Some(StyleComputation::new_sync(item_style_block));
         self
     }
 
@@ -83,18 +200,97 @@ impl PhlowListView {
         self
     }
 
+    pub fn async_send<T: 'static>(
+        mut self,
+        item_send_block: impl AsyncComputation<T, PhlowObject>,
+    ) -> Self {
+        self.send_computation = SendComputation::new_async(item_send_block);
+        self
+    }
+
     pub fn compute_items(&self) -> impl Stream<Item = PhlowObject> + '_ {
-        self.items_computation
-            .value_or_else(&self.object, || vec![])
-            .into_stream()
-            .map(|items| stream::iter(items))
-            .flatten()
+        self.compute_items_with_progress(PhlowProgress::new())
+    }
+
+    /// Stream the items while reporting the progress of their computation to the given
+    /// progress, created by [`PhlowView::new_items_progress`]
+    pub fn compute_items_with_progress(
+        &self,
+        progress: PhlowProgress,
+    ) -> impl Stream<Item = PhlowObject> + '_ {
+        self.items_source.stream(progress)
     }
 
     pub fn compute_items_sync(&self) -> Vec<PhlowObject> {
-        self.items_computation
-            .value_block_on(&self.object)
-            .unwrap_or_default()
+        self.items_source.values_block_on(PhlowProgress::new())
+    }
+
+    pub async fn compute_items_range(&self, start: usize, count: usize) -> Vec<PhlowObject> {
+        self.items_source
+            .range(start, count, PhlowProgress::new())
+            .await
+    }
+
+    pub fn compute_items_range_sync(&self, start: usize, count: usize) -> Vec<PhlowObject> {
+        self.items_source
+            .range_block_on(start, count, PhlowProgress::new())
+    }
+
+    /// Return the total amount of items if it is known without computing all items
+    pub async fn compute_items_count(&self) -> Option<usize> {
+        self.items_source.count(PhlowProgress::new()).await
+    }
+
+    pub fn compute_items_count_sync(&self) -> Option<usize> {
+        self.items_source.count_block_on(PhlowProgress::new())
+    }
+
+    /// Evaluate the block with a copy of the view, giving up once the time budget of the view
+    /// is exceeded. The block runs on a worker thread only if the inspected object and the
+    /// given items are thread safe
+    fn within_time_budget<R: Send + 'static>(
+        &self,
+        items: &[&PhlowObject],
+        block: impl FnOnce(Self) -> R + Send + 'static,
+    ) -> Result<R, PhlowComputationError> {
+        let view = self.clone();
+        self.items_source
+            .within_time_budget(items, move || block(view))
+    }
+
+    /// Compute all items, giving up once the time budget of the view is exceeded
+    pub fn try_compute_items_sync(&self) -> Result<Vec<PhlowObject>, PhlowComputationError> {
+        self.try_compute_items_sync_with_progress(PhlowProgress::new())
+    }
+
+    pub fn try_compute_items_sync_with_progress(
+        &self,
+        progress: PhlowProgress,
+    ) -> Result<Vec<PhlowObject>, PhlowComputationError> {
+        self.items_source.try_values_block_on(progress)
+    }
+
+    pub fn try_compute_items_range_sync(
+        &self,
+        start: usize,
+        count: usize,
+    ) -> Result<Vec<PhlowObject>, PhlowComputationError> {
+        self.items_source
+            .try_range_block_on(start, count, PhlowProgress::new())
+    }
+
+    pub fn try_compute_items_count_sync(&self) -> Result<Option<usize>, PhlowComputationError> {
+        self.items_source.try_count_block_on(PhlowProgress::new())
+    }
+
+    pub fn try_compute_item_text_sync(
+        &self,
+        item: &PhlowObject,
+    ) -> Result<String, PhlowComputationError> {
+        let computed_item = item.clone();
+        self.within_time_budget(&[item], move |view| {
+            view.compute_item_text_sync(&computed_item)
+        })
     }
 
     pub async fn compute_item_to_send(&self, item: &PhlowObject) -> Option<PhlowObject> {
@@ -116,6 +312,19 @@ impl PhlowListView {
             .value_or_else(item, || "Error coercing item type".to_string())
             .await
     }
+
+    pub fn compute_item_style_sync(&self, item: &PhlowObject) -> Option<PhlowStyle> {
+        self.item_style_computation
+            .as_ref()
+            .and_then(|computation| computation.value_block_on(item))
+    }
+
+    pub async fn compute_item_style(&self, item: &PhlowObject) -> Option<PhlowStyle> {
+        match &self.item_style_computation {
+            None => None,
+            Some(computation) => computation.value(item).await,
+        }
+    }
 }
 
 impl Debug for PhlowListView {
@@ -127,13 +336,32 @@ impl Debug for PhlowListView {
 impl Display for PhlowListView {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{}", self.title.as_str())?;
+        if let Some(description) = self.defining_method.get_description() {
+            writeln!(f, "{}", description)?;
+        }
         writeln!(f, "{}", "---------------------",)?;
         writeln!(f, "{0: >3} | {1}", "i", "item",)?;
 
-        let items = self.compute_items_sync();
-
-        for (index, item) in items.into_iter().enumerate() {
-            writeln!(f, "{0:>3} | {1}", index, self.compute_item_text_sync(&item))?;
+        // the items and their texts are computed within a single time budget
+        let item_texts = self.within_time_budget(&[], |view| {
+            view.compute_items_sync()
+                .into_iter()
+                .map(|item| {
+                    let item_text = view.compute_item_text_sync(&item);
+                    match view.compute_item_style_sync(&item) {
+                        None => item_text,
+                        Some(style) => style.paint(&item_text),
+                    }
+                })
+                .collect::<Vec<_>>()
+        });
+        let item_texts = match item_texts {
+            Ok(item_texts) => item_texts,
+            Err(error) => return writeln!(f, "{}", error),
+        };
+
+        for (index, item_text) in item_texts.into_iter().enumerate() {
+            writeln!(f, "{0:>3} | {1}", index, item_text)?;
         }
 
         Ok(())
@@ -161,8 +389,16 @@ impl PhlowView for PhlowListView {
         "list_view"
     }
 
+    fn get_time_budget(&self) -> Option<Duration> {
+        self.items_source.get_time_budget()
+    }
+
+    fn new_items_progress(&self) -> Option<PhlowProgress> {
+        self.items_source.new_items_progress()
+    }
+
     fn object(&self) -> &PhlowObject {
-        &self.object
+        self.items_source.object()
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -191,6 +427,7 @@ const _: () = {
 
 #[cfg(feature = "view-specification")]
 mod specification {
+    use futures_util::{stream, StreamExt};
     use serde::Serialize;
 
     use crate::views::view_specification::PhlowViewSpecificationTextualItemValue;
@@ -208,8 +445,13 @@ mod specification {
         priority: usize,
         data_transport: PhlowViewSpecificationDataTransport,
         method_selector: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
         #[serde(skip)]
         phlow_view: PhlowListView,
+        // the items of a specification are computed with a progress of their own
+        #[serde(skip)]
+        progress: Option<PhlowProgress>,
     }
 
     #[typetag::serialize(name = "GtPhlowListViewSpecification")]
@@ -217,20 +459,57 @@ mod specification {
     impl PhlowViewSpecification for PhlowListViewSpecification {
         async fn retrieve_items(&self) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
             self.phlow_view
-                .compute_items()
-                .then(|each| async move {
-                    Box::new(PhlowViewSpecificationTextualItemValue {
-                        phlow_object: each.clone(),
-                        item_text: self.phlow_view.compute_item_text(&each).await,
-                    }) as Box<dyn PhlowViewSpecificationListingItem>
-                })
+                .items_source
+                .stream(self.progress())
+                .then(|each| self.listing_item(each))
                 .collect()
                 .await
         }
 
+        async fn retrieve_items_range(
+            &self,
+            offset: usize,
+            limit: usize,
+        ) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
+            let items = self
+                .phlow_view
+                .items_source
+                .range(offset, limit, self.progress())
+                .await;
+            stream::iter(items)
+                .then(|each| self.listing_item(each))
+                .collect()
+                .await
+        }
+
+        async fn retrieve_items_count(&self) -> Option<usize> {
+            self.phlow_view.items_source.count(self.progress()).await
+        }
+
         async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject> {
             self.phlow_view.compute_item_to_send(item).await
         }
+
+        fn get_items_progress(&self) -> Option<PhlowProgress> {
+            self.progress.clone()
+        }
+    }
+
+    impl PhlowListViewSpecification {
+        fn progress(&self) -> PhlowProgress {
+            self.progress.clone().unwrap_or_default()
+        }
+
+        async fn listing_item(
+            &self,
+            each: PhlowObject,
+        ) -> Box<dyn PhlowViewSpecificationListingItem> {
+            Box::new(PhlowViewSpecificationTextualItemValue {
+                item_text: self.phlow_view.compute_item_text(&each).await,
+                item_style: self.phlow_view.compute_item_style(&each).await,
+                phlow_object: each,
+            })
+        }
     }
 
     impl AsPhlowViewSpecification for PhlowListView {
@@ -240,7 +519,9 @@ mod specification {
                 priority: self.get_priority(),
                 data_transport: PhlowViewSpecificationDataTransport::Lazy,
                 method_selector: self.get_defining_method().full_method_name.clone(),
+                description: self.get_defining_method().description.clone(),
                 phlow_view: self.clone(),
+                progress: self.new_items_progress(),
             }))
         }
     }
@@ -1,11 +1,45 @@
-pub use bitmap_view::{PhlowBitmap, PhlowBitmapView};
-pub use columned_list_view::PhlowColumnedListView;
+pub use bitmap::{PhlowBitmap, PhlowBitmapError, PixelFormat};
+pub use bitmap_encoding::PhlowBitmapEncoding;
+#[cfg(feature = "view-specification")]
+pub use bitmap_view::PhlowBitmapSpecification;
+pub use bitmap_view::PhlowBitmapView;
+pub use canvas::{
+    PhlowCanvas, PhlowCanvasCommand, PhlowPaint, PhlowPath, PhlowPathSegment, PhlowShape,
+    PhlowTransform,
+};
+pub use canvas_rasterizer::MAX_RASTER_EXTENT;
+pub use canvas_view::PhlowCanvasView;
+pub use columned_list_view::{
+    PhlowColumn, PhlowColumnAlignment, PhlowColumnCellType, PhlowColumnWidth, PhlowColumnedListView,
+};
+pub use composite_view::{PhlowCompositeLayout, PhlowCompositeView, SUB_VIEW_SEPARATOR};
+pub use computation_budget::{
+    block_in_place_within_time_budget, block_on_within_time_budget, block_on_within_time_budget_of,
+    global_time_budget, set_global_time_budget, within_time_budget, PhlowCancellationToken,
+    PhlowComputationError,
+};
+pub use computation_cache::ComputationCache;
+pub use diff::{
+    diff_lines, unified_diff, PhlowDiff, PhlowDiffLine, PhlowDiffLineKind, PhlowDiffMode,
+};
+pub use diff_view::PhlowDiffView;
+pub use graph::{PhlowGraph, PhlowGraphEdge, PhlowGraphLink, PhlowGraphNode};
+pub use graph_view::PhlowGraphView;
+pub use hex_dump_view::{
+    BytesComputation, HighlightsComputation, PhlowHexDumpHighlight, PhlowHexDumpRow,
+    PhlowHexDumpView,
+};
 pub use list_view::PhlowListView;
+pub use progress::{PhlowProgress, PhlowProgressReport};
+pub use style::{PhlowColor, PhlowStyle};
 pub use text_view::PhlowTextView;
 pub use view::types::*;
 pub use view::{
-    downcast_view_ref, Computation, ItemComputation, ItemsComputation, PhlowProtoView, PhlowView,
-    SendComputation, TextComputation,
+    downcast_view_ref, BitmapComputation, BytesRangeComputation, CanvasComputation, Computation,
+    DiffComputation, EdgesComputation, FromComputationError, ItemComputation, ItemsComputation,
+    ItemsCountComputation, ItemsProgressComputation, ItemsRangeComputation, PhlowProtoView,
+    PhlowView, RangeComputation, SendComputation, StyleComputation, TextComputation,
+    ITEMS_PAGE_SIZE,
 };
 #[cfg(feature = "view-specification")]
 pub use view_specification::{
@@ -13,9 +47,25 @@ pub use view_specification::{
     PhlowViewSpecificationListingItem, PhlowViewSpecificationListingType,
 };
 
+mod bitmap;
+mod bitmap_encoding;
 mod bitmap_view;
+mod canvas;
+mod canvas_rasterizer;
+mod canvas_view;
 mod columned_list_view;
+mod composite_view;
+mod computation_budget;
+mod computation_cache;
+mod diff;
+mod diff_view;
+mod graph;
+mod graph_view;
+mod hex_dump_view;
+mod items_source;
 mod list_view;
+mod progress;
+mod style;
 mod text_view;
 mod view;
 
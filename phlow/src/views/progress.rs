@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use futures_channel::mpsc::UnboundedSender;
+use parking_lot::Mutex;
+
+use crate::PhlowObject;
+
+/// The state of a computation at the moment it was requested
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+#[cfg_attr(feature = "view-specification", serde(rename_all = "camelCase"))]
+pub struct PhlowProgressReport {
+    /// Completed fraction between 0 and 1, unknown until the computation reports it
+    pub fraction: Option<f32>,
+    pub message: String,
+    /// The amount of items emitted so far
+    pub items_count: usize,
+    pub is_finished: bool,
+}
+
+#[derive(Default)]
+struct PhlowProgressState {
+    report: PhlowProgressReport,
+    batches: Option<UnboundedSender<Vec<PhlowObject>>>,
+}
+
+/// Lets a long-running items computation report how far it got and emit items
+/// before the whole computation is finished. Clones report to the same progress,
+/// every computation of items should be given a progress of its own
+#[derive(Clone, Default)]
+pub struct PhlowProgress(Arc<Mutex<PhlowProgressState>>);
+
+impl PhlowProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report the completed fraction between 0 and 1 along with a description of the current step
+    pub fn report(&self, fraction: f32, message: impl Into<String>) {
+        let mut state = self.0.lock();
+        state.report.fraction = Some(fraction.clamp(0.0, 1.0));
+        state.report.message = message.into();
+    }
+
+    pub fn message(&self, message: impl Into<String>) {
+        self.0.lock().report.message = message.into();
+    }
+
+    /// Stream the items right away, they are followed by items emitted later
+    /// and by the items returned by the computation
+    pub fn emit(&self, items: Vec<PhlowObject>) {
+        if items.is_empty() {
+            return;
+        }
+        let mut state = self.0.lock();
+        state.report.items_count += items.len();
+        if let Some(batches) = state.batches.as_ref() {
+            let _ = batches.unbounded_send(items);
+        }
+    }
+
+    pub fn get_report(&self) -> PhlowProgressReport {
+        self.0.lock().report.clone()
+    }
+
+    pub fn get_fraction(&self) -> Option<f32> {
+        self.0.lock().report.fraction
+    }
+
+    pub fn get_message(&self) -> String {
+        self.0.lock().report.message.clone()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.0.lock().report.is_finished
+    }
+
+    /// Emit the items of the computation to the sender
+    pub(crate) fn start(&self, batches: UnboundedSender<Vec<PhlowObject>>) {
+        self.0.lock().batches = Some(batches);
+    }
+
+    /// Close the stream of emitted items
+    pub(crate) fn finish(&self) {
+        let mut state = self.0.lock();
+        state.report.fraction = Some(1.0);
+        state.report.is_finished = true;
+        state.batches = None;
+    }
+}
+
+impl std::fmt::Debug for PhlowProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("PhlowProgress")
+            .field(&self.get_report())
+            .finish()
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhlowColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: u8,
+}
+
+impl PhlowColor {
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+    pub const WHITE: Self = Self::rgb(255, 255, 255);
+    pub const GRAY: Self = Self::rgb(128, 128, 128);
+    pub const RED: Self = Self::rgb(204, 0, 0);
+    pub const GREEN: Self = Self::rgb(0, 153, 0);
+    pub const BLUE: Self = Self::rgb(0, 102, 204);
+    pub const ORANGE: Self = Self::rgb(230, 126, 0);
+
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba(red, green, blue, 255)
+    }
+
+    pub const fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Pack the color as `0xRRGGBBAA`
+    pub fn as_u32(&self) -> u32 {
+        u32::from_be_bytes([self.red, self.green, self.blue, self.alpha])
+    }
+}
+
+impl Display for PhlowColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{:02X}{:02X}{:02X}{:02X}",
+            self.red, self.green, self.blue, self.alpha
+        )
+    }
+}
+
+#[cfg(feature = "view-specification")]
+impl serde::Serialize for PhlowColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Visual emphasis of an item or a cell of a list
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "view-specification", derive(serde::Serialize))]
+#[cfg_attr(feature = "view-specification", serde(rename_all = "camelCase"))]
+pub struct PhlowStyle {
+    #[cfg_attr(
+        feature = "view-specification",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    foreground: Option<PhlowColor>,
+    #[cfg_attr(
+        feature = "view-specification",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    background: Option<PhlowColor>,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    dimmed: bool,
+    /// Name of an icon to display next to the text, it is up to the client to resolve it
+    #[cfg_attr(
+        feature = "view-specification",
+        serde(skip_serializing_if = "Option::is_none")
+    )]
+    icon: Option<String>,
+}
+
+impl PhlowStyle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn foreground(mut self, color: PhlowColor) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    pub fn background(mut self, color: PhlowColor) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    pub fn dimmed(mut self) -> Self {
+        self.dimmed = true;
+        self
+    }
+
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn get_foreground(&self) -> Option<PhlowColor> {
+        self.foreground
+    }
+
+    pub fn get_background(&self) -> Option<PhlowColor> {
+        self.background
+    }
+
+    pub fn is_bold(&self) -> bool {
+        self.bold
+    }
+
+    pub fn is_italic(&self) -> bool {
+        self.italic
+    }
+
+    pub fn is_underline(&self) -> bool {
+        self.underline
+    }
+
+    pub fn is_dimmed(&self) -> bool {
+        self.dimmed
+    }
+
+    pub fn get_icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// Wrap the text in ANSI escape codes for terminal output
+    pub fn paint(&self, text: &str) -> String {
+        let mut codes: Vec<String> = vec![];
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if self.dimmed {
+            codes.push("2".to_string());
+        }
+        if self.italic {
+            codes.push("3".to_string());
+        }
+        if self.underline {
+            codes.push("4".to_string());
+        }
+        if let Some(color) = self.foreground {
+            codes.push(format!("38;2;{};{};{}", color.red, color.green, color.blue));
+        }
+        if let Some(color) = self.background {
+            codes.push(format!("48;2;{};{};{}", color.red, color.green, color.blue));
+        }
+
+        if codes.is_empty() {
+            return text.to_string();
+        }
+        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+    }
+}
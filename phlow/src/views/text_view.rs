@@ -1,27 +1,32 @@
 use std::any::Any;
 use std::fmt::{Debug, Display, Formatter};
+use std::time::Duration;
 
 use crate::{
-    PhlowObject, PhlowView, PhlowViewMethod, SyncComputation, SyncMutComputation, TextComputation,
+    global_time_budget, AsyncComputation, PhlowComputationError, PhlowObject, PhlowView,
+    PhlowViewMethod, SyncComputation, SyncMutComputation, TextComputation,
 };
 
 #[allow(unused)]
+#[derive(Clone)]
 pub struct PhlowTextView {
     object: PhlowObject,
     defining_method: PhlowViewMethod,
     title: String,
     priority: usize,
     text_computation: TextComputation,
+    time_budget: Option<Duration>,
 }
 
 impl PhlowTextView {
     pub fn new(object: PhlowObject, defining_method: PhlowViewMethod) -> Self {
         Self {
+            title: defining_method.get_default_title().to_string(),
+            priority: defining_method.get_default_priority(),
             object,
             defining_method,
-            title: "".to_string(),
-            priority: 10,
             text_computation: Default::default(),
+            time_budget: None,
         }
     }
 
@@ -35,6 +40,12 @@ impl PhlowTextView {
         self
     }
 
+    /// Give up computations that take longer than the budget instead of the global one
+    pub fn time_budget(mut self, time_budget: Duration) -> Self {
+        self.time_budget = Some(time_budget);
+        self
+    }
+
     pub fn text<T: 'static>(mut self, text_block: impl SyncComputation<T, String>) -> Self {
         self.text_computation = TextComputation::new_sync(text_block);
         self
@@ -45,11 +56,51 @@ impl PhlowTextView {
         self
     }
 
+    pub fn async_text<T: 'static>(mut self, text_block: impl AsyncComputation<T, String>) -> Self {
+        self.text_computation = TextComputation::new_async(text_block);
+        self
+    }
+
+    /// An error returned by the computation is shown instead of the text
+    pub fn try_text<T: 'static, E: Display + 'static>(
+        mut self,
+        text_block: impl SyncComputation<T, Result<String, E>>,
+    ) -> Self {
+        self.text_computation = TextComputation::try_new_sync(text_block);
+        self
+    }
+
+    pub fn async_try_text<T: 'static, E: Display + 'static>(
+        mut self,
+        text_block: impl AsyncComputation<T, Result<String, E>>,
+    ) -> Self {
+        self.text_computation = TextComputation::try_new_async(text_block);
+        self
+    }
+
+    pub(crate) fn text_computation(mut self, text_computation: TextComputation) -> Self {
+        self.text_computation = text_computation;
+        self
+    }
+
     pub fn compute_text(&self) -> String {
         self.text_computation
             .value_block_on(&self.object)
             .unwrap_or_else(|| "Error coercing item type".to_string())
     }
+
+    /// Compute the text, giving up once the time budget of the view is exceeded
+    pub fn try_compute_text(&self) -> Result<String, PhlowComputationError> {
+        self.text_computation
+            .value_within_block_on(&self.object, self.get_time_budget())
+            .map(|text| text.unwrap_or_else(|| "Error coercing item type".to_string()))
+    }
+
+    pub async fn async_compute_text(&self) -> String {
+        self.text_computation
+            .value_or_else(&self.object, || "Error coercing item type".to_string())
+            .await
+    }
 }
 
 impl Debug for PhlowTextView {
@@ -61,8 +112,14 @@ impl Debug for PhlowTextView {
 impl Display for PhlowTextView {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{}", self.title.as_str())?;
+        if let Some(description) = self.defining_method.get_description() {
+            writeln!(f, "{}", description)?;
+        }
         writeln!(f, "{}", "---------------------",)?;
-        writeln!(f, "{}", self.compute_text())?;
+        match self.try_compute_text() {
+            Ok(text) => writeln!(f, "{}", text)?,
+            Err(error) => writeln!(f, "{}", error)?,
+        }
 
         Ok(())
     }
@@ -92,6 +149,10 @@ impl PhlowView for PhlowTextView {
         "text_view"
     }
 
+    fn get_time_budget(&self) -> Option<Duration> {
+        self.time_budget.or_else(global_time_budget)
+    }
+
     fn object(&self) -> &PhlowObject {
         &self.object
     }
@@ -129,6 +190,10 @@ mod specification {
         data_transport: PhlowViewSpecificationDataTransport,
         string: String,
         method_selector: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip)]
+        phlow_view: PhlowTextView,
     }
 
     #[typetag::serialize(name = "GtPhlowTextEditorViewSpecification")]
@@ -138,6 +203,10 @@ mod specification {
             vec![]
         }
 
+        async fn retrieve_included_data(&mut self) {
+            self.string = self.phlow_view.async_compute_text().await;
+        }
+
         async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject> {
             Some(item.clone())
         }
@@ -149,8 +218,10 @@ mod specification {
                 title: self.get_title().to_string(),
                 priority: self.get_priority(),
                 data_transport: PhlowViewSpecificationDataTransport::Included,
-                string: self.compute_text(),
+                string: "".to_string(),
                 method_selector: self.get_defining_method().full_method_name.clone(),
+                description: self.get_defining_method().description.clone(),
+                phlow_view: self.clone(),
             }))
         }
     }
@@ -4,13 +4,18 @@ use std::fmt::{Debug, Display, Formatter};
 use std::future::{ready, Future};
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 
-use futures_util::FutureExt;
+use futures_util::{stream, FutureExt, Stream, StreamExt};
 
 use crate::{
-    AsyncComputation, PhlowBitmapView, PhlowColumnedListView, PhlowListView, PhlowObject,
-    PhlowTextView, PhlowViewMethod, SyncComputation, SyncMutComputation, TypedPhlowObject,
-    TypedPhlowObjectMut,
+    block_on_within_time_budget_of, global_time_budget, AsyncCancellableComputation,
+    AsyncComputation, AsyncProgressComputation, AsyncRangeComputation, ComputationCache,
+    PhlowBitmap, PhlowBitmapView, PhlowCancellationToken, PhlowCanvas, PhlowCanvasView,
+    PhlowColumnedListView, PhlowCompositeView, PhlowComputationError, PhlowDiff, PhlowDiffView,
+    PhlowGraphEdge, PhlowGraphView, PhlowHexDumpView, PhlowListView, PhlowObject, PhlowProgress,
+    PhlowStyle, PhlowTextView, PhlowVariant, PhlowViewMethod, SyncComputation, SyncMutComputation,
+    SyncRangeComputation, TypedPhlowObject, TypedPhlowObjectMut,
 };
 
 pub trait PhlowView: Debug + Display + Any {
@@ -22,6 +27,15 @@ pub trait PhlowView: Debug + Display + Any {
     where
         Self: Sized;
     fn object(&self) -> &PhlowObject;
+    /// How long computations of the view may take before they are given up
+    fn get_time_budget(&self) -> Option<Duration> {
+        global_time_budget()
+    }
+    /// A new progress for one computation of the items, if their computation reports one.
+    /// Pass it to the computation of the items to poll it while they are computed
+    fn new_items_progress(&self) -> Option<PhlowProgress> {
+        None
+    }
     fn list(&self) -> PhlowListView {
         PhlowListView::new(self.object().clone(), self.get_defining_method().clone())
     }
@@ -34,14 +48,69 @@ pub trait PhlowView: Debug + Display + Any {
     fn bitmap(&self) -> PhlowBitmapView {
         PhlowBitmapView::new(self.object().clone(), self.get_defining_method().clone())
     }
+    fn canvas(&self) -> PhlowCanvasView {
+        PhlowCanvasView::new(self.object().clone(), self.get_defining_method().clone())
+    }
+    fn composite(&self) -> PhlowCompositeView {
+        PhlowCompositeView::new(self.object().clone(), self.get_defining_method().clone())
+    }
+    fn diff(&self) -> PhlowDiffView {
+        PhlowDiffView::new(self.object().clone(), self.get_defining_method().clone())
+    }
+    fn graph(&self) -> PhlowGraphView {
+        PhlowGraphView::new(self.object().clone(), self.get_defining_method().clone())
+    }
+    fn hex_dump(&self) -> PhlowHexDumpView {
+        PhlowHexDumpView::new(self.object().clone(), self.get_defining_method().clone())
+    }
+    /// List the variant name and the fields of an enum value
+    fn variant<T: PhlowVariant + 'static>(&self) -> PhlowColumnedListView
+    where
+        Self: Sized,
+    {
+        self.columned_list()
+            .title("Variant")
+            .items::<T>(|value| {
+                let name = PhlowObject::object(value.phlow_variant_name(), |_| vec![]);
+                let fields = value.phlow_variant_fields(value.phlow_object());
+                std::iter::once(("variant".to_string(), name))
+                    .chain(fields)
+                    .map(|row| PhlowObject::object(row, |_| vec![]))
+                    .collect()
+            })
+            .column(|column| {
+                column
+                    .title("Field")
+                    .text::<(String, PhlowObject)>(|row| row.0.clone())
+            })
+            .column_item::<(String, PhlowObject)>("Value", |row| row.1.clone())
+            .send::<(String, PhlowObject)>(|row| row.1.clone())
+    }
+    /// A text view showing why the view could not be built, used for view methods
+    /// that return `Result<impl PhlowView, E>`
+    fn error(&self, error: impl Display) -> PhlowTextView
+    where
+        Self: Sized,
+    {
+        let error = PhlowComputationError::Failed(error.to_string());
+        self.text()
+            .text_computation(TextComputation::new_value(error.to_string()))
+    }
     fn as_any(&self) -> &dyn Any;
     fn to_any(self: Box<Self>) -> Box<dyn Any>;
     #[cfg(feature = "view-specification")]
     fn as_view_specification_builder(&self) -> &dyn crate::AsPhlowViewSpecification;
+    /// Create a view specification with its included data computed in place.
+    /// Prefer [`crate::AsPhlowViewSpecification::create_view_specification`] followed by
+    /// awaiting [`crate::PhlowViewSpecification::retrieve_included_data`] in async contexts
     #[cfg(feature = "view-specification")]
     fn as_view_specification(&self) -> Option<Box<dyn crate::PhlowViewSpecification>> {
         self.as_view_specification_builder()
             .create_view_specification()
+            .map(|mut specification| {
+                futures_executor::block_on(specification.retrieve_included_data());
+                specification
+            })
     }
 }
 
@@ -68,11 +137,11 @@ impl Display for PhlowProtoView {
 
 impl PhlowView for PhlowProtoView {
     fn get_title(&self) -> &str {
-        "Untitled"
+        self.defining_method.get_title().unwrap_or("Untitled")
     }
 
     fn get_priority(&self) -> usize {
-        10
+        self.defining_method.get_default_priority()
     }
 
     fn get_view_type(&self) -> &str {
@@ -122,7 +191,6 @@ pub fn downcast_view_ref<T: PhlowView>(
 }
 
 /// Represents a computation that can be either sync or async
-#[derive(Clone)]
 pub enum Computation<Return> {
     Sync(Arc<dyn Fn(&PhlowObject) -> Option<Return> + Send + Sync>),
     Async(
@@ -132,7 +200,21 @@ pub enum Computation<Return> {
                 + Sync,
         >,
     ),
+    /// Remembers the values of the wrapped computation per object, see [`Computation::cached`]
+    Cached(Box<Computation<Return>>, Arc<ComputationCache<Return>>),
 }
+
+// cloning shares the closures and the cache, the values do not need to be `Clone`
+impl<Return> Clone for Computation<Return> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Sync(computation) => Self::Sync(computation.clone()),
+            Self::Async(computation) => Self::Async(computation.clone()),
+            Self::Cached(computation, cache) => Self::Cached(computation.clone(), cache.clone()),
+        }
+    }
+}
+
 impl<Return: Send + Sync + 'static> Computation<Return> {
     pub fn new_sync<T: 'static>(items_block: impl SyncComputation<T, Return>) -> Self {
         Self::Sync(Arc::new(move |object: &PhlowObject| {
@@ -159,6 +241,65 @@ impl<Return: Send + Sync + 'static> Computation<Return> {
         }))
     }
 
+    /// Always compute the same value regardless of the object
+    pub fn new_value(value: Return) -> Self
+    where
+        Return: Clone,
+    {
+        Self::Sync(Arc::new(move |_| Some(value.clone())))
+    }
+
+    /// The computation receives a token that is cancelled once its time budget is exceeded
+    pub fn new_async_cancellable<T: 'static>(
+        items_block: impl AsyncCancellableComputation<T, Return>,
+    ) -> Self {
+        Self::Async(Arc::new(move |object: &PhlowObject| {
+            object.value_ref::<T>().map(|reference| {
+                items_block(
+                    TypedPhlowObject::new(object, &reference),
+                    PhlowCancellationToken::current(),
+                )
+            })
+        }))
+    }
+
+    /// An error returned by the computation is turned into a value carrying its message
+    pub fn try_new_sync<T: 'static, E: Display + 'static>(
+        items_block: impl SyncComputation<T, Result<Return, E>>,
+    ) -> Self
+    where
+        Return: FromComputationError,
+    {
+        Self::new_sync::<T>(move |object| items_block(object).unwrap_or_else(Return::from_error))
+    }
+
+    pub fn try_new_async<T: 'static, E: Display + 'static>(
+        items_block: impl AsyncComputation<T, Result<Return, E>>,
+    ) -> Self
+    where
+        Return: FromComputationError,
+    {
+        Self::new_async::<T>(move |object| {
+            items_block(object)
+                .map(|value| value.unwrap_or_else(Return::from_error))
+                .boxed()
+        })
+    }
+
+    pub fn get_cache(&self) -> Option<&ComputationCache<Return>> {
+        match self {
+            Self::Cached(_, cache) => Some(cache),
+            _ => None,
+        }
+    }
+
+    /// Forget the cached value of the object, if any
+    pub fn invalidate(&self, object: &PhlowObject) {
+        if let Some(cache) = self.get_cache() {
+            cache.invalidate(object);
+        }
+    }
+
     pub async fn value(&self, object: &PhlowObject) -> Option<Return> {
         match self {
             Self::Sync(computation) => ready((computation)(object)).await,
@@ -169,6 +310,18 @@ impl<Return: Send + Sync + 'static> Computation<Return> {
                     Some(future) => future.map(|value| Some(value)).await,
                 }
             }
+            Self::Cached(computation, cache) => {
+                if let Some(value) = cache.get(object) {
+                    return Some(value);
+                }
+                // the version is read before computing to not cache a value of a concurrent change
+                let version = object.version();
+                let value = Box::pin(computation.value(object)).await;
+                if let Some(value) = value.as_ref() {
+                    cache.insert(object, version, value);
+                }
+                value
+            }
         }
     }
 
@@ -182,6 +335,7 @@ impl<Return: Send + Sync + 'static> Computation<Return> {
                 let value = (computation)(object);
                 value.unwrap_or_else(|| Box::pin(ready(f()))).await
             }
+            Self::Cached(_, _) => self.value(object).await.unwrap_or_else(f),
         }
     }
 
@@ -191,21 +345,115 @@ impl<Return: Send + Sync + 'static> Computation<Return> {
             Self::Async(computation) => {
                 (computation)(object).map(|future| futures_executor::block_on(future))
             }
+            Self::Cached(computation, cache) => {
+                if let Some(value) = cache.get(object) {
+                    return Some(value);
+                }
+                let version = object.version();
+                let value = computation.value_block_on(object);
+                if let Some(value) = value.as_ref() {
+                    cache.insert(object, version, value);
+                }
+                value
+            }
         }
     }
 
+    /// Compute the value and give up once the time budget is exceeded. The value is computed
+    /// on a worker thread if the object is thread safe, and in place otherwise
+    pub fn value_within_block_on(
+        &self,
+        object: &PhlowObject,
+        budget: Option<Duration>,
+    ) -> Result<Option<Return>, PhlowComputationError> {
+        let computation = self.clone();
+        let computed_object = object.clone();
+        block_on_within_time_budget_of(&[object], budget, move || {
+            computation.value_block_on(&computed_object)
+        })
+    }
+
     pub fn is_async(&self) -> bool {
         match self {
             Self::Sync(_) => false,
             Self::Async(_) => true,
+            Self::Cached(computation, _) => computation.is_async(),
         }
     }
 }
 
+/// Caching hands out copies of the remembered values, so only computations of `Clone`
+/// values can be cached
+impl<Return: Clone + Send + Sync + 'static> Computation<Return> {
+    /// Remember the values computed for up to `capacity` objects. A value is computed again
+    /// once the value of its object is replaced or mutated. A capacity of zero disables caching
+    pub fn cached(self, capacity: usize) -> Self {
+        self.cached_in(capacity, || Arc::new(ComputationCache::new(capacity)))
+    }
+
+    /// Like [`Computation::cached`], but remember the values in the cache shared by all
+    /// computations with the same key, see [`ComputationCache::shared`]
+    pub fn shared_cached(self, key: &str, capacity: usize) -> Self {
+        self.cached_in(capacity, || ComputationCache::shared(key, capacity))
+    }
+
+    fn cached_in(
+        self,
+        capacity: usize,
+        cache: impl FnOnce() -> Arc<ComputationCache<Return>>,
+    ) -> Self {
+        let computation = match self {
+            Self::Cached(computation, _) => *computation,
+            computation => computation,
+        };
+        if capacity == 0 {
+            computation
+        } else {
+            Self::Cached(Box::new(computation), cache())
+        }
+    }
+}
+
+/// Values that stand in for the result of a computation that failed,
+/// such as an error text or an error item
+pub trait FromComputationError: Sized {
+    fn from_computation_error(error: PhlowComputationError) -> Self;
+
+    fn from_error(error: impl Display) -> Self {
+        Self::from_computation_error(PhlowComputationError::Failed(error.to_string()))
+    }
+}
+
+impl FromComputationError for String {
+    fn from_computation_error(error: PhlowComputationError) -> Self {
+        error.to_string()
+    }
+}
+
+/// The error becomes an object of its own that can be inspected
+impl FromComputationError for PhlowObject {
+    fn from_computation_error(error: PhlowComputationError) -> Self {
+        PhlowObject::object(error, |_| vec![])
+    }
+}
+
+/// A single item describing the error
+impl FromComputationError for Vec<PhlowObject> {
+    fn from_computation_error(error: PhlowComputationError) -> Self {
+        vec![PhlowObject::from_computation_error(error)]
+    }
+}
+
 pub type ItemsComputation = Computation<Vec<PhlowObject>>;
+pub type ItemsCountComputation = Computation<usize>;
 pub type TextComputation = Computation<String>;
 pub type SendComputation = Computation<PhlowObject>;
 pub type ItemComputation = Computation<PhlowObject>;
+pub type BitmapComputation = Computation<PhlowBitmap>;
+pub type CanvasComputation = Computation<PhlowCanvas>;
+pub type DiffComputation = Computation<PhlowDiff>;
+pub type EdgesComputation = Computation<Vec<PhlowGraphEdge>>;
+pub type StyleComputation = Computation<PhlowStyle>;
 
 impl Default for ItemsComputation {
     fn default() -> Self {
@@ -224,11 +472,190 @@ impl Default for Computation<PhlowObject> {
     }
 }
 
+/// The amount of items requested at once when streaming items from a range computation
+pub const ITEMS_PAGE_SIZE: usize = 256;
+
+type ItemsFuture = Pin<Box<dyn Future<Output = Vec<PhlowObject>> + Send>>;
+type RangeFuture<Item> = Pin<Box<dyn Future<Output = Vec<Item>> + Send>>;
+type RangeFn<Return> = dyn Fn(&PhlowObject, usize, usize) -> Option<Return> + Send + Sync;
+
+/// Represents a computation of a range of items that can be either sync or async.
+/// It receives the index of the first item and the maximum amount of items to return.
+pub enum RangeComputation<Item> {
+    Sync(Arc<RangeFn<Vec<Item>>>),
+    Async(Arc<RangeFn<RangeFuture<Item>>>),
+}
+
+// cloning shares the closures, the items do not need to be `Clone`
+impl<Item> Clone for RangeComputation<Item> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Sync(computation) => Self::Sync(computation.clone()),
+            Self::Async(computation) => Self::Async(computation.clone()),
+        }
+    }
+}
+
+impl<Item: Send + 'static> RangeComputation<Item> {
+    pub fn new_sync<T: 'static>(items_block: impl SyncRangeComputation<T, Vec<Item>>) -> Self {
+        Self::Sync(Arc::new(
+            move |object: &PhlowObject, start: usize, count: usize| {
+                object.value_ref::<T>().map(|reference| {
+                    items_block(TypedPhlowObject::new(object, &reference), start, count)
+                })
+            },
+        ))
+    }
+
+    pub fn new_async<T: 'static>(items_block: impl AsyncRangeComputation<T, Vec<Item>>) -> Self {
+        Self::Async(Arc::new(
+            move |object: &PhlowObject, start: usize, count: usize| {
+                object.value_ref::<T>().map(|reference| {
+                    items_block(TypedPhlowObject::new(object, &reference), start, count)
+                })
+            },
+        ))
+    }
+
+    /// Compute at most `count` items starting from the `start` index. Large ranges are
+    /// requested page by page, so that the computation never receives more than
+    /// [`ITEMS_PAGE_SIZE`] as the amount of items
+    pub async fn value(&self, object: &PhlowObject, start: usize, count: usize) -> Vec<Item> {
+        let mut items = vec![];
+        while items.len() < count {
+            let page_size = (count - items.len()).min(ITEMS_PAGE_SIZE);
+            let page = self
+                .page(object, start.saturating_add(items.len()), page_size)
+                .await;
+            let is_last_page = page.len() < page_size;
+            items.extend(page);
+            if is_last_page {
+                break;
+            }
+        }
+        items.truncate(count);
+        items
+    }
+
+    pub fn value_block_on(&self, object: &PhlowObject, start: usize, count: usize) -> Vec<Item> {
+        let mut items = vec![];
+        while items.len() < count {
+            let page_size = (count - items.len()).min(ITEMS_PAGE_SIZE);
+            let page = self.page_block_on(object, start.saturating_add(items.len()), page_size);
+            let is_last_page = page.len() < page_size;
+            items.extend(page);
+            if is_last_page {
+                break;
+            }
+        }
+        items.truncate(count);
+        items
+    }
+
+    async fn page(&self, object: &PhlowObject, start: usize, count: usize) -> Vec<Item> {
+        match self {
+            Self::Sync(computation) => ready((computation)(object, start, count)).await,
+            Self::Async(computation) => match (computation)(object, start, count) {
+                None => None,
+                Some(future) => Some(future.await),
+            },
+        }
+        .unwrap_or_default()
+    }
+
+    fn page_block_on(&self, object: &PhlowObject, start: usize, count: usize) -> Vec<Item> {
+        match self {
+            Self::Sync(computation) => (computation)(object, start, count),
+            Self::Async(computation) => {
+                (computation)(object, start, count).map(futures_executor::block_on)
+            }
+        }
+        .unwrap_or_default()
+    }
+
+    /// Lazily request pages of items until the computation returns an incomplete page
+    pub fn stream<'a>(&'a self, object: &'a PhlowObject) -> impl Stream<Item = Item> + 'a {
+        stream::unfold(Some(0usize), move |start| async move {
+            let start = start?;
+            let page = self.page(object, start, ITEMS_PAGE_SIZE).await;
+            if page.is_empty() {
+                return None;
+            }
+            let next_start = if page.len() < ITEMS_PAGE_SIZE {
+                None
+            } else {
+                Some(start + page.len())
+            };
+            Some((stream::iter(page), next_start))
+        })
+        .flatten()
+    }
+
+    pub fn values_block_on(&self, object: &PhlowObject) -> Vec<Item> {
+        self.value_block_on(object, 0, usize::MAX)
+    }
+
+    pub fn is_async(&self) -> bool {
+        match self {
+            Self::Sync(_) => false,
+            Self::Async(_) => true,
+        }
+    }
+}
+
+pub type ItemsRangeComputation = RangeComputation<PhlowObject>;
+pub type BytesRangeComputation = RangeComputation<u8>;
+
+type ItemsProgressFn = dyn Fn(&PhlowObject, PhlowProgress) -> Option<ItemsFuture> + Send + Sync;
+
+/// Represents an async computation of items that reports its progress
+/// and may emit items before it is finished
+#[derive(Clone)]
+pub struct ItemsProgressComputation(Arc<ItemsProgressFn>);
+
+impl ItemsProgressComputation {
+    pub fn new_async<T: 'static>(
+        items_block: impl AsyncProgressComputation<T, Vec<PhlowObject>>,
+    ) -> Self {
+        Self(Arc::new(move |object: &PhlowObject, progress| {
+            object
+                .value_ref::<T>()
+                .map(|reference| items_block(TypedPhlowObject::new(object, &reference), progress))
+        }))
+    }
+
+    /// Stream emitted items as soon as they are available followed by the returned items
+    pub fn stream<'a>(
+        &'a self,
+        object: &'a PhlowObject,
+        progress: PhlowProgress,
+    ) -> impl Stream<Item = PhlowObject> + 'a {
+        let (sender, receiver) = futures_channel::mpsc::unbounded();
+        progress.start(sender);
+        let computation = (self.0)(object, progress.clone());
+        let driver = async move {
+            if let Some(computation) = computation {
+                progress.emit(computation.await);
+            }
+            progress.finish();
+        };
+
+        // all items go through the channel to keep them in order,
+        // the driver does not produce items, it only runs the computation
+        stream::select(
+            receiver.map(stream::iter).flatten(),
+            driver
+                .into_stream()
+                .filter_map(|_| ready(None::<PhlowObject>)),
+        )
+    }
+}
+
 pub mod types {
     use std::future::Future;
     use std::pin::Pin;
 
-    use crate::{TypedPhlowObject, TypedPhlowObjectMut};
+    use crate::{PhlowCancellationToken, PhlowProgress, TypedPhlowObject, TypedPhlowObjectMut};
 
     pub trait SyncComputation<T, R>: Fn(TypedPhlowObject<T>) -> R + Send + Sync + 'static {}
     impl<T, R, O: Fn(TypedPhlowObject<T>) -> R + Send + Sync + 'static> SyncComputation<T, R> for O {}
@@ -243,6 +670,16 @@ pub mod types {
     {
     }
 
+    pub trait SyncRangeComputation<T, R>:
+        Fn(TypedPhlowObject<T>, usize, usize) -> R + Send + Sync + 'static
+    {
+    }
+
+    impl<T, R, O: Fn(TypedPhlowObject<T>, usize, usize) -> R + Send + Sync + 'static>
+        SyncRangeComputation<T, R> for O
+    {
+    }
+
     pub trait AsyncComputationFuture<T>: Future<Output = T> + Send {}
 
     impl<T, O: Future<Output = T> + Send + Sync + 'static> AsyncComputationFuture<T> for O {}
@@ -262,4 +699,64 @@ pub mod types {
         > AsyncComputation<T, R> for O
     {
     }
+
+    pub trait AsyncCancellableComputation<T, R>:
+        Fn(TypedPhlowObject<T>, PhlowCancellationToken) -> Pin<Box<dyn Future<Output = R> + Send>>
+        + Send
+        + Sync
+        + 'static
+    {
+    }
+
+    impl<
+            T,
+            R,
+            O: Fn(
+                    TypedPhlowObject<T>,
+                    PhlowCancellationToken,
+                ) -> Pin<Box<dyn Future<Output = R> + Send>>
+                + Send
+                + Sync
+                + 'static,
+        > AsyncCancellableComputation<T, R> for O
+    {
+    }
+
+    pub trait AsyncProgressComputation<T, R>:
+        Fn(TypedPhlowObject<T>, PhlowProgress) -> Pin<Box<dyn Future<Output = R> + Send>>
+        + Send
+        + Sync
+        + 'static
+    {
+    }
+
+    impl<
+            T,
+            R,
+            O: Fn(TypedPhlowObject<T>, PhlowProgress) -> Pin<Box<dyn Future<Output = R> + Send>>
+                + Send
+                + Sync
+                + 'static,
+        > AsyncProgressComputation<T, R> for O
+    {
+    }
+
+    pub trait AsyncRangeComputation<T, R>:
+        Fn(TypedPhlowObject<T>, usize, usize) -> Pin<Box<dyn Future<Output = R> + Send>>
+        + Send
+        + Sync
+        + 'static
+    {
+    }
+
+    impl<
+            T,
+            R,
+            O: Fn(TypedPhlowObject<T>, usize, usize) -> Pin<Box<dyn Future<Output = R> + Send>>
+                + Send
+                + Sync
+                + 'static,
+        > AsyncRangeComputation<T, R> for O
+    {
+    }
 }
@@ -4,13 +4,62 @@ use erased_serde::serialize_trait_object;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
-use crate::{PhlowObject, PhlowView};
+use crate::views::bitmap_view::PhlowBitmapSpecification;
+use crate::{PhlowBitmap, PhlowColumnCellType, PhlowObject, PhlowProgress, PhlowStyle, PhlowView};
 
 #[typetag::serialize(tag = "viewName")]
 #[async_trait::async_trait]
-pub trait PhlowViewSpecification: Send {
+pub trait PhlowViewSpecification: Send + Sync {
     async fn retrieve_items(&self) -> Vec<Box<dyn PhlowViewSpecificationListingItem>>;
+    /// Retrieve at most `limit` items starting from the `offset`.
+    /// Views with lazy data transport should override it to avoid computing all items
+    async fn retrieve_items_range(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<Box<dyn PhlowViewSpecificationListingItem>> {
+        self.retrieve_items()
+            .await
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect()
+    }
+    /// Return the total amount of items if it can be determined without computing them
+    async fn retrieve_items_count(&self) -> Option<usize> {
+        None
+    }
     async fn retrieve_sent_item(&self, item: &PhlowObject) -> Option<PhlowObject>;
+    /// Progress of the items computed by this specification, if their computation reports one
+    fn get_items_progress(&self) -> Option<PhlowProgress> {
+        None
+    }
+    /// Compute the data of views with included data transport, such as text.
+    /// Must be awaited before the specification is serialized
+    async fn retrieve_included_data(&mut self) {}
+
+    /// Bitmap views are too large to be included in the specification,
+    /// instead clients fetch the bitmap separately
+    async fn retrieve_bitmap(&self) -> Option<PhlowBitmap> {
+        None
+    }
+
+    /// Views drawn with vector graphics can be exported as an SVG document
+    async fn retrieve_svg(&self) -> Option<String> {
+        None
+    }
+
+    /// Views of graphs can be exported in the Graphviz DOT language
+    async fn retrieve_dot(&self) -> Option<String> {
+        None
+    }
+
+    /// The bitmap encoded the way the view prefers to transport it
+    async fn retrieve_bitmap_specification(&self) -> Option<PhlowBitmapSpecification> {
+        self.retrieve_bitmap()
+            .await
+            .map(PhlowBitmapSpecification::new)
+    }
 }
 
 pub trait AsPhlowViewSpecification: PhlowView {
@@ -34,11 +83,32 @@ pub enum PhlowViewSpecificationDataTransport {
 #[serde(rename_all = "lowercase")]
 pub enum PhlowViewSpecificationListingType {
     Text,
+    Number,
+    Bitmap,
+    Boolean,
+    Progress,
+}
+
+impl From<PhlowColumnCellType> for PhlowViewSpecificationListingType {
+    fn from(cell_type: PhlowColumnCellType) -> Self {
+        match cell_type {
+            PhlowColumnCellType::Text => Self::Text,
+            PhlowColumnCellType::Number => Self::Number,
+            PhlowColumnCellType::Bitmap => Self::Bitmap,
+            PhlowColumnCellType::Boolean => Self::Boolean,
+            PhlowColumnCellType::Progress => Self::Progress,
+        }
+    }
 }
 
 #[typetag::serialize(tag = "valueTypeName")]
 pub trait PhlowViewSpecificationListingItem: Send + Debug {
     fn phlow_object(&self) -> &PhlowObject;
+    /// Objects that should be spawned when clicking on the corresponding cells of the item,
+    /// `None` if a cell does not spawn objects
+    fn spawned_objects(&self) -> Vec<Option<PhlowObject>> {
+        vec![]
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -47,6 +117,8 @@ pub struct PhlowViewSpecificationTextualItemValue {
     #[serde(skip)]
     pub phlow_object: PhlowObject,
     pub item_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_style: Option<PhlowStyle>,
 }
 
 #[typetag::serialize(name = "textualValue")]
@@ -56,12 +128,29 @@ impl PhlowViewSpecificationListingItem for PhlowViewSpecificationTextualItemValu
     }
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhlowViewSpecificationBitmapItemValue {
+    #[serde(skip)]
+    pub phlow_object: PhlowObject,
+    pub bitmap: PhlowBitmapSpecification,
+}
+
+#[typetag::serialize(name = "bitmapValue")]
+impl PhlowViewSpecificationListingItem for PhlowViewSpecificationBitmapItemValue {
+    fn phlow_object(&self) -> &PhlowObject {
+        &self.phlow_object
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PhlowViewSpecificationRowValue {
     #[serde(skip)]
     pub phlow_object: PhlowObject,
     pub column_values: Vec<Box<dyn PhlowViewSpecificationListingItem>>,
+    #[serde(skip)]
+    pub spawned_objects: Vec<Option<PhlowObject>>,
 }
 
 impl PhlowViewSpecificationRowValue {
@@ -75,4 +164,8 @@ impl PhlowViewSpecificationListingItem for PhlowViewSpecificationRowValue {
     fn phlow_object(&self) -> &PhlowObject {
         &self.phlow_object
     }
+
+    fn spawned_objects(&self) -> Vec<Option<PhlowObject>> {
+        self.spawned_objects.clone()
+    }
 }
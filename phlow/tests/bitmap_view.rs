@@ -0,0 +1,148 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use phlow::{PhlowBitmap, PhlowBitmapError, PhlowBitmapView, PhlowView, PixelFormat};
+
+use common::*;
+
+pub struct Pixel(u8);
+
+#[phlow::extensions(TestExtensions, Pixel)]
+impl PixelExtensions {
+    #[phlow::view]
+    fn preview_for(_this: &Pixel, view: impl PhlowView) -> impl PhlowView {
+        view.bitmap()
+            .title("Preview")
+            .bitmap::<Pixel>(|pixel| PhlowBitmap::rgba8(vec![pixel.0; 4], 1, 1).unwrap())
+    }
+
+    #[phlow::view]
+    fn async_preview_for(_this: &Pixel, view: impl PhlowView) -> impl PhlowView {
+        view.bitmap()
+            .title("Async preview")
+            .async_bitmap::<Pixel>(|pixel| {
+                let value = pixel.0;
+                Box::pin(async move { PhlowBitmap::rgba8(vec![value; 8], 2, 1).unwrap() })
+            })
+    }
+}
+
+#[test]
+pub fn sync_bitmap() {
+    let pixel = phlow!(Pixel(42));
+    let bitmap = view_named::<PhlowBitmapView>(&pixel, "preview_for").compute_bitmap();
+
+    assert_eq!(bitmap.width(), 1);
+    assert_eq!(bitmap.height(), 1);
+    assert_eq!(bitmap.pixels(), &[42, 42, 42, 42]);
+}
+
+#[test]
+pub fn async_bitmap() {
+    let pixel = phlow!(Pixel(7));
+    let view = view_named::<PhlowBitmapView>(&pixel, "async_preview_for");
+
+    let bitmap = futures_executor::block_on(view.async_compute_bitmap());
+    assert_eq!(bitmap.width(), 2);
+    assert_eq!(bitmap.pixels(), &[7; 8]);
+
+    let cloned_view = view.clone();
+    let bitmap = std::thread::spawn(move || cloned_view.compute_bitmap())
+        .join()
+        .unwrap();
+    assert_eq!(bitmap.pixels().len(), 8);
+}
+
+#[test]
+pub fn validated_constructors() {
+    let bitmap = PhlowBitmap::rgb8(vec![0; 12], 2, 2).unwrap();
+    assert_eq!(bitmap.stride(), 6);
+    assert_eq!(bitmap.format(), PixelFormat::RGB888);
+
+    assert_eq!(
+        PhlowBitmap::rgba8(vec![0; 15], 2, 2),
+        Err(PhlowBitmapError::WrongPixelsLength {
+            expected: 16,
+            actual: 15
+        })
+    );
+    assert_eq!(
+        PhlowBitmap::new(vec![0; 6], 2, 2, 3, PixelFormat::GrayAlpha88),
+        Err(PhlowBitmapError::StrideTooSmall {
+            stride: 3,
+            row_length: 4
+        })
+    );
+    assert_eq!(
+        PhlowBitmap::gray8(vec![], -1, 0),
+        Err(PhlowBitmapError::NegativeExtent {
+            width: -1,
+            height: 0
+        })
+    );
+}
+
+#[test]
+pub fn convert_formats() {
+    // a single red pixel followed by a padding byte per row
+    let bitmap = PhlowBitmap::new(vec![255, 0, 0, 0], 1, 1, 4, PixelFormat::RGB888).unwrap();
+
+    let bgra = bitmap.convert(PixelFormat::BGRA8888);
+    assert_eq!(bgra.pixels(), &[0, 0, 255, 255]);
+    assert_eq!(bgra.stride(), 4);
+
+    let rgba16 = bitmap.convert(PixelFormat::RGBA16);
+    assert_eq!(rgba16.pixels(), &[255, 255, 0, 0, 0, 0, 255, 255]);
+    assert_eq!(rgba16.to_rgba8().pixels(), &[255, 0, 0, 255]);
+
+    let float = PhlowBitmap::rgba_f32(&[0.0, 0.0, 1.0, 0.5], 1, 1).unwrap();
+    assert_eq!(float.to_rgba8().pixels(), &[0, 0, 255, 128]);
+
+    let gray = PhlowBitmap::gray_alpha8(vec![100, 200], 1, 1).unwrap();
+    assert_eq!(gray.convert(PixelFormat::Gray8).pixels(), &[100]);
+    assert_eq!(PixelFormat::try_from(6), Ok(PixelFormat::RGBAF32));
+}
+
+#[cfg(feature = "png")]
+#[test]
+pub fn png_round_trip() {
+    let bitmap =
+        PhlowBitmap::new(vec![1, 2, 3, 0, 4, 5, 6, 0], 1, 2, 4, PixelFormat::RGB888).unwrap();
+    let png = bitmap.to_png().unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+
+    let decoded = PhlowBitmap::from_png(&png).unwrap();
+    assert_eq!(decoded.format(), PixelFormat::RGB888);
+    assert_eq!(decoded.pixels(), &[1, 2, 3, 4, 5, 6]);
+
+    let wide = PhlowBitmap::rgba16(&[1, 2, 3, 65535], 1, 1).unwrap();
+    assert_eq!(PhlowBitmap::from_png(&wide.to_png().unwrap()), Ok(wide));
+}
+
+#[cfg(feature = "qoi")]
+#[test]
+pub fn qoi_round_trip() {
+    let bitmap = PhlowBitmap::bgra8(vec![1, 2, 3, 4], 1, 1).unwrap();
+    let decoded = PhlowBitmap::from_qoi(&bitmap.to_qoi().unwrap()).unwrap();
+    assert_eq!(decoded, bitmap.to_rgba8());
+}
+
+#[cfg(feature = "view-specification")]
+#[test]
+pub fn lazy_bitmap_specification() {
+    let pixel = phlow!(Pixel(3));
+    let view = view_named::<PhlowBitmapView>(&pixel, "async_preview_for");
+    let specification = view
+        .as_view_specification_builder()
+        .create_view_specification()
+        .unwrap();
+
+    let bitmap = futures_executor::block_on(specification.retrieve_bitmap()).unwrap();
+    assert_eq!(bitmap.width(), 2);
+    assert_eq!(bitmap.pixels(), &[3; 8]);
+}
@@ -0,0 +1,119 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use phlow::{
+    PhlowBitmapError, PhlowCanvas, PhlowCanvasView, PhlowColor, PhlowPath, PhlowView,
+    MAX_RASTER_EXTENT,
+};
+
+use common::*;
+
+pub struct Square(f32);
+
+#[phlow::extensions(TestExtensions, Square)]
+impl SquareExtensions {
+    #[phlow::view]
+    fn shape_for(_this: &Square, view: impl PhlowView) -> impl PhlowView {
+        view.canvas().title("Shape").canvas::<Square>(|square| {
+            PhlowCanvas::new(square.0, square.0)
+                .background(PhlowColor::WHITE)
+                .no_stroke()
+                .fill(PhlowColor::RED)
+                .rectangle(0.0, 0.0, square.0 / 2.0, square.0)
+        })
+    }
+}
+
+fn canvas_view(square: f32) -> PhlowCanvasView {
+    view_named::<PhlowCanvasView>(&phlow!(Square(square)), "shape_for")
+}
+
+#[test]
+pub fn svg() {
+    let svg = PhlowCanvas::new(20.0, 10.0)
+        .save()
+        .translate(5.0, 0.0)
+        .stroke(PhlowColor::rgba(0, 0, 255, 51), 2.0)
+        .line(0.0, 0.0, 10.0, 10.0)
+        .restore()
+        .fill(PhlowColor::BLACK)
+        .circle(5.0, 5.0, 2.5)
+        .path(
+            PhlowPath::new()
+                .move_to(0.0, 0.0)
+                .quad_to(1.0, 2.0, 3.0, 4.0)
+                .close(),
+        )
+        .text(1.0, 9.0, "a < b", 8.0)
+        .to_svg();
+
+    assert_eq!(
+        svg,
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10" viewBox="0 0 20 10">"#,
+            r##"<line x1="0" y1="0" x2="10" y2="10" fill="none" stroke="#0000FF" stroke-opacity="0.2" stroke-width="2" transform="matrix(1 0 0 1 5 0)"/>"##,
+            r##"<circle cx="5" cy="5" r="2.5" fill="#000000" stroke="#000000" stroke-width="1"/>"##,
+            r##"<path d="M0 0 Q1 2 3 4 Z" fill="#000000" stroke="#000000" stroke-width="1"/>"##,
+            r##"<text x="1" y="9" font-size="8" fill="#000000">a &lt; b</text>"##,
+            "</svg>"
+        )
+    );
+}
+
+#[test]
+pub fn rasterize() {
+    let bitmap = canvas_view(4.0).compute_bitmap(2.0).unwrap();
+    assert_eq!((bitmap.width(), bitmap.height()), (8, 8));
+
+    let pixel = |x: usize, y: usize| {
+        let offset = y * bitmap.stride() as usize + x * 4;
+        bitmap.pixels()[offset..offset + 4].to_vec()
+    };
+    assert_eq!(pixel(0, 0), vec![204, 0, 0, 255]);
+    assert_eq!(pixel(3, 7), vec![204, 0, 0, 255]);
+    assert_eq!(pixel(4, 0), vec![255, 255, 255, 255]);
+}
+
+#[test]
+pub fn rasterize_stroke() {
+    let bitmap = PhlowCanvas::new(10.0, 10.0)
+        .stroke(PhlowColor::BLACK, 2.0)
+        .line(0.0, 5.0, 10.0, 5.0)
+        .rasterize(1.0)
+        .unwrap();
+
+    let alpha = |x: usize, y: usize| bitmap.pixels()[y * bitmap.stride() as usize + x * 4 + 3];
+    assert_eq!(alpha(5, 4), 255);
+    assert_eq!(alpha(5, 5), 255);
+    assert_eq!(alpha(5, 2), 0);
+    assert_eq!(alpha(5, 7), 0);
+}
+
+#[test]
+pub fn rasterize_rejects_unbounded_scales() {
+    let canvas = PhlowCanvas::new(10.0, 10.0);
+
+    assert_eq!(
+        canvas.rasterize(f32::INFINITY).unwrap_err(),
+        PhlowBitmapError::NonFiniteScale
+    );
+    assert_eq!(
+        canvas.rasterize(f32::NAN).unwrap_err(),
+        PhlowBitmapError::NonFiniteScale
+    );
+    assert_eq!(
+        canvas.rasterize(2000.0).unwrap_err(),
+        PhlowBitmapError::ExtentTooLarge {
+            width: 20_000,
+            height: 20_000
+        }
+    );
+    assert!(PhlowCanvas::new(MAX_RASTER_EXTENT as f32, 1.0)
+        .rasterize(1.0)
+        .is_ok());
+}
@@ -0,0 +1,158 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use phlow::{
+    PhlowColumnAlignment, PhlowColumnCellType, PhlowColumnWidth, PhlowColumnedListView, PhlowView,
+};
+
+use common::*;
+
+pub struct Task {
+    name: String,
+    done: bool,
+    progress: f32,
+}
+
+#[phlow::extensions(TestExtensions, Vec<Task>)]
+impl TasksExtensions {
+    #[phlow::view]
+    fn tasks_for(_this: &Vec<Task>, view: impl PhlowView) -> impl PhlowView {
+        view.columned_list()
+            .title("Tasks")
+            .items::<Vec<Task>>(|tasks| {
+                tasks
+                    .iter()
+                    .map(|task| phlow!(task, tasks.phlow_object()))
+                    .collect()
+            })
+            .column(|column| {
+                column
+                    .title("Name")
+                    .relative_width(2.0)
+                    .item::<Task>(|task| phlow!(task.name.clone()))
+            })
+            .column(|column| {
+                column
+                    .title("Done")
+                    .width(40.0)
+                    .align(PhlowColumnAlignment::Center)
+                    .boolean::<Task>(|task| task.done)
+            })
+            .column(|column| {
+                column
+                    .title("Progress")
+                    .spawns_objects(true)
+                    .item::<Task>(|task| phlow!(task.progress))
+                    .number::<f32>(|progress| (*progress * 100.0) as f64)
+            })
+    }
+
+    #[phlow::view]
+    fn async_tasks_for(_this: &Vec<Task>, view: impl PhlowView) -> impl PhlowView {
+        view.columned_list()
+            .title("Async tasks")
+            .items::<Vec<Task>>(|tasks| {
+                tasks
+                    .iter()
+                    .map(|task| phlow!(task, tasks.phlow_object()))
+                    .collect()
+            })
+            .column(|column| {
+                column
+                    .title("Name")
+                    .async_item::<Task>(|task| {
+                        let name = task.name.clone();
+                        Box::pin(async move { phlow!(name) })
+                    })
+                    .async_text::<String>(|name| {
+                        let text = name.to_uppercase();
+                        Box::pin(async move { text })
+                    })
+            })
+            .async_send::<Task>(|task| {
+                let progress = task.progress;
+                Box::pin(async move { phlow!(progress) })
+            })
+    }
+}
+
+fn tasks_view(tasks: &phlow::PhlowObject) -> PhlowColumnedListView {
+    view_named::<PhlowColumnedListView>(tasks, "tasks_for")
+}
+
+#[test]
+pub fn column_configuration() {
+    let tasks = phlow!(Vec::<Task>::new());
+    let view = tasks_view(&tasks);
+    let columns = view.get_columns();
+
+    assert_eq!(
+        columns[0].get_width(),
+        Some(PhlowColumnWidth::Relative(2.0))
+    );
+    assert_eq!(columns[0].get_cell_type(), PhlowColumnCellType::Text);
+    assert_eq!(columns[0].get_alignment(), PhlowColumnAlignment::Left);
+    assert!(!columns[0].get_spawns_objects());
+
+    assert_eq!(columns[1].get_width(), Some(PhlowColumnWidth::Fixed(40.0)));
+    assert_eq!(columns[1].get_cell_type(), PhlowColumnCellType::Boolean);
+    assert_eq!(columns[1].get_alignment(), PhlowColumnAlignment::Center);
+
+    assert_eq!(columns[2].get_width(), None);
+    assert_eq!(columns[2].get_cell_type(), PhlowColumnCellType::Number);
+    assert_eq!(columns[2].get_alignment(), PhlowColumnAlignment::Right);
+    assert!(columns[2].get_spawns_objects());
+}
+
+#[test]
+pub fn typed_cell_text() {
+    let tasks = phlow!(vec![Task {
+        name: "Write tests".to_string(),
+        done: true,
+        progress: 0.5,
+    }]);
+    let view = tasks_view(&tasks);
+    let columns = view.get_columns();
+    let row = view.compute_items().remove(0);
+
+    let cells = columns
+        .iter()
+        .map(|column| {
+            let cell = column.compute_cell_item(&row).unwrap();
+            column.compute_cell_text(&cell)
+        })
+        .collect::<Vec<String>>();
+
+    assert_eq!(cells, vec!["Write tests", "true", "50"]);
+}
+
+#[test]
+pub fn async_cells_and_send() {
+    let tasks = phlow!(vec![Task {
+        name: "Write tests".to_string(),
+        done: false,
+        progress: 0.25,
+    }]);
+    let view = view_named::<PhlowColumnedListView>(&tasks, "async_tasks_for");
+    let column = &view.get_columns()[0];
+    let row = view.compute_items().remove(0);
+
+    let (text, sent) = futures_executor::block_on(async {
+        let cell = column.async_compute_cell_item(&row).await.unwrap();
+        (
+            column.async_compute_cell_text(&cell).await,
+            view.async_compute_item_send(&row).await.unwrap(),
+        )
+    });
+
+    assert_eq!(text, "WRITE TESTS");
+    assert_eq!(sent.to_string(), "0.25");
+
+    let cell = column.compute_cell_item(&row).unwrap();
+    assert_eq!(column.compute_cell_text(&cell), "WRITE TESTS");
+}
@@ -0,0 +1,19 @@
+//! Setup shared by the integration tests, each test includes it with `mod common;` and
+//! imports it at the crate root with `use common::*;` so that `phlow!` finds the extensions
+#![allow(dead_code)]
+
+use phlow::{PhlowObject, PhlowView};
+
+define_extensions!(TestExtensions);
+import_extensions!(TestExtensions);
+
+/// Return the view of the object built by the view method named `view_name`
+pub fn view_named<T: PhlowView>(object: &PhlowObject, view_name: &str) -> T {
+    object
+        .phlow_view_named(view_name)
+        .unwrap()
+        .to_any()
+        .downcast::<T>()
+        .map(|view| *view)
+        .unwrap()
+}
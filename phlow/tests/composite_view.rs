@@ -0,0 +1,159 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use phlow::{PhlowCompositeLayout, PhlowCompositeView, PhlowListView, PhlowTextView, PhlowView};
+
+use common::*;
+
+pub struct Owner(&'static str);
+
+pub struct Inventory {
+    owner: Owner,
+    items: Vec<&'static str>,
+}
+
+#[phlow::extensions(TestExtensions, Owner)]
+impl OwnerExtensions {
+    #[phlow::view]
+    fn name_for(_this: &Owner, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .title("Owner")
+            .text::<Owner>(|owner| owner.0.to_string())
+    }
+}
+
+#[phlow::extensions(TestExtensions, Inventory)]
+impl InventoryExtensions {
+    #[phlow::view]
+    fn overview_for(_this: &Inventory, view: impl PhlowView) -> impl PhlowView {
+        view.composite()
+            .title("Overview")
+            .view(|view| {
+                view.text()
+                    .title("Summary")
+                    .text::<Inventory>(|inventory| format!("{} items", inventory.items.len()))
+            })
+            .view(|view| {
+                view.list()
+                    .title("Items")
+                    .items::<Inventory>(|inventory| phlow_all!(inventory.items.clone()))
+            })
+            .view(|view| {
+                view.composite()
+                    .tabs()
+                    .title("Details")
+                    .object_view_named::<Inventory>(
+                        |inventory| phlow!(Owner(inventory.owner.0)),
+                        "name_for",
+                    )
+                    .object_view::<Inventory, _>(
+                        |inventory| phlow!(inventory.items.len()),
+                        |view| view.text().title("Count"),
+                    )
+            })
+    }
+}
+
+fn inventory() -> Inventory {
+    Inventory {
+        owner: Owner("Ada"),
+        items: vec!["apple", "pear"],
+    }
+}
+
+fn composite_view() -> PhlowCompositeView {
+    let inventory = inventory();
+    view_named::<PhlowCompositeView>(&phlow!(inventory), "overview_for")
+}
+
+#[test]
+pub fn sub_views() {
+    let view = composite_view();
+    assert_eq!(view.get_layout(), PhlowCompositeLayout::Stacked);
+    assert_eq!(view.sub_views_count(), 3);
+
+    let views = view.compute_views();
+    let titles = views
+        .iter()
+        .map(|each| each.get_title())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Summary", "Items", "Details"]);
+
+    let selectors = views
+        .iter()
+        .map(|each| each.get_defining_method().full_method_name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        selectors,
+        vec![
+            "InventoryExtensions::overview_for#0",
+            "InventoryExtensions::overview_for#1",
+            "InventoryExtensions::overview_for#2",
+        ]
+    );
+    assert!(view.compute_view_at(3).is_none());
+}
+
+#[test]
+pub fn computed_objects() {
+    let details = composite_view().compute_view_at(2).unwrap();
+    let details = details
+        .to_any()
+        .downcast::<PhlowCompositeView>()
+        .map(|view| *view)
+        .unwrap();
+    assert_eq!(details.get_layout(), PhlowCompositeLayout::Tabs);
+
+    let owner = details.compute_view_at(0).unwrap();
+    assert_eq!(owner.get_title(), "Owner");
+    assert_eq!(
+        owner.get_defining_method().full_method_name,
+        "InventoryExtensions::overview_for#2#0"
+    );
+    let owner = owner.to_any().downcast::<PhlowTextView>().unwrap();
+    assert_eq!(owner.compute_text(), "Ada");
+
+    let count = details.compute_view_at(1).unwrap();
+    assert_eq!(count.object().to_string(), "2");
+}
+
+#[test]
+pub fn find_by_selector() {
+    let inventory = inventory();
+    let object = phlow!(inventory);
+
+    let items = object
+        .phlow_view_by_selector("InventoryExtensions::overview_for#1")
+        .unwrap();
+    let items = items.to_any().downcast::<PhlowListView>().unwrap();
+    assert_eq!(items.compute_items_sync().len(), 2);
+
+    let owner = object
+        .phlow_view_by_selector("InventoryExtensions::overview_for#2#0")
+        .unwrap();
+    assert_eq!(owner.get_title(), "Owner");
+
+    assert!(object
+        .phlow_view_by_selector("InventoryExtensions::overview_for")
+        .is_some());
+    assert!(object
+        .phlow_view_by_selector("InventoryExtensions::overview_for#0#0")
+        .is_none());
+    assert!(object
+        .phlow_view_by_selector("InventoryExtensions::overview_for#x")
+        .is_none());
+}
+
+#[test]
+pub fn display() {
+    let text = composite_view().to_string();
+    assert!(text.starts_with("Overview\n=====================\nSummary\n"));
+    assert!(text.contains("2 items"));
+    assert!(text.contains("apple"));
+    assert!(text.contains("Ada"));
+}
@@ -0,0 +1,221 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use phlow::{PhlowListView, PhlowView, TextComputation};
+
+use common::*;
+
+pub struct Numbers(usize);
+pub struct Digits(Vec<usize>);
+
+static ITEMS_COMPUTED: AtomicUsize = AtomicUsize::new(0);
+static ITEM_TEXTS_COMPUTED: AtomicUsize = AtomicUsize::new(0);
+static SHARED_ITEMS_COMPUTED: AtomicUsize = AtomicUsize::new(0);
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl NumbersExtensions {
+    #[phlow::view]
+    fn cached_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Cached")
+            .cache_size(8)
+            .items::<Numbers>(|numbers| {
+                ITEMS_COMPUTED.fetch_add(1, Ordering::SeqCst);
+                phlow_all!(0..numbers.0)
+            })
+            .item_text::<usize>(|number| {
+                ITEM_TEXTS_COMPUTED.fetch_add(1, Ordering::SeqCst);
+                format!("#{}", *number)
+            })
+    }
+
+    #[phlow::view]
+    fn shared_cached_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Shared cache")
+            .cache_size(8)
+            .items::<Numbers>(|numbers| {
+                SHARED_ITEMS_COMPUTED.fetch_add(1, Ordering::SeqCst);
+                phlow_all!(0..numbers.0)
+            })
+    }
+}
+
+#[phlow::extensions(TestExtensions, Digits)]
+impl DigitsExtensions {
+    #[phlow::view]
+    fn digits_for(_this: &Digits, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Digits")
+            .cache_size(8)
+            .items::<Digits>(|digits| {
+                digits
+                    .0
+                    .iter()
+                    .map(|digit| phlow_ref!(digit, digits.phlow_object()))
+                    .collect()
+            })
+    }
+}
+
+fn counted_length(counter: &'static AtomicUsize) -> TextComputation {
+    TextComputation::new_sync::<String>(move |string| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        string.len().to_string()
+    })
+}
+
+#[test]
+pub fn cached_value_is_reused() {
+    static COMPUTED: AtomicUsize = AtomicUsize::new(0);
+    let computation = counted_length(&COMPUTED).cached(4);
+    let string = "hello".to_string();
+    let object = phlow!(string);
+
+    assert_eq!(computation.value_block_on(&object), Some("5".to_string()));
+    assert_eq!(computation.value_block_on(&object), Some("5".to_string()));
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+pub fn uncached_value_is_computed_every_time() {
+    static COMPUTED: AtomicUsize = AtomicUsize::new(0);
+    let computation = counted_length(&COMPUTED).cached(4).cached(0);
+    let string = "hello".to_string();
+    let object = phlow!(string);
+
+    computation.value_block_on(&object);
+    computation.value_block_on(&object);
+    assert!(computation.get_cache().is_none());
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+pub fn replaced_value_is_computed_again() {
+    static COMPUTED: AtomicUsize = AtomicUsize::new(0);
+    let computation = counted_length(&COMPUTED).cached(4);
+    let string = "hello".to_string();
+    let object = phlow!(string);
+
+    computation.value_block_on(&object);
+    object.replace_value("hello world".to_string());
+    assert_eq!(computation.value_block_on(&object), Some("11".to_string()));
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+pub fn mutated_value_is_computed_again() {
+    static COMPUTED: AtomicUsize = AtomicUsize::new(0);
+    let computation = counted_length(&COMPUTED).cached(4);
+    let string = "hello".to_string();
+    let object = phlow!(string);
+
+    computation.value_block_on(&object);
+    object.value_mut::<String>().unwrap().push('!');
+    assert_eq!(computation.value_block_on(&object), Some("6".to_string()));
+
+    object.mark_changed();
+    computation.value_block_on(&object);
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+pub fn least_recently_used_value_is_evicted() {
+    static COMPUTED: AtomicUsize = AtomicUsize::new(0);
+    let computation = counted_length(&COMPUTED).cached(2);
+    let objects = ["a", "bb", "ccc"]
+        .iter()
+        .map(|each| {
+            let string = each.to_string();
+            phlow!(string)
+        })
+        .collect::<Vec<_>>();
+
+    computation.value_block_on(&objects[0]);
+    computation.value_block_on(&objects[1]);
+    computation.value_block_on(&objects[0]);
+    computation.value_block_on(&objects[2]);
+    assert_eq!(computation.get_cache().unwrap().len(), 2);
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 3);
+
+    computation.value_block_on(&objects[0]);
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 3);
+    computation.value_block_on(&objects[1]);
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 4);
+
+    computation.invalidate(&objects[1]);
+    assert_eq!(computation.get_cache().unwrap().len(), 1);
+}
+
+#[test]
+pub fn cached_list_view() {
+    let numbers = Numbers(3);
+    let view = view_named::<PhlowListView>(&phlow!(numbers), "cached_for");
+
+    let items = view.compute_items_sync();
+    view.compute_items_sync();
+    assert_eq!(view.compute_items_count_sync(), Some(3));
+    assert_eq!(ITEMS_COMPUTED.load(Ordering::SeqCst), 1);
+
+    let texts = items
+        .iter()
+        .chain(items.iter())
+        .map(|item| view.compute_item_text_sync(item))
+        .collect::<Vec<_>>();
+    assert_eq!(texts, vec!["#0", "#1", "#2", "#0", "#1", "#2"]);
+    assert_eq!(ITEM_TEXTS_COMPUTED.load(Ordering::SeqCst), 3);
+
+    view.object().mark_changed();
+    view.compute_items_sync();
+    assert_eq!(ITEMS_COMPUTED.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+pub fn failed_mutable_borrow_keeps_cached_value() {
+    let string = "hello".to_string();
+    let object = phlow!(string);
+
+    assert!(object.value_mut::<usize>().is_none());
+    assert_eq!(object.version(), 0);
+    object.value_mut::<String>().unwrap().push('!');
+    assert_eq!(object.version(), 1);
+}
+
+#[test]
+pub fn rebuilt_list_view_reuses_cached_values() {
+    let numbers = phlow!(Numbers(2));
+    let view =
+        |numbers: &phlow::PhlowObject| view_named::<PhlowListView>(numbers, "shared_cached_for");
+
+    view(&numbers).compute_items_sync();
+    view(&numbers).compute_items_sync();
+    assert_eq!(SHARED_ITEMS_COMPUTED.load(Ordering::SeqCst), 1);
+
+    view(&phlow!(Numbers(2))).compute_items_sync();
+    assert_eq!(SHARED_ITEMS_COMPUTED.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+pub fn items_referring_to_a_dropped_parent_are_evicted() {
+    let digits = phlow!(Digits(vec![4, 2]));
+    let identity = digits.identity();
+    let items = view_named::<PhlowListView>(&digits, "digits_for").compute_items_sync();
+    assert!(identity.is_identity_of(items[0].parent().unwrap()));
+    drop(items);
+
+    // the cached items keep the parent alive, but they do not count as its use
+    drop(digits);
+    assert!(identity.is_alive());
+    assert!(!identity.is_used());
+
+    // caching the items of another object evicts the stale entry
+    view_named::<PhlowListView>(&phlow!(Digits(vec![1])), "digits_for").compute_items_sync();
+    assert!(!identity.is_alive());
+}
@@ -0,0 +1,200 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use std::fmt::{Display, Formatter};
+
+use phlow::{
+    diff_lines, unified_diff, PhlowDiff, PhlowDiffLineKind, PhlowDiffView, PhlowObject, PhlowView,
+};
+
+use common::*;
+
+#[derive(Debug)]
+pub struct Config {
+    name: String,
+    retries: u32,
+    tags: Vec<&'static str>,
+}
+
+impl Display for Config {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "name: {}", self.name)?;
+        writeln!(f, "retries: {}", self.retries)?;
+        write!(f, "tags: {}", self.tags.join(", "))
+    }
+}
+
+pub struct Comparison(PhlowObject, PhlowObject);
+
+#[phlow::extensions(TestExtensions, Config)]
+impl ConfigExtensions {
+    #[phlow::view]
+    fn tags_for(_this: &Config, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Tags")
+            .items::<Config>(|config| phlow_all!(config.tags.clone()))
+    }
+}
+
+#[phlow::extensions(TestExtensions, Comparison)]
+impl ComparisonExtensions {
+    #[phlow::view]
+    fn display_diff_for(_this: &Comparison, view: impl PhlowView) -> impl PhlowView {
+        view.diff()
+            .title("Display")
+            .context_lines(1)
+            .diff::<Comparison>(|comparison| {
+                PhlowDiff::new(comparison.0.clone(), comparison.1.clone())
+            })
+    }
+
+    #[phlow::view]
+    fn debug_diff_for(_this: &Comparison, view: impl PhlowView) -> impl PhlowView {
+        view.diff().title("Debug").diff::<Comparison>(|comparison| {
+            PhlowDiff::new(comparison.0.clone(), comparison.1.clone()).by_debug()
+        })
+    }
+
+    #[phlow::view]
+    fn items_diff_for(_this: &Comparison, view: impl PhlowView) -> impl PhlowView {
+        view.diff().title("Items").diff::<Comparison>(|comparison| {
+            PhlowDiff::new(comparison.0.clone(), comparison.1.clone()).by_items("tags_for")
+        })
+    }
+}
+
+fn lines(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|line| line.to_string())
+        .collect()
+}
+
+fn diff_view(name: &str) -> PhlowDiffView {
+    let expected = Config {
+        name: "phlow".to_string(),
+        retries: 3,
+        tags: vec!["a", "b", "c"],
+    };
+    let actual = Config {
+        name: "phlow".to_string(),
+        retries: 5,
+        tags: vec!["a", "c", "d"],
+    };
+    let comparison = Comparison(phlow!(expected), phlow!(actual));
+    view_named::<PhlowDiffView>(&phlow!(comparison), name)
+}
+
+#[test]
+pub fn shortest_edit() {
+    let diff = diff_lines(&lines("a b c a b b a"), &lines("c b a b a c"));
+    let kinds = diff.iter().map(|line| line.kind).collect::<Vec<_>>();
+
+    let unchanged = kinds
+        .iter()
+        .filter(|kind| **kind == PhlowDiffLineKind::Unchanged)
+        .count();
+    assert_eq!(unchanged, 4);
+    assert_eq!(diff.len(), 9);
+
+    let left = diff
+        .iter()
+        .filter(|line| line.kind != PhlowDiffLineKind::Inserted)
+        .map(|line| line.text.clone())
+        .collect::<Vec<_>>();
+    let right = diff
+        .iter()
+        .filter(|line| line.kind != PhlowDiffLineKind::Deleted)
+        .map(|line| line.text.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(left, lines("a b c a b b a"));
+    assert_eq!(right, lines("c b a b a c"));
+}
+
+#[test]
+pub fn empty_and_equal() {
+    assert!(diff_lines(&[], &[]).is_empty());
+    assert_eq!(
+        unified_diff(&diff_lines(&lines("a b"), &lines("a b")), 3),
+        ""
+    );
+
+    let inserted = diff_lines(&[], &lines("a"));
+    assert_eq!(inserted[0].kind, PhlowDiffLineKind::Inserted);
+    assert_eq!(inserted[0].right_index, Some(0));
+    assert_eq!(
+        unified_diff(&inserted, 3),
+        "--- left\n+++ right\n@@ -0,0 +1,1 @@\n+a\n"
+    );
+}
+
+#[test]
+pub fn unified_hunks() {
+    let diff = diff_lines(
+        &lines("1 2 3 4 5 6 7 8 9 10"),
+        &lines("1 two 3 4 5 6 7 8 9 10 11"),
+    );
+    assert_eq!(
+        unified_diff(&diff, 1),
+        concat!(
+            "--- left\n+++ right\n",
+            "@@ -1,3 +1,3 @@\n 1\n-2\n+two\n 3\n",
+            "@@ -10,1 +10,2 @@\n 10\n+11\n",
+        )
+    );
+}
+
+#[test]
+pub fn display_diff() {
+    let view = diff_view("display_diff_for");
+    assert_eq!(
+        view.compute_unified_diff(),
+        concat!(
+            "--- left\n+++ right\n",
+            "@@ -1,3 +1,3 @@\n",
+            " name: phlow\n",
+            "-retries: 3\n",
+            "-tags: a, b, c\n",
+            "+retries: 5\n",
+            "+tags: a, c, d\n",
+        )
+    );
+    assert!(view.to_string().starts_with("Display\n"));
+}
+
+#[test]
+pub fn debug_diff() {
+    let view = diff_view("debug_diff_for");
+    let changed = view
+        .compute_lines()
+        .into_iter()
+        .filter(|line| line.kind != PhlowDiffLineKind::Unchanged)
+        .map(|line| (line.kind, line.text.trim().to_string()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        changed,
+        vec![
+            (PhlowDiffLineKind::Deleted, "retries: 3,".to_string()),
+            (PhlowDiffLineKind::Inserted, "retries: 5,".to_string()),
+            (PhlowDiffLineKind::Deleted, "\"b\",".to_string()),
+            (PhlowDiffLineKind::Inserted, "\"d\",".to_string()),
+        ]
+    );
+}
+
+#[test]
+pub fn items_diff() {
+    let view = diff_view("items_diff_for");
+    let lines = view
+        .compute_lines()
+        .into_iter()
+        .map(|line| format!("{}{}", line.kind.prefix(), line.text))
+        .collect::<Vec<_>>();
+
+    assert_eq!(lines, vec![" a", "-b", " c", "+d"]);
+}
@@ -0,0 +1,145 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use std::num::ParseIntError;
+
+use phlow::{
+    PhlowColumnedListView, PhlowComputationError, PhlowListView, PhlowTextView, PhlowView,
+    TextComputation,
+};
+
+use common::*;
+
+pub struct Record(String);
+
+#[phlow::extensions(TestExtensions, Record)]
+impl RecordExtensions {
+    #[phlow::view(title = "Number")]
+    fn number_for(this: &Record, view: impl PhlowView) -> Result<impl PhlowView, ParseIntError> {
+        let number = this.0.parse::<usize>()?;
+        Ok(view.text().text::<Record>(move |_| number.to_string()))
+    }
+
+    #[phlow::view]
+    fn parsed_for(_this: &Record, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .title("Parsed")
+            .try_text::<Record, _>(|record| record.0.parse::<usize>().map(|n| n.to_string()))
+    }
+
+    #[phlow::view]
+    fn digits_for(_this: &Record, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Digits")
+            .try_items::<Record, _>(|record| {
+                record
+                    .0
+                    .chars()
+                    .map(|digit| {
+                        digit
+                            .to_digit(10)
+                            .ok_or(format!("`{}` is not a digit", digit))
+                    })
+                    .collect::<Result<Vec<u32>, String>>()
+                    .map(|digits| phlow_all!(digits))
+            })
+    }
+
+    #[phlow::view]
+    fn fields_for(_this: &Record, view: impl PhlowView) -> impl PhlowView {
+        view.columned_list()
+            .title("Fields")
+            .items::<Record>(|record| {
+                phlow_all!(record.0.split(',').map(|field| field.to_string()))
+            })
+            .column(|column| {
+                column.title("Number").try_text::<String, _>(|field| {
+                    field.trim().parse::<i64>().map(|n| n.to_string())
+                })
+            })
+    }
+}
+
+fn record(record: &str) -> phlow::PhlowObject {
+    phlow!(Record(record.to_string()))
+}
+
+#[test]
+pub fn failed_view_method_is_an_error_view() {
+    let view = view_named::<PhlowTextView>(&record("42"), "number_for");
+    assert_eq!(view.compute_text(), "42");
+
+    let view = view_named::<PhlowTextView>(&record("forty two"), "number_for");
+    assert_eq!(view.get_title(), "Number");
+    assert_eq!(view.compute_text(), "Error: invalid digit found in string");
+}
+
+#[test]
+pub fn failed_text_computation() {
+    let view = view_named::<PhlowTextView>(&record("7"), "parsed_for");
+    assert_eq!(view.compute_text(), "7");
+
+    let view = view_named::<PhlowTextView>(&record(""), "parsed_for");
+    assert_eq!(
+        view.compute_text(),
+        "Error: cannot parse integer from empty string"
+    );
+}
+
+#[test]
+pub fn failed_items_computation() {
+    let view = view_named::<PhlowListView>(&record("123"), "digits_for");
+    assert_eq!(view.compute_items_sync().len(), 3);
+
+    let view = view_named::<PhlowListView>(&record("1x3"), "digits_for");
+    let items = view.compute_items_sync();
+    assert_eq!(items.len(), 1);
+    assert_eq!(
+        items[0].value_ref::<PhlowComputationError>().as_deref(),
+        Some(&PhlowComputationError::Failed(
+            "`x` is not a digit".to_string()
+        ))
+    );
+    assert_eq!(
+        view.compute_item_text_sync(&items[0]),
+        "Error: `x` is not a digit"
+    );
+}
+
+#[test]
+pub fn failed_cell_computation() {
+    let view = view_named::<PhlowColumnedListView>(&record("1, two"), "fields_for");
+    let column = &view.get_columns()[0];
+    let texts = view
+        .compute_items()
+        .iter()
+        .map(|row| column.compute_cell_text(&column.compute_cell_item(row).unwrap()))
+        .collect::<Vec<String>>();
+
+    assert_eq!(texts, vec!["1", "Error: invalid digit found in string"]);
+}
+
+#[test]
+pub fn failed_async_computation() {
+    let computation = TextComputation::try_new_async::<String, String>(|string| {
+        let is_empty = string.is_empty();
+        Box::pin(async move {
+            if is_empty {
+                Err("nothing to show".to_string())
+            } else {
+                Ok("something".to_string())
+            }
+        })
+    });
+    let empty = String::new();
+
+    assert_eq!(
+        computation.value_block_on(&phlow!(empty)),
+        Some("Error: nothing to show".to_string())
+    );
+}
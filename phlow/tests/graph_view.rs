@@ -0,0 +1,137 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use phlow::{PhlowGraphEdge, PhlowGraphLink, PhlowGraphView, PhlowView};
+
+use common::*;
+
+#[derive(Debug)]
+pub struct Task {
+    name: &'static str,
+    next: RefCell<Vec<Rc<Task>>>,
+}
+
+pub struct Pipeline(Rc<Task>);
+
+#[phlow::extensions(TestExtensions, Pipeline)]
+impl PipelineExtensions {
+    #[phlow::view]
+    fn tasks_for(_this: &Pipeline, view: impl PhlowView) -> impl PhlowView {
+        view.graph()
+            .title("Tasks")
+            .nodes::<Pipeline>(|pipeline| vec![phlow!(pipeline.0.clone())])
+            .node_key::<Rc<Task>>(|task| format!("{:p}", Rc::as_ptr(&task)))
+            .node_text::<Rc<Task>>(|task| task.name.to_string())
+            .edges::<Rc<Task>>(|task| {
+                task.next
+                    .borrow()
+                    .iter()
+                    .map(|next| PhlowGraphEdge::new(phlow!(next.clone())).label("then"))
+                    .collect()
+            })
+            .send::<Rc<Task>>(|task| phlow!(task.name))
+    }
+}
+
+fn task(name: &'static str) -> Rc<Task> {
+    Rc::new(Task {
+        name,
+        next: RefCell::new(vec![]),
+    })
+}
+
+fn pipeline() -> Pipeline {
+    let fetch = task("fetch");
+    let build = task("build");
+    let test = task("test");
+    fetch.next.borrow_mut().push(build.clone());
+    build.next.borrow_mut().push(test.clone());
+    test.next.borrow_mut().push(fetch.clone());
+    fetch.next.borrow_mut().push(test);
+    Pipeline(fetch)
+}
+
+fn graph_view() -> PhlowGraphView {
+    let pipeline = pipeline();
+    view_named::<PhlowGraphView>(&phlow!(pipeline), "tasks_for")
+}
+
+fn link(from: usize, to: usize) -> PhlowGraphLink {
+    PhlowGraphLink {
+        from,
+        to,
+        label: "then".to_string(),
+    }
+}
+
+#[test]
+pub fn cycle() {
+    let graph = graph_view().compute_graph_sync();
+    let names = graph
+        .get_nodes()
+        .iter()
+        .map(|node| node.text.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, vec!["fetch", "build", "test"]);
+    assert_eq!(
+        graph.get_links(),
+        &[link(0, 1), link(0, 2), link(1, 2), link(2, 0)]
+    );
+}
+
+#[test]
+pub fn max_nodes() {
+    let graph = graph_view().max_nodes(2).compute_graph_sync();
+    assert_eq!(graph.get_nodes().len(), 2);
+    assert_eq!(graph.get_links(), &[link(0, 1)]);
+}
+
+#[test]
+pub fn dot_and_display() {
+    let view = graph_view().title("Tasks \"ci\"");
+    assert_eq!(
+        view.compute_graph_sync().to_dot(),
+        concat!(
+            "digraph {\n",
+            "  n0 [label=\"fetch\"];\n",
+            "  n1 [label=\"build\"];\n",
+            "  n2 [label=\"test\"];\n",
+            "  n0 -> n1 [label=\"then\"];\n",
+            "  n0 -> n2 [label=\"then\"];\n",
+            "  n1 -> n2 [label=\"then\"];\n",
+            "  n2 -> n0 [label=\"then\"];\n",
+            "}\n",
+        )
+    );
+    assert_eq!(
+        view.to_string(),
+        concat!(
+            "Tasks \"ci\"\n",
+            "---------------------\n",
+            "  0 | fetch\n",
+            "    | --then--> 1: build\n",
+            "    | --then--> 2: test\n",
+            "  1 | build\n",
+            "    | --then--> 2: test\n",
+            "  2 | test\n",
+            "    | --then--> 0: fetch\n",
+        )
+    );
+}
+
+#[test]
+pub fn send_node() {
+    let view = graph_view();
+    let graph = view.compute_graph_sync();
+    let sent = futures_executor::block_on(view.compute_item_to_send(&graph.get_nodes()[1].object));
+    assert_eq!(sent.unwrap().to_string(), "build");
+}
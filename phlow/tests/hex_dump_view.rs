@@ -0,0 +1,156 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use futures_util::FutureExt;
+use phlow::{PhlowHexDumpHighlight, PhlowHexDumpView, PhlowStyle, PhlowView, ITEMS_PAGE_SIZE};
+
+use common::*;
+
+pub struct Packet(Vec<u8>);
+
+#[phlow::extensions(TestExtensions, Packet)]
+impl PacketExtensions {
+    #[phlow::view]
+    fn hex_for(_this: &Packet, view: impl PhlowView) -> impl PhlowView {
+        view.hex_dump()
+            .title("Hex")
+            .bytes_per_row(4)
+            .bytes::<Packet>(|packet| packet.0.clone())
+            .highlights::<Packet>(|_packet| {
+                vec![PhlowHexDumpHighlight::new(2..6, PhlowStyle::new().bold())]
+            })
+    }
+
+    #[phlow::view]
+    fn paged_hex_for(_this: &Packet, view: impl PhlowView) -> impl PhlowView {
+        view.hex_dump()
+            .title("Paged hex")
+            .bytes_per_row(4)
+            .bytes_range::<Packet>(|packet, start, count| {
+                let bytes = &packet.0[start.min(packet.0.len())..];
+                bytes[..count.min(bytes.len())].to_vec()
+            })
+            .bytes_count::<Packet>(|packet| packet.0.len())
+    }
+
+    #[phlow::view]
+    fn streamed_hex_for(_this: &Packet, view: impl PhlowView) -> impl PhlowView {
+        view.hex_dump()
+            .title("Streamed hex")
+            .bytes_per_row(4)
+            .bytes_range::<Packet>(|packet, start, count| {
+                let bytes = &packet.0[start.min(packet.0.len())..];
+                bytes[..count.min(bytes.len())].to_vec()
+            })
+    }
+
+    #[phlow::view]
+    fn async_hex_for(_this: &Packet, view: impl PhlowView) -> impl PhlowView {
+        view.hex_dump()
+            .title("Async hex")
+            .async_bytes_range::<Packet>(|packet, start, count| {
+                assert!(count <= ITEMS_PAGE_SIZE);
+                let bytes = &packet.0[start.min(packet.0.len())..];
+                let bytes = bytes[..count.min(bytes.len())].to_vec();
+                async move { bytes }.boxed()
+            })
+    }
+}
+
+fn hex_dump_view(bytes: Vec<u8>, name: &str) -> PhlowHexDumpView {
+    let packet = Packet(bytes);
+    view_named::<PhlowHexDumpView>(&phlow!(packet), name)
+}
+
+#[test]
+pub fn rows() {
+    let view = hex_dump_view(b"Hi!\x00\x7fphlow".to_vec(), "hex_for");
+    assert_eq!(view.compute_rows_count_sync(), Some(3));
+
+    let rows = view.compute_rows_sync(0, 10);
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[0].get_offset(), 0);
+    assert_eq!(rows[0].hex(4), "48 69 21 00");
+    assert_eq!(rows[0].ascii(), "Hi!.");
+    assert_eq!(rows[1].get_offset(), 4);
+    assert_eq!(rows[1].ascii(), ".phl");
+    assert_eq!(rows[2].get_bytes(), b"ow");
+    assert_eq!(rows[2].hex(4), "6f 77      ");
+}
+
+#[test]
+pub fn highlights() {
+    let view = hex_dump_view((0..12).collect(), "hex_for");
+    let highlights = view.compute_highlights_sync();
+    let rows = view.compute_rows_sync(0, 3);
+
+    let ranges = rows
+        .iter()
+        .map(|row| {
+            row.highlights(&highlights)
+                .into_iter()
+                .map(|highlight| highlight.range)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(ranges, vec![vec![2..4], vec![0..2], vec![]]);
+}
+
+#[test]
+pub fn paging() {
+    let view = hex_dump_view((0..=255).collect(), "paged_hex_for");
+    assert_eq!(view.compute_rows_count_sync(), Some(64));
+
+    let rows = view.compute_rows_sync(10, 2);
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].get_offset(), 40);
+    assert_eq!(rows[0].get_bytes(), &[40, 41, 42, 43]);
+    assert_eq!(rows[1].get_offset(), 44);
+
+    assert!(view.compute_rows_sync(64, 1).is_empty());
+}
+
+#[test]
+pub fn async_byte_ranges_are_paged() {
+    let view = hex_dump_view((0..=255).cycle().take(1000).collect(), "async_hex_for");
+
+    let bytes = view.compute_bytes_sync(100, 800);
+    assert_eq!(bytes.len(), 800);
+    assert_eq!(bytes[0], 100);
+    assert_eq!(view.compute_rows_sync(62, 10).len(), 1);
+}
+
+#[test]
+pub fn printed_rows_of_unknown_count_are_capped() {
+    let view = hex_dump_view(
+        (0..=255).cycle().take(4 * 300 + 1).collect(),
+        "streamed_hex_for",
+    );
+    let printed = view.to_string();
+    let lines = printed.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 2 + PhlowHexDumpView::MAX_DISPLAYED_ROWS + 1);
+    assert_eq!(lines.last(), Some(&"…"));
+}
+
+#[cfg(feature = "view-specification")]
+#[test]
+pub fn items_of_unknown_count_are_paged() {
+    let view = hex_dump_view(
+        (0..=255).cycle().take(4 * 300 + 1).collect(),
+        "streamed_hex_for",
+    );
+    assert_eq!(view.compute_rows_count_sync(), None);
+
+    let specification = view
+        .as_view_specification_builder()
+        .create_view_specification()
+        .unwrap();
+    let items = futures_executor::block_on(specification.retrieve_items());
+    assert_eq!(items.len(), 301);
+}
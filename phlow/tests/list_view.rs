@@ -0,0 +1,133 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use futures_util::StreamExt;
+use phlow::{PhlowColor, PhlowListView, PhlowStyle, PhlowView, ITEMS_PAGE_SIZE};
+
+use common::*;
+
+pub struct Numbers(usize);
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl NumbersExtensions {
+    #[phlow::view]
+    fn paged_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Paged")
+            .items_range::<Numbers>(|numbers, start, count| {
+                phlow_all!((start..numbers.0).take(count))
+            })
+            .items_count::<Numbers>(|numbers| numbers.0)
+    }
+
+    /// The range computation adds the amount of items to the start index
+    #[phlow::view]
+    fn bounded_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Bounded")
+            .items_range::<Numbers>(|numbers, start, count| {
+                assert!(count <= ITEMS_PAGE_SIZE);
+                phlow_all!(start..(start + count).min(numbers.0))
+            })
+    }
+
+    #[phlow::view]
+    fn all_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("All")
+            .items::<Numbers>(|numbers| phlow_all!(0..numbers.0))
+    }
+
+    #[phlow::view]
+    fn styled_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Styled")
+            .items::<Numbers>(|numbers| phlow_all!(0..numbers.0))
+            .item_style::<usize>(|number| {
+                if *number % 2 == 0 {
+                    PhlowStyle::new().foreground(PhlowColor::RED).bold()
+                } else {
+                    PhlowStyle::new().dimmed().icon("inactive")
+                }
+            })
+    }
+}
+
+fn list_view(numbers: usize, view_name: &str) -> PhlowListView {
+    view_named::<PhlowListView>(&phlow!(Numbers(numbers)), view_name)
+}
+
+fn items_text(view: &PhlowListView, items: Vec<phlow::PhlowObject>) -> Vec<String> {
+    items
+        .iter()
+        .map(|each| view.compute_item_text_sync(each))
+        .collect()
+}
+
+#[test]
+pub fn items_range() {
+    let view = list_view(1_000_000, "paged_for");
+
+    let items = view.compute_items_range_sync(500_000, 3);
+    assert_eq!(items_text(&view, items), vec!["500000", "500001", "500002"]);
+    assert_eq!(view.compute_items_count_sync(), Some(1_000_000));
+
+    let items = view.compute_items_range_sync(999_999, 10);
+    assert_eq!(items_text(&view, items), vec!["999999"]);
+}
+
+#[test]
+pub fn unbounded_items_range_is_paged() {
+    let view = list_view(1_000, "bounded_for");
+
+    let items = view.compute_items_range_sync(10, usize::MAX);
+    assert_eq!(items.len(), 990);
+    assert_eq!(view.compute_item_text_sync(&items[989]), "999");
+
+    let items = futures_executor::block_on(view.compute_items_range(998, usize::MAX));
+    assert_eq!(items_text(&view, items), vec!["998", "999"]);
+}
+
+#[test]
+pub fn items_range_stream() {
+    let view = list_view(1_000, "paged_for");
+
+    let items = futures_executor::block_on(view.compute_items().collect::<Vec<_>>());
+    assert_eq!(items.len(), 1_000);
+    assert_eq!(view.compute_item_text_sync(&items[999]), "999");
+}
+
+#[test]
+pub fn items_range_without_range_computation() {
+    let view = list_view(10, "all_for");
+
+    let items = view.compute_items_range_sync(8, 5);
+    assert_eq!(items_text(&view, items), vec!["8", "9"]);
+    assert_eq!(view.compute_items_count_sync(), Some(10));
+}
+
+#[test]
+pub fn item_style() {
+    let view = list_view(2, "styled_for");
+    let items = view.compute_items_sync();
+
+    let even_style = view.compute_item_style_sync(&items[0]).unwrap();
+    assert_eq!(even_style.get_foreground(), Some(PhlowColor::RED));
+    assert!(even_style.is_bold());
+    assert_eq!(even_style.paint("0"), "\x1b[1;38;2;204;0;0m0\x1b[0m");
+
+    let odd_style = view.compute_item_style_sync(&items[1]).unwrap();
+    assert!(odd_style.is_dimmed());
+    assert_eq!(odd_style.get_icon(), Some("inactive"));
+
+    assert!(view.to_string().contains("\x1b[2m1\x1b[0m"));
+    assert_eq!(
+        list_view(1, "all_for").compute_item_style_sync(&items[0]),
+        None
+    );
+}
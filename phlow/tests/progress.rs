@@ -0,0 +1,207 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use futures_channel::oneshot;
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+
+use phlow::{PhlowColumnedListView, PhlowListView, PhlowProgress, PhlowProgressReport, PhlowView};
+
+use common::*;
+
+pub struct Numbers(usize);
+
+static RESUMED: Mutex<Option<oneshot::Receiver<()>>> = Mutex::new(None);
+static COLUMNED_RESUMED: Mutex<Option<oneshot::Receiver<()>>> = Mutex::new(None);
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl NumbersExtensions {
+    #[phlow::view]
+    fn batches_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Batches")
+            .async_items_with_progress::<Numbers>(|numbers, progress| {
+                let count = numbers.0;
+                Box::pin(async move { emit_in_batches(count, progress) })
+            })
+    }
+
+    #[phlow::view]
+    fn columned_batches_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.columned_list()
+            .title("Columned batches")
+            .async_items_with_progress::<Numbers>(|numbers, progress| {
+                let count = numbers.0;
+                Box::pin(async move { emit_in_batches(count, progress) })
+            })
+            .column_item::<usize>("Number", |number| phlow!(*number))
+    }
+
+    #[phlow::view]
+    fn gated_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Gated")
+            .async_items_with_progress::<Numbers>(|_numbers, progress| {
+                let resumed = RESUMED.lock().take();
+                Box::pin(async move {
+                    progress.report(0.5, "Waiting");
+                    progress.emit(phlow_all!(vec![0usize]));
+                    if let Some(resumed) = resumed {
+                        let _ = resumed.await;
+                    }
+                    phlow_all!(vec![1usize, 2])
+                })
+            })
+    }
+
+    #[phlow::view]
+    fn columned_gated_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.columned_list()
+            .title("Columned gated")
+            .async_items_with_progress::<Numbers>(|_numbers, progress| {
+                let resumed = COLUMNED_RESUMED.lock().take();
+                Box::pin(async move {
+                    progress.emit(phlow_all!(vec![0usize]));
+                    if let Some(resumed) = resumed {
+                        let _ = resumed.await;
+                    }
+                    phlow_all!(vec![1usize, 2])
+                })
+            })
+            .column_item::<usize>("Number", |number| phlow!(*number))
+    }
+
+    #[phlow::view]
+    fn plain_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Plain")
+            .items::<Numbers>(|numbers| phlow_all!(0..numbers.0))
+    }
+}
+
+/// Emit all but the last number one by one and return the last one
+fn emit_in_batches(count: usize, progress: PhlowProgress) -> Vec<phlow::PhlowObject> {
+    for number in 0..count.saturating_sub(1) {
+        progress.report(number as f32 / count as f32, format!("Number {}", number));
+        progress.emit(phlow_all!(vec![number]));
+    }
+    phlow_all!((count.saturating_sub(1)..count).collect::<Vec<_>>())
+}
+
+fn numbers_of(items: &[phlow::PhlowObject]) -> Vec<usize> {
+    items
+        .iter()
+        .map(|item| *item.value_ref::<usize>().unwrap())
+        .collect()
+}
+
+#[test]
+pub fn emitted_items_are_streamed_in_order() {
+    let view = view_named::<PhlowListView>(&phlow!(Numbers(4)), "batches_for");
+    let items = view.compute_items_sync();
+
+    assert_eq!(numbers_of(&items), vec![0, 1, 2, 3]);
+    assert_eq!(view.compute_items_count_sync(), Some(4));
+}
+
+#[test]
+pub fn progress_is_finished_after_computation() {
+    let view = view_named::<PhlowListView>(&phlow!(Numbers(4)), "batches_for");
+    let progress = view.new_items_progress().unwrap();
+    assert_eq!(progress.get_report(), PhlowProgressReport::default());
+
+    view.try_compute_items_sync_with_progress(progress.clone())
+        .unwrap();
+    assert_eq!(
+        progress.get_report(),
+        PhlowProgressReport {
+            fraction: Some(1.0),
+            message: "Number 2".to_string(),
+            items_count: 4,
+            is_finished: true,
+        }
+    );
+}
+
+#[test]
+pub fn emitted_items_arrive_before_computation_finishes() {
+    let (resume, resumed) = oneshot::channel::<()>();
+    RESUMED.lock().replace(resumed);
+    let view = view_named::<PhlowListView>(&phlow!(Numbers(3)), "gated_for");
+    let progress = view.new_items_progress().unwrap();
+
+    futures_executor::block_on(async {
+        let mut items = Box::pin(view.compute_items_with_progress(progress.clone()));
+        let first = items.next().await.unwrap();
+        assert_eq!(*first.value_ref::<usize>().unwrap(), 0);
+        assert_eq!(progress.get_fraction(), Some(0.5));
+        assert_eq!(progress.get_message(), "Waiting");
+        assert!(!progress.is_finished());
+
+        resume.send(()).unwrap();
+        let rest = items.collect::<Vec<_>>().await;
+        assert_eq!(numbers_of(&rest), vec![1, 2]);
+    });
+    assert!(progress.is_finished());
+}
+
+#[test]
+pub fn computations_report_to_their_own_progress() {
+    let view = view_named::<PhlowListView>(&phlow!(Numbers(4)), "batches_for");
+    let first = view.new_items_progress().unwrap();
+    let second = view.new_items_progress().unwrap();
+
+    view.try_compute_items_sync_with_progress(first.clone())
+        .unwrap();
+    let first_report = first.get_report();
+    futures_executor::block_on(async {
+        let mut second_items = Box::pin(view.compute_items_with_progress(second.clone()));
+        second_items.next().await.unwrap();
+        assert_eq!(first.get_report(), first_report);
+        second_items.collect::<Vec<_>>().await;
+    });
+
+    assert_eq!(first.get_report(), first_report);
+    assert_eq!(second.get_report(), first_report);
+    assert_eq!(first_report.items_count, 4);
+}
+
+#[test]
+pub fn columned_list_with_progress() {
+    let view = view_named::<PhlowColumnedListView>(&phlow!(Numbers(3)), "columned_batches_for");
+    let progress = view.new_items_progress().unwrap();
+    let items = view
+        .try_compute_items_with_progress(progress.clone())
+        .unwrap();
+
+    assert_eq!(numbers_of(&items), vec![0, 1, 2]);
+    assert_eq!(progress.get_report().items_count, 3);
+}
+
+#[test]
+pub fn columned_list_streams_emitted_items() {
+    let (resume, resumed) = oneshot::channel::<()>();
+    COLUMNED_RESUMED.lock().replace(resumed);
+    let view = view_named::<PhlowColumnedListView>(&phlow!(Numbers(3)), "columned_gated_for");
+
+    futures_executor::block_on(async {
+        let mut items = Box::pin(view.async_compute_items());
+        let first = items.next().await.unwrap();
+        assert_eq!(*first.value_ref::<usize>().unwrap(), 0);
+
+        resume.send(()).unwrap();
+        let rest = items.collect::<Vec<_>>().await;
+        assert_eq!(numbers_of(&rest), vec![1, 2]);
+    });
+}
+
+#[test]
+pub fn views_without_progress() {
+    let view = view_named::<PhlowListView>(&phlow!(Numbers(3)), "plain_for");
+    assert!(view.new_items_progress().is_none());
+}
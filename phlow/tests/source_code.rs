@@ -0,0 +1,80 @@
+//! The source code captured for view methods depends on the `source-*` features:
+//!
+//! cargo test -p phlow --test source_code
+//! cargo test -p phlow --test source_code --no-default-features --features phlow-derive
+//! cargo test -p phlow --test source_code --no-default-features --features phlow-derive,source-raw
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use phlow::PhlowView;
+
+use common::*;
+
+pub struct Shelf;
+
+#[phlow::extensions(TestExtensions, Shelf)]
+impl ShelfExtensions {
+    #[phlow::view]
+    #[allow(unused_variables)]
+    fn count_for(this: &Shelf, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Count")
+    }
+}
+
+fn count_source_code() -> String {
+    let shelf = Shelf;
+    let object = phlow!(shelf);
+    let view = object.phlow_view_named("count_for").unwrap();
+    view.get_defining_method().source_code().to_string()
+}
+
+/// Printed tokens are spaced differently by different compilers
+#[cfg(not(feature = "source-formatted"))]
+fn without_whitespace(source_code: &str) -> String {
+    source_code.split_whitespace().collect()
+}
+
+#[cfg(not(feature = "source-raw"))]
+#[test]
+pub fn signature_only() {
+    assert_eq!(
+        without_whitespace(&count_source_code()),
+        "fncount_for(this:&Shelf,view:implPhlowView)->implPhlowView"
+    );
+}
+
+#[cfg(all(feature = "source-raw", not(feature = "source-formatted")))]
+#[test]
+pub fn raw_source_code() {
+    let source_code = count_source_code();
+    assert_eq!(
+        without_whitespace(&source_code),
+        concat!(
+            "#[phlow::view]#[allow(unused_variables)]",
+            "fncount_for(this:&Shelf,view:implPhlowView)->implPhlowView",
+            "{view.text().title(\"Count\")}"
+        )
+    );
+    assert!(!source_code.contains("\n    view"));
+}
+
+// rustfmt formats the source code differently when it is installed
+#[cfg(all(feature = "source-formatted", not(feature = "rustfmt")))]
+#[test]
+pub fn pretty_printed_source_code() {
+    assert_eq!(
+        count_source_code(),
+        concat!(
+            "#[phlow::view]\n",
+            "#[allow(unused_variables)]\n",
+            "fn count_for(this: &Shelf, view: impl PhlowView) -> impl PhlowView {\n",
+            "    view.text().title(\"Count\")\n",
+            "}"
+        )
+    );
+}
@@ -0,0 +1,224 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use phlow::{
+    set_global_time_budget, PhlowCancellationToken, PhlowColumnedListView, PhlowComputationError,
+    PhlowListView, PhlowTextView, PhlowView, TextComputation,
+};
+
+use common::*;
+
+pub struct Numbers(usize);
+
+/// Not thread safe, computations over it must stay on the calling thread
+pub struct LocalNumbers(Rc<usize>);
+
+const BUDGET: Duration = Duration::from_millis(50);
+
+static ENDLESS_ITEMS_STOPPED: AtomicBool = AtomicBool::new(false);
+static ENDLESS_ASYNC_ITEMS_STOPPED: AtomicBool = AtomicBool::new(false);
+
+fn current_thread_name() -> String {
+    thread::current().name().unwrap_or_default().to_string()
+}
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl NumbersExtensions {
+    #[phlow::view]
+    fn endless_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Endless")
+            .time_budget(BUDGET)
+            .items::<Numbers>(|_numbers| {
+                let token = PhlowCancellationToken::current();
+                let mut items = vec![];
+                while !token.is_cancelled() {
+                    items.push(items.len());
+                    thread::sleep(Duration::from_millis(1));
+                }
+                ENDLESS_ITEMS_STOPPED.store(true, Ordering::SeqCst);
+                phlow_all!(items)
+            })
+    }
+
+    #[phlow::view]
+    fn endless_async_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Endless async")
+            .time_budget(BUDGET)
+            .async_items_cancellable::<Numbers>(|_numbers, token| {
+                Box::pin(async move {
+                    while !token.is_cancelled() {
+                        thread::sleep(Duration::from_millis(1));
+                    }
+                    ENDLESS_ASYNC_ITEMS_STOPPED.store(true, Ordering::SeqCst);
+                    vec![]
+                })
+            })
+    }
+
+    #[phlow::view]
+    fn thread_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .title("Thread")
+            .time_budget(Duration::from_secs(10))
+            .text::<Numbers>(|_numbers| current_thread_name())
+    }
+
+    #[phlow::view]
+    fn all_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("All")
+            .time_budget(Duration::from_secs(10))
+            .items::<Numbers>(|numbers| phlow_all!(0..numbers.0))
+    }
+
+    #[phlow::view]
+    fn slow_cells_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.columned_list()
+            .title("Slow cells")
+            .time_budget(BUDGET)
+            .items::<Numbers>(|numbers| phlow_all!(0..numbers.0))
+            .column(|column| {
+                column.title("Number").text::<usize>(|number| {
+                    thread::sleep(Duration::from_millis(100));
+                    number.to_string()
+                })
+            })
+    }
+
+    #[phlow::view]
+    fn slow_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Slow").text::<Numbers>(|numbers| {
+            thread::sleep(Duration::from_millis(500));
+            numbers.0.to_string()
+        })
+    }
+}
+
+#[phlow::extensions(TestExtensions, LocalNumbers)]
+impl LocalNumbersExtensions {
+    #[phlow::view]
+    fn thread_for(_this: &LocalNumbers, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .title("Thread")
+            .time_budget(Duration::from_secs(10))
+            .text::<LocalNumbers>(|numbers| format!("{} {}", numbers.0, current_thread_name()))
+    }
+}
+
+#[test]
+pub fn exceeded_time_budget() {
+    let view = view_named::<PhlowListView>(&phlow!(Numbers(3)), "endless_for");
+
+    assert_eq!(view.get_time_budget(), Some(BUDGET));
+    assert_eq!(
+        view.try_compute_items_sync().err(),
+        Some(PhlowComputationError::TimedOut(BUDGET))
+    );
+    assert!(view.to_string().contains("did not finish within 50 ms"));
+
+    // the computation notices the cancellation and stops
+    thread::sleep(Duration::from_millis(200));
+    assert!(ENDLESS_ITEMS_STOPPED.load(Ordering::SeqCst));
+}
+
+#[test]
+pub fn within_time_budget() {
+    let view = view_named::<PhlowListView>(&phlow!(Numbers(3)), "all_for");
+
+    assert_eq!(
+        view.try_compute_items_sync().map(|items| items.len()),
+        Ok(3)
+    );
+    assert_eq!(view.try_compute_items_count_sync(), Ok(Some(3)));
+}
+
+#[test]
+pub fn printed_cells_are_computed_within_the_time_budget() {
+    let view = view_named::<PhlowColumnedListView>(&phlow!(Numbers(3)), "slow_cells_for");
+
+    assert!(view.to_string().contains("did not finish within 50 ms"));
+}
+
+#[test]
+pub fn cancellable_async_computation() {
+    let stopped = Arc::new(AtomicBool::new(false));
+    let computation = {
+        let stopped = stopped.clone();
+        TextComputation::new_async_cancellable::<Numbers>(move |_numbers, token| {
+            let stopped = stopped.clone();
+            Box::pin(async move {
+                while !token.is_cancelled() {
+                    thread::sleep(Duration::from_millis(1));
+                }
+                stopped.store(true, Ordering::SeqCst);
+                "cancelled".to_string()
+            })
+        })
+    };
+    let numbers = Numbers(3);
+    let object = phlow!(numbers);
+
+    assert_eq!(
+        computation.value_within_block_on(&object, Some(BUDGET)),
+        Err(PhlowComputationError::TimedOut(BUDGET))
+    );
+    thread::sleep(Duration::from_millis(200));
+    assert!(stopped.load(Ordering::SeqCst));
+}
+
+#[test]
+pub fn global_time_budget() {
+    let view = view_named::<PhlowTextView>(&phlow!(Numbers(3)), "slow_for");
+    assert_eq!(view.try_compute_text(), Ok("3".to_string()));
+
+    set_global_time_budget(Some(BUDGET));
+    let result = view.try_compute_text();
+    set_global_time_budget(None);
+
+    assert_eq!(result, Err(PhlowComputationError::TimedOut(BUDGET)));
+}
+
+#[test]
+pub fn cancellable_async_items() {
+    let view = view_named::<PhlowListView>(&phlow!(Numbers(3)), "endless_async_for");
+
+    assert_eq!(
+        view.try_compute_items_sync().err(),
+        Some(PhlowComputationError::TimedOut(BUDGET))
+    );
+    thread::sleep(Duration::from_millis(200));
+    assert!(ENDLESS_ASYNC_ITEMS_STOPPED.load(Ordering::SeqCst));
+}
+
+#[test]
+pub fn thread_safe_values_are_computed_by_workers() {
+    let view = view_named::<PhlowTextView>(&phlow!(Numbers(3)), "thread_for");
+    let thread_name = view.try_compute_text().unwrap();
+    assert!(thread_name.starts_with("phlow-computation-"));
+}
+
+#[test]
+pub fn other_values_are_computed_in_place() {
+    let numbers = LocalNumbers(Rc::new(3));
+    let object = phlow!(numbers);
+    assert!(!object.is_thread_safe());
+
+    let view = view_named::<PhlowTextView>(&object, "thread_for");
+    assert_eq!(
+        view.try_compute_text(),
+        Ok(format!("3 {}", current_thread_name()))
+    );
+}
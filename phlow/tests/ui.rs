@@ -0,0 +1,7 @@
+/// Compile errors reported by `#[phlow::extensions]` and `#[derive(PhlowVariant)]`,
+/// regenerate the expected output with `TRYBUILD=overwrite cargo test --test ui`
+#[test]
+pub fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,19 @@
+#![allow(incomplete_features, unused)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+use phlow::PhlowView;
+
+define_extensions!(TestExtensions);
+
+pub struct Numbers(usize);
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl Default for NumbersExtensions {
+    fn default() -> Self {
+        Self
+    }
+}
+fn main() {}
@@ -0,0 +1,27 @@
+#![allow(incomplete_features, unused)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+use phlow::PhlowView;
+
+define_extensions!(TestExtensions);
+
+#[phlow::extensions(TestExtensions, [u8])]
+impl BytesExtensions {
+    #[phlow::view]
+    fn bytes_for(_this: &[u8], view: impl PhlowView) -> impl PhlowView {
+        view.text()
+    }
+}
+
+#[phlow::extensions(TestExtensions, str)]
+impl StrExtensions {
+    #[phlow::view]
+    fn str_for(_this: &str, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![allow(incomplete_features, unused)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod messages {
+    define_extensions!(MessageExtensions);
+    import_extensions!(MessageExtensions);
+
+    #[derive(phlow::PhlowVariant)]
+    pub enum Message {
+        Text(String),
+    }
+}
+
+fn main() {}
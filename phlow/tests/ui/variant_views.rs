@@ -0,0 +1,36 @@
+#![allow(incomplete_features, unused)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+use phlow::PhlowView;
+
+define_extensions!(TestExtensions);
+import_extensions!(TestExtensions);
+
+#[derive(phlow::PhlowVariant)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(phlow::PhlowVariant)]
+#[phlow(category = TestExtensions)]
+pub enum Message {
+    Ping,
+}
+
+pub enum Shape {
+    Circle(f64),
+}
+
+#[phlow::extensions(TestExtensions, Shape)]
+impl ShapeExtensions {
+    #[phlow::view(variant = Shape::Circle)]
+    fn radius_for(_this: &Shape, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+    }
+}
+
+fn main() {}
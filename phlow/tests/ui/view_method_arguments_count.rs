@@ -0,0 +1,25 @@
+#![allow(incomplete_features, unused)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+use phlow::PhlowView;
+
+define_extensions!(TestExtensions);
+
+pub struct Numbers(usize);
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl NumbersExtensions {
+    #[phlow::view]
+    fn numbers_for(view: impl PhlowView) -> impl PhlowView {
+        view.text()
+    }
+
+    #[phlow::view]
+    fn empty_for() -> impl PhlowView {
+        todo!()
+    }
+}
+fn main() {}
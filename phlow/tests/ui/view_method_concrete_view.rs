@@ -0,0 +1,20 @@
+#![allow(incomplete_features, unused)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+use phlow::PhlowView;
+
+define_extensions!(TestExtensions);
+
+pub struct Numbers(usize);
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl NumbersExtensions {
+    #[phlow::view]
+    fn numbers_for(_this: &Numbers, view: phlow::PhlowProtoView) -> impl PhlowView {
+        view.text()
+    }
+}
+fn main() {}
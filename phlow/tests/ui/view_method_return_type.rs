@@ -0,0 +1,25 @@
+#![allow(incomplete_features, unused)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+use phlow::PhlowView;
+
+define_extensions!(TestExtensions);
+
+pub struct Numbers(usize);
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl NumbersExtensions {
+    #[phlow::view]
+    fn numbers_for(_this: &Numbers, view: impl PhlowView) {
+        view.text();
+    }
+
+    #[phlow::view]
+    fn text_for(_this: &Numbers, view: impl PhlowView) -> phlow::PhlowTextView {
+        view.text()
+    }
+}
+fn main() {}
@@ -0,0 +1,182 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use phlow::{PhlowColumnedListView, PhlowObject, PhlowVariant, PhlowView};
+
+use common::*;
+
+#[derive(phlow::PhlowVariant)]
+#[phlow(extensions = TestExtensions)]
+pub enum Message {
+    Ping,
+    Text(String),
+    Move { x: i32, y: i32 },
+}
+
+#[derive(phlow::PhlowVariant)]
+pub enum Shape {
+    Circle(f64),
+    Rectangle { width: f64, height: f64 },
+}
+
+#[phlow::extensions(TestExtensions, Shape)]
+impl ShapeExtensions {
+    #[phlow::view]
+    fn variant_for(_this: &Shape, view: impl PhlowView) -> impl PhlowView {
+        view.variant::<Shape>()
+    }
+
+    #[phlow::view(title = "Radius", variant = Circle)]
+    fn radius_for(_this: &Shape, view: impl PhlowView) -> impl PhlowView {
+        view.text().text::<Shape>(|shape| match *shape {
+            Shape::Circle(radius) => radius.to_string(),
+            _ => unreachable!(),
+        })
+    }
+
+    #[phlow::view(title = "Square", variant = Rectangle, when = |shape: &Shape| matches!(shape, Shape::Rectangle { width, height } if width == height))]
+    fn square_for(_this: &Shape, view: impl PhlowView) -> impl PhlowView {
+        view.text().text::<Shape>(|_shape| "square".to_string())
+    }
+}
+
+mod labels {
+    use phlow::PhlowView;
+
+    define_extensions!(ModuleExtensions);
+    import_extensions!(ModuleExtensions);
+
+    pub struct Label(pub String);
+
+    #[phlow::extensions(crate::labels::ModuleExtensions, Label)]
+    impl LabelExtensions {
+        #[phlow::view]
+        fn label_for(_this: &Label, view: impl PhlowView) -> impl PhlowView {
+            view.text().text::<Label>(|label| label.0.clone())
+        }
+    }
+
+    #[derive(phlow::PhlowVariant)]
+    #[phlow(extensions_of_val = self::phlow_extensions_of_val)]
+    pub enum Tag {
+        Labeled(Label),
+    }
+}
+
+fn variant_rows(object: &PhlowObject) -> Vec<(String, String)> {
+    let view = view_named::<PhlowColumnedListView>(&object, "variant_for");
+    let columns = view.get_columns();
+
+    view.compute_items()
+        .iter()
+        .map(|row| {
+            let cells = columns
+                .iter()
+                .map(|column| {
+                    let cell = column.compute_cell_item(row).unwrap();
+                    column.compute_cell_text(&cell)
+                })
+                .collect::<Vec<String>>();
+            (cells[0].clone(), cells[1].clone())
+        })
+        .collect()
+}
+
+fn row(field: &str, value: &str) -> (String, String) {
+    (field.to_string(), value.to_string())
+}
+
+fn view_titles(shape: Shape) -> Vec<String> {
+    phlow!(shape)
+        .phlow_views()
+        .iter()
+        .map(|view| view.get_title().to_string())
+        .collect()
+}
+
+#[test]
+pub fn variant_names_and_fields() {
+    let message = Message::Move { x: 1, y: -2 };
+    assert_eq!(message.phlow_variant_name(), "Move");
+    assert_eq!(Message::Ping.phlow_variant_name(), "Ping");
+
+    let object = phlow!(message);
+    let fields = object
+        .value_ref::<Message>()
+        .unwrap()
+        .phlow_variant_fields(&object);
+    let fields = fields
+        .iter()
+        .map(|(name, value)| (name.clone(), value.to_string()))
+        .collect::<Vec<_>>();
+    assert_eq!(fields, vec![row("x", "1"), row("y", "-2")]);
+}
+
+#[test]
+pub fn derived_variant_view() {
+    let text = Message::Text("hello".to_string());
+    assert_eq!(
+        variant_rows(&phlow!(text)),
+        vec![row("variant", "Text"), row("0", "hello")]
+    );
+
+    let ping = Message::Ping;
+    assert_eq!(variant_rows(&phlow!(ping)), vec![row("variant", "Ping")]);
+
+    let method = phlow!(Message::Ping).phlow_view_methods().remove(0);
+    assert_eq!(method.full_method_name, "Message::variant_for");
+    assert_eq!(method.get_default_title(), "Variant");
+}
+
+#[test]
+pub fn variant_view_in_extensions() {
+    let shape = Shape::Rectangle {
+        width: 2.0,
+        height: 3.0,
+    };
+    assert_eq!(
+        variant_rows(&phlow!(shape)),
+        vec![
+            row("variant", "Rectangle"),
+            row("width", "2"),
+            row("height", "3")
+        ]
+    );
+}
+
+#[test]
+pub fn views_of_variants() {
+    assert_eq!(view_titles(Shape::Circle(1.5)), vec!["Radius", "Variant"]);
+    assert_eq!(
+        view_titles(Shape::Rectangle {
+            width: 1.0,
+            height: 2.0
+        }),
+        vec!["Variant"]
+    );
+    assert_eq!(
+        view_titles(Shape::Rectangle {
+            width: 2.0,
+            height: 2.0
+        }),
+        vec!["Square", "Variant"]
+    );
+}
+
+#[test]
+pub fn fields_with_extensions_of_another_module() {
+    let tag = labels::Tag::Labeled(labels::Label("urgent".to_string()));
+    let object = phlow!(tag);
+    let fields = object
+        .value_ref::<labels::Tag>()
+        .unwrap()
+        .phlow_variant_fields(&object);
+
+    let label = &fields[0].1;
+    assert!(label.phlow_view_named("label_for").is_some());
+}
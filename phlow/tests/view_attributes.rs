@@ -0,0 +1,98 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use phlow::PhlowView;
+
+use common::*;
+
+pub struct Numbers(Vec<usize>);
+
+fn has_many_numbers(numbers: &Numbers) -> bool {
+    numbers.0.len() > 2
+}
+
+#[phlow::extensions(TestExtensions, Numbers)]
+impl NumbersExtensions {
+    #[phlow::view(title = "Items", priority = 5)]
+    fn items_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .items::<Numbers>(|numbers| phlow_all!(numbers.0.clone()))
+    }
+
+    #[phlow::view(title = "Count")]
+    fn count_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .title("Amount")
+            .text::<Numbers>(|numbers| numbers.0.len().to_string())
+    }
+
+    #[phlow::view(title = "Sum", when = has_many_numbers)]
+    fn sum_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.text()
+            .text::<Numbers>(|numbers| numbers.0.iter().sum::<usize>().to_string())
+    }
+
+    #[phlow::view(when = |numbers: &Numbers| numbers.0.is_empty())]
+    fn empty_for(_this: &Numbers, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Empty")
+    }
+}
+
+fn view_titles(numbers: Vec<usize>) -> Vec<String> {
+    let numbers = Numbers(numbers);
+    phlow!(numbers)
+        .phlow_views()
+        .iter()
+        .map(|view| view.get_title().to_string())
+        .collect()
+}
+
+#[test]
+pub fn attributes_are_view_defaults() {
+    let numbers = Numbers(vec![1, 2, 3]);
+    let object = phlow!(numbers);
+
+    let items = object.phlow_view_named("items_for").unwrap();
+    assert_eq!(items.get_title(), "Items");
+    assert_eq!(items.get_priority(), 5);
+
+    // the building code overrides the defaults
+    let count = object.phlow_view_named("count_for").unwrap();
+    assert_eq!(count.get_title(), "Amount");
+    assert_eq!(count.get_priority(), 10);
+}
+
+#[test]
+pub fn metadata_without_building_views() {
+    let numbers = Numbers(vec![1]);
+    let object = phlow!(numbers);
+    let methods = object.phlow_view_methods();
+    let items = methods
+        .iter()
+        .find(|method| method.method_name == "items_for")
+        .unwrap();
+
+    assert_eq!(items.get_title(), Some("Items"));
+    assert_eq!(items.get_priority(), Some(5));
+    assert!(items.is_applicable_to(&object));
+
+    let sum = methods
+        .iter()
+        .find(|method| method.method_name == "sum_for")
+        .unwrap();
+    assert_eq!(sum.get_priority(), None);
+    assert_eq!(sum.get_default_priority(), 10);
+    assert!(!sum.is_applicable_to(&object));
+}
+
+#[test]
+pub fn views_are_shown_when_applicable() {
+    assert_eq!(view_titles(vec![1, 2, 3]), vec!["Items", "Amount", "Sum"]);
+    assert_eq!(view_titles(vec![1]), vec!["Items", "Amount"]);
+    assert_eq!(view_titles(vec![]), vec!["Items", "Amount", "Empty"]);
+}
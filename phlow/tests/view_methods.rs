@@ -0,0 +1,116 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+mod common;
+
+use phlow::{view, PhlowView};
+
+use common::*;
+
+pub struct Shelf(Vec<&'static str>);
+
+/// Views of a book shelf
+#[phlow::extensions(TestExtensions, Shelf)]
+impl ShelfExtensions {
+    /// Books on the shelf.
+    ///
+    /// Documented views are still views
+    #[phlow::view]
+    fn books_for(this: &Shelf, view: impl PhlowView) -> impl PhlowView {
+        view.text().title(Self::books_title(this))
+    }
+
+    #[view]
+    #[allow(unused_variables)]
+    fn count_for(this: &Shelf, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Count")
+    }
+
+    /// Helper methods with attributes are not views
+    #[inline]
+    #[allow(dead_code)]
+    fn books_title(this: &Shelf) -> String {
+        format!("Books ({})", this.0.len())
+    }
+
+    fn unused_helper_for(_this: &Shelf) -> usize {
+        0
+    }
+}
+
+#[test]
+pub fn only_annotated_methods_are_views() {
+    let shelf = Shelf(vec!["Dune", "Emma"]);
+    let object = phlow!(shelf);
+    let names = object
+        .phlow_views()
+        .iter()
+        .map(|view| view.get_defining_method().method_name.clone())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, vec!["books_for", "count_for"]);
+    assert_eq!(
+        object.phlow_view_named("books_for").unwrap().get_title(),
+        "Books (2)"
+    );
+    assert_eq!(ShelfExtensions::unused_helper_for(&Shelf(vec![])), 0);
+}
+
+#[test]
+pub fn view_method_source_location() {
+    let shelf = Shelf(vec![]);
+    let methods = phlow!(shelf).phlow_view_methods();
+    let books = methods
+        .iter()
+        .find(|method| method.method_name == "books_for")
+        .unwrap();
+
+    assert_eq!(books.source_location().file, file!());
+    assert_eq!(books.source_location().line, 22);
+    assert_eq!(books.source_location().column, 8);
+    assert_eq!(
+        books.source_location().to_string(),
+        format!("{}:22:8", file!())
+    );
+    assert_eq!(books.extension.module_path(), module_path!());
+    assert_eq!(books.extension.crate_name(), "view_methods");
+}
+
+#[test]
+pub fn doc_comments_are_descriptions() {
+    let shelf = Shelf(vec!["Dune"]);
+    let object = phlow!(shelf);
+    let methods = object.phlow_view_methods();
+    let description_of = |name: &str| {
+        methods
+            .iter()
+            .find(|method| method.method_name == name)
+            .unwrap()
+            .get_description()
+            .map(|description| description.to_string())
+    };
+
+    assert_eq!(
+        description_of("books_for").as_deref(),
+        Some("Books on the shelf.\n\nDocumented views are still views")
+    );
+    assert_eq!(description_of("count_for"), None);
+    assert_eq!(
+        methods[0].extension.description(),
+        Some("Views of a book shelf")
+    );
+
+    let books = object.phlow_view_named("books_for").unwrap().to_string();
+    assert_eq!(
+        books.lines().take(4).collect::<Vec<_>>(),
+        vec![
+            "Books (1)",
+            "Books on the shelf.",
+            "",
+            "Documented views are still views"
+        ]
+    );
+}
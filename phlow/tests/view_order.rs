@@ -0,0 +1,94 @@
+#![allow(incomplete_features)]
+#![feature(specialization)]
+
+#[macro_use]
+extern crate phlow;
+
+use phlow::PhlowView;
+
+define_extensions!(TestExtensions);
+define_extensions!(OtherExtensions);
+import_extensions!(TestExtensions, OtherExtensions);
+
+pub struct Shelf(Vec<&'static str>);
+
+#[phlow::extensions(TestExtensions, Shelf)]
+impl ShelfExtensions {
+    #[phlow::view]
+    fn raw_for(_this: &Shelf, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Raw").priority(20)
+    }
+
+    #[phlow::view]
+    fn books_for(_this: &Shelf, view: impl PhlowView) -> impl PhlowView {
+        view.list()
+            .title("Books")
+            .priority(5)
+            .items::<Shelf>(|shelf| phlow_all!(shelf.0.clone()))
+    }
+
+    #[phlow::view]
+    fn count_for(_this: &Shelf, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("Count").priority(5)
+    }
+}
+
+#[phlow::extensions(OtherExtensions, Shelf)]
+impl OtherShelfExtensions {
+    #[phlow::view]
+    fn books_for(_this: &Shelf, view: impl PhlowView) -> impl PhlowView {
+        view.list().title("Books").priority(5)
+    }
+
+    #[phlow::view]
+    fn first_for(_this: &Shelf, view: impl PhlowView) -> impl PhlowView {
+        view.text().title("First").priority(1)
+    }
+}
+
+fn shelf() -> Shelf {
+    Shelf(vec!["Dune", "Emma"])
+}
+
+#[test]
+pub fn sorted_views() {
+    let shelf = shelf();
+    let views = phlow!(shelf).phlow_views();
+    let names = views
+        .iter()
+        .map(|view| view.get_defining_method().full_method_name.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        names,
+        vec![
+            "OtherShelfExtensions::first_for",
+            "OtherShelfExtensions::books_for",
+            "ShelfExtensions::books_for",
+            "ShelfExtensions::count_for",
+            "ShelfExtensions::raw_for",
+        ]
+    );
+}
+
+#[test]
+pub fn views_of_type() {
+    let shelf = shelf();
+    let object = phlow!(shelf);
+
+    let lists = object
+        .phlow_views_of_type("list_view")
+        .iter()
+        .map(|view| view.get_defining_method().full_method_name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lists,
+        vec![
+            "OtherShelfExtensions::books_for",
+            "ShelfExtensions::books_for"
+        ]
+    );
+
+    assert_eq!(object.phlow_views_of_type("text_view").len(), 3);
+    assert!(object.phlow_views_of_type("bitmap_view").is_empty());
+}